@@ -111,10 +111,39 @@ fn bench_get_seq_raw(c: &mut Criterion) {
     });
 }
 
+/// Benchmark of point lookups opening a cursor each, with and without a cursor pool.
+fn bench_get_rand_cursor(c: &mut Criterion) {
+    let n = 100;
+    let (_dir, db) = setup_bench_db(n);
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    let keys: Vec<String> = (0..n).map(get_key).collect();
+
+    c.bench_function("bench_get_rand_cursor", |b| {
+        b.iter(|| {
+            for key in &keys {
+                let mut cursor = txn.cursor(&table).unwrap();
+                black_box(cursor.set::<ObjectLength>(key.as_bytes()).unwrap());
+            }
+        })
+    });
+
+    let pool = txn.cursor_pool();
+    c.bench_function("bench_get_rand_cursor_pool", |b| {
+        b.iter(|| {
+            for key in &keys {
+                let mut cursor = pool.cursor(&table).unwrap();
+                black_box(cursor.set::<ObjectLength>(key.as_bytes()).unwrap());
+            }
+        })
+    });
+}
+
 criterion_group!(
     benches,
     bench_get_seq_iter,
     bench_get_seq_cursor,
-    bench_get_seq_raw
+    bench_get_seq_raw,
+    bench_get_rand_cursor
 );
 criterion_main!(benches);
@@ -3,7 +3,7 @@ mod utils;
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use ffi::*;
 use libc::size_t;
-use libmdbx::{ObjectLength, WriteFlags};
+use libmdbx::{Database, DatabaseOptions, NoWriteMap, ObjectLength, TableFlags, WriteFlags};
 use rand::{prelude::SliceRandom, SeedableRng};
 use rand_xorshift::XorShiftRng;
 use std::ptr;
@@ -130,11 +130,72 @@ fn bench_put_rand_raw(c: &mut Criterion) {
     });
 }
 
+fn bench_open_named_tables(c: &mut Criterion) {
+    let n = 32;
+    let dir = tempfile::tempdir().unwrap();
+    let db = Database::<NoWriteMap>::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(n),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let names: Vec<String> = (0..n).map(|i| format!("table{i}")).collect();
+    let txn = db.begin_rw_txn().unwrap();
+    for name in &names {
+        txn.create_table(Some(name), TableFlags::empty()).unwrap();
+    }
+    txn.commit().unwrap();
+
+    // Handles are cached after the first transaction opening the tables.
+    c.bench_function("bench_open_named_tables", |b| {
+        b.iter(|| {
+            let txn = db.begin_ro_txn().unwrap();
+            for name in &names {
+                black_box(txn.open_table(Some(name)).unwrap());
+            }
+        })
+    });
+}
+
+fn bench_bulk_load(c: &mut Criterion) {
+    let n = 10_000u32;
+    let (_dir, db) = setup_bench_db(0);
+
+    // Big-endian keys sort in numeric order.
+    let items: Vec<([u8; 4], String)> = (0..n).map(|n| (n.to_be_bytes(), get_data(n))).collect();
+
+    // Both variants write sorted input into a transaction which is then aborted.
+    c.bench_function("bench_bulk_load_put", |b| {
+        b.iter(|| {
+            let txn = db.begin_rw_txn().unwrap();
+            let table = txn.open_table(None).unwrap();
+            for (key, data) in &items {
+                txn.put(&table, key, data, WriteFlags::empty()).unwrap();
+            }
+        })
+    });
+
+    c.bench_function("bench_bulk_load", |b| {
+        b.iter(|| {
+            let txn = db.begin_rw_txn().unwrap();
+            let table = txn.open_table(None).unwrap();
+            black_box(
+                txn.bulk_load(&table, items.iter().map(|(k, v)| (k, v)), None)
+                    .unwrap(),
+            );
+        })
+    });
+}
+
 criterion_group!(
     benches,
     bench_get_rand,
     bench_get_rand_raw,
     bench_put_rand,
-    bench_put_rand_raw
+    bench_put_rand_raw,
+    bench_open_named_tables,
+    bench_bulk_load
 );
 criterion_main!(benches);
@@ -13039,7 +13039,8 @@ static int txn_renew(MDBX_txn *txn, const unsigned flags) {
       goto bailout;
     }
 
-    txn->mt_flags = flags;
+    /* MDBX_TXN_TRY only applies to taking the writer lock above. */
+    txn->mt_flags = flags & ~MDBX_TXN_TRY;
     txn->mt_child = NULL;
     txn->tw.loose_pages = NULL;
     txn->tw.loose_count = 0;
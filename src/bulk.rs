@@ -0,0 +1,234 @@
+use crate::{
+    cancellation::CancellationToken,
+    cursor::Cursor,
+    database::{Database, DatabaseKind, Writable},
+    error::{Error, Result},
+    flags::{TableFlags, WriteFlags},
+    table::Table,
+    transaction::{Transaction, RW},
+};
+use std::cmp::Ordering;
+
+/// Progress of a bulk load, carried over between the transactions of [Database::bulk_load].
+#[derive(Default)]
+struct LoadState {
+    /// Number of items consumed from the input so far.
+    count: u64,
+    /// The last item consumed, to check that the input is sorted.
+    last: Option<(Vec<u8>, Vec<u8>)>,
+}
+
+/// Values of a single key of a [TableFlags::DUP_FIXED] table, waiting to be stored with one
+/// [Cursor::put_multiple] call.
+#[derive(Default)]
+struct Batch {
+    key: Vec<u8>,
+    values: Vec<u8>,
+    value_size: usize,
+    /// Input position of the first value in the batch.
+    start: u64,
+}
+
+impl<E> Transaction<'_, RW, E>
+where
+    E: DatabaseKind,
+{
+    /// Stores items sorted in table order, appending them to the end of the table.
+    ///
+    /// Appending skips the search for each key and fills pages completely, which makes this much
+    /// faster than separate [Transaction::put] calls for large inputs. Each key must sort after
+    /// the previous one and after any key already in the table. For [TableFlags::DUP_SORT]
+    /// tables, a key may repeat with increasing values, and runs of values of a
+    /// [TableFlags::DUP_FIXED] table are stored a page at a time.
+    ///
+    /// Returns the number of items stored. If the input is out of order, fails with
+    /// [Error::Unsorted] reporting the position of the offending item; the items before it have
+    /// been written, and the transaction can still be committed or aborted. The same holds once
+    /// `cancel` is cancelled, which is polled before each item, with [Error::Cancelled] counting
+    /// the items written.
+    pub fn bulk_load<'txn, I, K, V>(
+        &'txn self,
+        table: &Table<'txn>,
+        pairs: I,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<u64>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<[u8]>,
+        V: AsRef<[u8]>,
+    {
+        let mut state = LoadState::default();
+        self.load_sorted(table, &mut pairs.into_iter(), &mut state, None, cancel)?;
+        Ok(state.count)
+    }
+
+    /// Stores up to `limit` items from `pairs`, returning whether the input is exhausted.
+    fn load_sorted<'txn, K, V>(
+        &'txn self,
+        table: &Table<'txn>,
+        pairs: &mut impl Iterator<Item = (K, V)>,
+        state: &mut LoadState,
+        limit: Option<usize>,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<bool>
+    where
+        K: AsRef<[u8]>,
+        V: AsRef<[u8]>,
+    {
+        let flags = self.table_flags(table)?;
+        let dup_sort = flags.contains(TableFlags::DUP_SORT);
+        let dup_fixed = flags.contains(TableFlags::DUP_FIXED);
+        // Without NO_OVERWRITE, appending a key equal to the last one in the table silently
+        // replaces its value. With APPEND_DUP, repeating the last duplicate already fails.
+        let put_flags = if dup_sort {
+            WriteFlags::APPEND | WriteFlags::APPEND_DUP
+        } else {
+            WriteFlags::APPEND | WriteFlags::NO_OVERWRITE
+        };
+
+        let mut cursor = self.cursor(table)?;
+        let mut batch = Batch::default();
+        let mut loaded = 0;
+        let exhausted = loop {
+            if limit.is_some_and(|limit| loaded == limit) {
+                break false;
+            }
+            if let Some(Err(e)) = cancel.map(|cancel| cancel.check(state.count)) {
+                batch.flush(&mut cursor, put_flags)?;
+                return Err(e);
+            }
+            let Some((key, value)) = pairs.next() else {
+                break true;
+            };
+            let (key, value) = (key.as_ref(), value.as_ref());
+            let index = state.count;
+
+            if let Some((last_key, last_value)) = &state.last {
+                let ordering = match self.compare_keys(table, key, last_key) {
+                    Ordering::Equal if dup_sort => self.compare_values(table, value, last_value),
+                    ordering => ordering,
+                };
+                if ordering != Ordering::Greater {
+                    batch.flush(&mut cursor, put_flags)?;
+                    return Err(Error::Unsorted { index });
+                }
+            }
+            let last = state.last.get_or_insert_with(Default::default);
+            last.0.clear();
+            last.0.extend_from_slice(key);
+            last.1.clear();
+            last.1.extend_from_slice(value);
+            state.count += 1;
+            loaded += 1;
+
+            if !dup_fixed {
+                put(&mut cursor, key, value, put_flags, index)?;
+                continue;
+            }
+            if !batch.values.is_empty() && (batch.key != key || batch.value_size != value.len()) {
+                batch.flush(&mut cursor, put_flags)?;
+            }
+            if batch.values.is_empty() {
+                batch.key.clear();
+                batch.key.extend_from_slice(key);
+                batch.value_size = value.len();
+                batch.start = index;
+            }
+            batch.values.extend_from_slice(value);
+        };
+        batch.flush(&mut cursor, put_flags)?;
+
+        Ok(exhausted)
+    }
+}
+
+impl Batch {
+    fn flush(&mut self, cursor: &mut Cursor<'_, RW>, flags: WriteFlags) -> Result<()> {
+        let mut values = &self.values[..];
+        // Input position of the first value not written yet, which is the one MDBX refuses.
+        let mut index = self.start;
+        // MDBX stores at least two values per call.
+        while values.len() > self.value_size {
+            let written = cursor
+                .put_multiple(&self.key, values, self.value_size, flags)
+                .map_err(|e| unsorted(e, index))?;
+            values = &values[written * self.value_size..];
+            index += written as u64;
+        }
+        if !values.is_empty() {
+            put(cursor, &self.key, values, flags, index)?;
+        }
+        self.values.clear();
+
+        Ok(())
+    }
+}
+
+fn put(
+    cursor: &mut Cursor<'_, RW>,
+    key: &[u8],
+    value: &[u8],
+    flags: WriteFlags,
+    index: u64,
+) -> Result<()> {
+    cursor
+        .put(key, value, flags)
+        .map_err(|e| unsorted(e, index))
+}
+
+/// The input was checked to be sorted, so MDBX refusing to append means the item does not sort
+/// after the data already in the table.
+fn unsorted(e: Error, index: u64) -> Error {
+    match e {
+        Error::KeyMismatch | Error::KeyExist => Error::Unsorted { index },
+        other => other,
+    }
+}
+
+impl<E> Database<E>
+where
+    E: Writable,
+{
+    /// Stores items sorted in table order into an existing table, see [Transaction::bulk_load].
+    ///
+    /// With `commit_every`, the items are written in separate transactions of that many items
+    /// each, which bounds the amount of dirty pages held in memory. Otherwise a single transaction
+    /// is used. If the input turns out to be out of order, the items of the transaction in
+    /// progress are discarded, while those of earlier transactions stay committed. Once `cancel` is
+    /// cancelled, the transaction in progress is committed as well, and [Error::Cancelled] counts
+    /// all items stored, so that the load can be resumed after them.
+    ///
+    /// Panics if `commit_every` is `Some(0)`.
+    pub fn bulk_load<I, K, V>(
+        &self,
+        name: Option<&str>,
+        pairs: I,
+        commit_every: Option<usize>,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<u64>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: AsRef<[u8]>,
+        V: AsRef<[u8]>,
+    {
+        assert_ne!(commit_every, Some(0), "commit_every must be positive");
+        let mut pairs = pairs.into_iter();
+        let mut state = LoadState::default();
+        loop {
+            let txn = self.begin_rw_txn()?;
+            let table = txn.open_table(name)?;
+            let exhausted =
+                match txn.load_sorted(&table, &mut pairs, &mut state, commit_every, cancel) {
+                    Err(e @ Error::Cancelled { .. }) => {
+                        txn.commit()?;
+                        return Err(e);
+                    }
+                    result => result?,
+                };
+            txn.commit()?;
+            if exhausted {
+                return Ok(state.count);
+            }
+        }
+    }
+}
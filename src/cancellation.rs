@@ -0,0 +1,89 @@
+use crate::error::{Error, Result};
+use std::{
+    iter::FusedIterator,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+};
+
+/// A cheaply clonable flag used to abort long-running operations.
+///
+/// All clones share the same state, so a token can be handed to a worker and cancelled from
+/// another thread (e.g. a Ctrl-C handler). Long-running operations poll the token between
+/// batches and stop with [Error::Cancelled] once it has been triggered.
+#[derive(Clone, Debug, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Request cancellation of every operation observing this token.
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    /// Whether [CancellationToken::cancel] has been called on this token or any of its clones.
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Returns [Error::Cancelled] reporting `progress` if the token has been cancelled.
+    pub fn check(&self, progress: u64) -> Result<()> {
+        if self.is_cancelled() {
+            return Err(Error::Cancelled { progress });
+        }
+
+        Ok(())
+    }
+
+    /// Wraps a fallible iterator (such as a cursor iterator) so that it checks this token before
+    /// yielding each item. Once cancelled, it yields a single [Error::Cancelled] counting the
+    /// items yielded before, and then stops.
+    pub fn wrap<I>(&self, iter: I) -> Cancellable<I> {
+        Cancellable {
+            iter,
+            token: self.clone(),
+            yielded: 0,
+            done: false,
+        }
+    }
+}
+
+/// An iterator which stops early when its [CancellationToken] is cancelled.
+///
+/// Created by [CancellationToken::wrap].
+#[derive(Debug)]
+pub struct Cancellable<I> {
+    iter: I,
+    token: CancellationToken,
+    yielded: u64,
+    done: bool,
+}
+
+impl<I, T> Iterator for Cancellable<I>
+where
+    I: Iterator<Item = Result<T>>,
+{
+    type Item = Result<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        if let Err(e) = self.token.check(self.yielded) {
+            self.done = true;
+            return Some(Err(e));
+        }
+
+        let item = self.iter.next();
+        self.done = item.is_none();
+        self.yielded += u64::from(!self.done);
+        item
+    }
+}
+
+impl<I, T> FusedIterator for Cancellable<I> where I: Iterator<Item = Result<T>> {}
@@ -0,0 +1,348 @@
+use crate::{
+    cancellation::CancellationToken,
+    database::{Database, DatabaseKind},
+    error::{Error, Result},
+    flags::TableFlags,
+    page_walk::PageOwner,
+    table::Table,
+    transaction::{Transaction, RO},
+};
+use std::{borrow::Cow, cmp::Ordering, collections::HashMap, fmt, mem, result};
+
+/// How thoroughly [Database::check] verifies the database.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CheckLevel {
+    /// Walk the pages of every table, checking that each page is well-formed and used only once,
+    /// and that every allocated page is either in use or free.
+    Pages,
+    /// Additionally read every item, checking that keys and duplicates are in the order of the
+    /// table's comparators and that the number of items matches the table statistics.
+    Full,
+}
+
+/// The result of [Database::check].
+#[derive(Debug)]
+pub struct CheckReport {
+    /// The main table, followed by the named tables in key order.
+    pub tables: Vec<TableReport>,
+    /// Number of pages allocated in the database, up to the last used one.
+    pub pages: u64,
+    /// Number of pages used by the tables, including the GC table and the meta pages.
+    pub used_pages: u64,
+    /// Number of free pages recorded in the GC table, each of which is checked not to be in use.
+    pub gc_pages: u64,
+    /// Number of allocated pages which are neither used nor free.
+    pub unreachable_pages: u64,
+    /// The first inconsistency found, if any.
+    pub problem: Option<Inconsistency>,
+}
+
+impl CheckReport {
+    /// Whether no inconsistency was found.
+    pub fn is_ok(&self) -> bool {
+        self.problem.is_none()
+    }
+}
+
+/// Per-table part of a [CheckReport].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TableReport {
+    /// The table name, or [None] for the main table.
+    pub name: Option<String>,
+    /// Number of items, as counted by [CheckLevel::Full] or else as recorded in the table
+    /// statistics.
+    pub entries: u64,
+    /// Number of pages used by the table.
+    pub pages: u64,
+}
+
+/// An inconsistency found by [Database::check].
+#[derive(Debug)]
+pub enum Inconsistency {
+    /// A page is malformed.
+    BrokenPage { pgno: u64, error: Error },
+    /// A page is used more than once.
+    DuplicatePage { pgno: u64 },
+    /// A page lies beyond the last allocated page.
+    PageOutOfBounds { pgno: u64 },
+    /// A page recorded as free in the GC table is in use.
+    FreePageInUse { pgno: u64 },
+    /// Allocated pages are neither used nor free.
+    UnreachablePages { count: u64 },
+    /// A key, or a duplicate of it, does not sort after the previous item.
+    Unsorted { table: Option<String>, key: Vec<u8> },
+    /// The number of items differs from the one recorded in the table statistics.
+    EntryCount {
+        table: Option<String>,
+        recorded: u64,
+        counted: u64,
+    },
+    /// MDBX failed to walk or read the data.
+    Failed(Error),
+}
+
+impl fmt::Display for Inconsistency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = |table: &Option<String>| table.clone().unwrap_or_else(|| "main".into());
+        match self {
+            Self::BrokenPage { pgno, error } => write!(f, "page {pgno} is broken: {error}"),
+            Self::DuplicatePage { pgno } => write!(f, "page {pgno} is used more than once"),
+            Self::PageOutOfBounds { pgno } => {
+                write!(f, "page {pgno} lies beyond the allocated pages")
+            }
+            Self::FreePageInUse { pgno } => write!(f, "free page {pgno} is in use"),
+            Self::UnreachablePages { count } => {
+                write!(f, "{count} pages are neither used nor free")
+            }
+            Self::Unsorted { table, key } => {
+                write!(
+                    f,
+                    "item with key {key:02x?} of table {} is out of order",
+                    name(table)
+                )
+            }
+            Self::EntryCount {
+                table,
+                recorded,
+                counted,
+            } => write!(
+                f,
+                "table {} has {counted} items, while {recorded} are recorded",
+                name(table)
+            ),
+            Self::Failed(error) => write!(f, "{error}"),
+        }
+    }
+}
+
+impl<E> Database<E>
+where
+    E: DatabaseKind,
+{
+    /// Verifies the integrity of the database, similar to the `mdbx_chk` tool.
+    ///
+    /// The check runs in a read-only transaction and never modifies the database. Corruption is
+    /// reported in [CheckReport::problem], as are errors reading the data. The parts of the report
+    /// past the first inconsistency are left empty.
+    ///
+    /// The check polls `cancel` before every page and item. Once cancelled, it stops and reports
+    /// [Error::Cancelled], counting the pages and items checked, as the problem; the report covers
+    /// the part checked so far.
+    pub fn check(
+        &self,
+        level: CheckLevel,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<CheckReport> {
+        let txn = self.begin_ro_txn()?;
+        let pages = txn.info(false)?.space_used() / u64::from(self.stat()?.page_size());
+
+        let mut report = CheckReport {
+            tables: Vec::new(),
+            pages,
+            used_pages: 0,
+            gc_pages: 0,
+            unreachable_pages: 0,
+            problem: None,
+        };
+        let mut progress = Progress { cancel, checked: 0 };
+        if let Err(problem) = check_snapshot(&txn, level, &mut progress, &mut report) {
+            report.problem = Some(problem);
+        }
+
+        Ok(report)
+    }
+}
+
+/// Counts the pages and items checked, for [Error::Cancelled].
+struct Progress<'a> {
+    cancel: Option<&'a CancellationToken>,
+    checked: u64,
+}
+
+impl Progress<'_> {
+    /// Fails if cancelled, otherwise counts one more page or item.
+    fn step(&mut self) -> Result<()> {
+        if let Some(cancel) = self.cancel {
+            cancel.check(self.checked)?;
+        }
+        self.checked += 1;
+        Ok(())
+    }
+}
+
+/// Fills in the report, stopping at the first inconsistency. After a broken page, MDBX refuses
+/// further reads in the transaction anyway.
+fn check_snapshot<E>(
+    txn: &Transaction<'_, RO, E>,
+    level: CheckLevel,
+    progress: &mut Progress<'_>,
+    report: &mut CheckReport,
+) -> result::Result<(), Inconsistency>
+where
+    E: DatabaseKind,
+{
+    let mut used = vec![false; report.pages as usize];
+    // Pages used by the main and the named tables.
+    let mut table_pages = HashMap::<Option<String>, u64>::new();
+    let mut problem = None;
+    let walked = txn.try_walk_pages(|page| {
+        progress.step()?;
+        if let Some(error) = &page.error {
+            problem.get_or_insert(Inconsistency::BrokenPage {
+                pgno: page.pgno,
+                error: Error::from_err_code(error.code()),
+            });
+        }
+
+        // Sub-pages are embedded in the leaf page reported after them.
+        let count = u64::from(page.count);
+        if count == 0 {
+            return Ok(());
+        }
+        let table = match &page.owner {
+            PageOwner::Main => Some(None),
+            PageOwner::Table(name) => Some(Some(name.clone().into_owned())),
+            PageOwner::Gc | PageOwner::Meta => None,
+        };
+        if let Some(table) = table {
+            *table_pages.entry(table).or_default() += count;
+        }
+
+        report.used_pages += count;
+        for pgno in page.pgno..page.pgno + count {
+            match used.get_mut(pgno as usize) {
+                Some(used) if *used => {
+                    problem.get_or_insert(Inconsistency::DuplicatePage { pgno });
+                }
+                Some(used) => *used = true,
+                None => {
+                    problem.get_or_insert(Inconsistency::PageOutOfBounds { pgno });
+                }
+            }
+        }
+        Ok(())
+    });
+    if let Some(problem) = problem {
+        return Err(problem);
+    }
+    walked.map_err(Inconsistency::Failed)?;
+
+    let names = txn.table_names().map_err(Inconsistency::Failed)?;
+    for name in [None].into_iter().chain(names.into_iter().map(Some)) {
+        let table = txn
+            .open_table(name.as_deref())
+            .map_err(Inconsistency::Failed)?;
+        let recorded = txn
+            .table_stat(&table)
+            .map_err(Inconsistency::Failed)?
+            .entries() as u64;
+        let entries = match level {
+            CheckLevel::Pages => recorded,
+            CheckLevel::Full => check_table(txn, &table, &name, recorded, progress)?,
+        };
+        report.tables.push(TableReport {
+            pages: table_pages.remove(&name).unwrap_or_default(),
+            name,
+            entries,
+        });
+    }
+    report.gc_pages = check_gc(txn, &used, progress)?;
+
+    report.unreachable_pages = report
+        .pages
+        .saturating_sub(report.used_pages + report.gc_pages);
+    if report.unreachable_pages > 0 {
+        return Err(Inconsistency::UnreachablePages {
+            count: report.unreachable_pages,
+        });
+    }
+
+    Ok(())
+}
+
+/// Counts the items of the table, checking that they are in order and that the count matches
+/// the one recorded.
+fn check_table<E>(
+    txn: &Transaction<'_, RO, E>,
+    table: &Table<'_>,
+    name: &Option<String>,
+    recorded: u64,
+    progress: &mut Progress<'_>,
+) -> result::Result<u64, Inconsistency>
+where
+    E: DatabaseKind,
+{
+    let dup_sort = txn
+        .table_flags(table)
+        .map_err(Inconsistency::Failed)?
+        .contains(TableFlags::DUP_SORT);
+    let mut cursor = txn.cursor(table).map_err(Inconsistency::Failed)?;
+    let mut last: Option<(Cow<[u8]>, Cow<[u8]>)> = None;
+    let mut counted = 0;
+    for item in cursor.iter_start::<Cow<[u8]>, Cow<[u8]>>() {
+        let (key, value) = item.map_err(Inconsistency::Failed)?;
+        progress.step().map_err(Inconsistency::Failed)?;
+        if let Some((last_key, last_value)) = &last {
+            let ordering = match txn.compare_keys(table, &key, last_key) {
+                Ordering::Equal if dup_sort => txn.compare_values(table, &value, last_value),
+                ordering => ordering,
+            };
+            if ordering != Ordering::Greater {
+                return Err(Inconsistency::Unsorted {
+                    table: name.clone(),
+                    key: key.into_owned(),
+                });
+            }
+        }
+        last = Some((key, value));
+        counted += 1;
+    }
+
+    if counted != recorded {
+        return Err(Inconsistency::EntryCount {
+            table: name.clone(),
+            recorded,
+            counted,
+        });
+    }
+    Ok(counted)
+}
+
+/// Counts the free pages, checking that none of them is in use. Each GC record holds a page count
+/// followed by the page numbers, see [Database::freelist].
+fn check_gc<E>(
+    txn: &Transaction<'_, RO, E>,
+    used: &[bool],
+    progress: &mut Progress<'_>,
+) -> result::Result<u64, Inconsistency>
+where
+    E: DatabaseKind,
+{
+    let cursor = txn
+        .cursor(&Table::freelist_table())
+        .map_err(Inconsistency::Failed)?;
+    let mut pages = 0;
+    for item in cursor {
+        let (_, value) = item.map_err(Inconsistency::Failed)?;
+        progress.step().map_err(Inconsistency::Failed)?;
+        let mut pgnos = value
+            .chunks_exact(mem::size_of::<u32>())
+            .map(|pgno| u64::from(u32::from_ne_bytes(pgno.try_into().unwrap())));
+        let count = pgnos
+            .next()
+            .ok_or(Inconsistency::Failed(Error::Corrupted))?;
+        if value.len() / mem::size_of::<u32>() <= count as usize {
+            return Err(Inconsistency::Failed(Error::Corrupted));
+        }
+        for pgno in pgnos.take(count as usize) {
+            match used.get(pgno as usize) {
+                Some(true) => return Err(Inconsistency::FreePageInUse { pgno }),
+                Some(false) => {}
+                None => return Err(Inconsistency::PageOutOfBounds { pgno }),
+            }
+        }
+        pages += count;
+    }
+
+    Ok(pages)
+}
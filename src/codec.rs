@@ -1,9 +1,19 @@
 use crate::{error::mdbx_result, Error, TransactionKind};
 use derive_more::*;
-use std::{borrow::Cow, slice};
-use thiserror::Error;
+use std::{borrow::Cow, slice, str};
 
 /// Implement this to be able to decode data values
+///
+/// Raw values can be decoded as:
+/// - [Vec<u8>], which always copies the value, so it remains usable after the transaction ends.
+/// - [Cow<'tx, \[u8\]>](Cow), which borrows the value from the memory map unless it was modified
+///   in the current read-write transaction, in which case it is copied.
+/// - `&'tx [u8]`, which always borrows the value, failing with [Error::CannotBorrow] if it was
+///   modified in the current read-write transaction.
+///
+/// Borrowed values are tied to the lifetime of the transaction, so using them after it ends
+/// does not compile. Values are never modified in place while a read-only transaction is open, so
+/// reading through one never copies when borrowing is possible.
 pub trait Decodable<'tx> {
     fn decode(data_val: &[u8]) -> Result<Self, Error>
     where
@@ -17,15 +27,22 @@ pub trait Decodable<'tx> {
     where
         Self: Sized,
     {
-        let s = slice::from_raw_parts(data_val.iov_base as *const u8, data_val.iov_len);
+        Decodable::decode(val_as_slice(data_val))
+    }
+}
 
-        Decodable::decode(s)
+/// The bytes of a value returned by MDBX. Empty values may come with a null pointer.
+unsafe fn val_as_slice<'a>(val: &ffi::MDBX_val) -> &'a [u8] {
+    if val.iov_len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(val.iov_base as *const u8, val.iov_len)
     }
 }
 
 impl<'tx> Decodable<'tx> for Cow<'tx, [u8]> {
-    fn decode(_: &[u8]) -> Result<Self, Error> {
-        unreachable!()
+    fn decode(data_val: &[u8]) -> Result<Self, Error> {
+        Ok(Cow::Owned(data_val.to_vec()))
     }
 
     #[doc(hidden)]
@@ -33,9 +50,12 @@ impl<'tx> Decodable<'tx> for Cow<'tx, [u8]> {
         txn: *const ffi::MDBX_txn,
         data_val: &ffi::MDBX_val,
     ) -> Result<Self, Error> {
+        if data_val.iov_len == 0 {
+            return Ok(Cow::Borrowed(&[]));
+        }
         let is_dirty = (!K::ONLY_CLEAN) && mdbx_result(ffi::mdbx_is_dirty(txn, data_val.iov_base))?;
 
-        let s = slice::from_raw_parts(data_val.iov_base as *const u8, data_val.iov_len);
+        let s = val_as_slice(data_val);
 
         Ok(if is_dirty {
             Cow::Owned(s.to_vec())
@@ -45,10 +65,33 @@ impl<'tx> Decodable<'tx> for Cow<'tx, [u8]> {
     }
 }
 
+impl<'tx> Decodable<'tx> for &'tx [u8] {
+    /// Values outside of the database cannot be borrowed for the lifetime of a transaction.
+    fn decode(_: &[u8]) -> Result<Self, Error> {
+        Err(Error::CannotBorrow)
+    }
+
+    #[doc(hidden)]
+    unsafe fn decode_val<K: TransactionKind>(
+        txn: *const ffi::MDBX_txn,
+        data_val: &ffi::MDBX_val,
+    ) -> Result<Self, Error> {
+        if data_val.iov_len == 0 {
+            return Ok(&[]);
+        }
+        // Pages modified in a read-write transaction may be reused by later writes.
+        if !K::ONLY_CLEAN && mdbx_result(ffi::mdbx_is_dirty(txn, data_val.iov_base))? {
+            return Err(Error::CannotBorrow);
+        }
+
+        Ok(val_as_slice(data_val))
+    }
+}
+
 #[cfg(feature = "lifetimed-bytes")]
 impl<'tx> Decodable<'tx> for lifetimed_bytes::Bytes<'tx> {
-    fn decode(_: &[u8]) -> Result<Self, Error> {
-        unreachable!()
+    fn decode(data_val: &[u8]) -> Result<Self, Error> {
+        Cow::<'tx, [u8]>::decode(data_val).map(From::from)
     }
 
     #[doc(hidden)]
@@ -82,6 +125,83 @@ impl<'tx> Decodable<'tx> for () {
     }
 }
 
+/// Decodes valid UTF-8 only, see [Utf8Strict].
+impl<'tx> Decodable<'tx> for String {
+    fn decode(data_val: &[u8]) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        Utf8Strict::decode(data_val).map(|s| s.0.into_owned())
+    }
+}
+
+/// A UTF-8 string, borrowed from the database when possible.
+///
+/// Invalid UTF-8 fails with [Error::DecodeError] carrying the [str::Utf8Error], which reports the
+/// offset of the first invalid byte.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Deref)]
+pub struct Utf8Strict<'tx>(pub Cow<'tx, str>);
+
+impl<'tx> Decodable<'tx> for Utf8Strict<'tx> {
+    fn decode(data_val: &[u8]) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        str::from_utf8(data_val)
+            .map(|s| Self(Cow::Owned(s.to_owned())))
+            .map_err(|e| Error::DecodeError(e.into()))
+    }
+
+    #[doc(hidden)]
+    unsafe fn decode_val<K: TransactionKind>(
+        txn: *const ffi::MDBX_txn,
+        data_val: &ffi::MDBX_val,
+    ) -> Result<Self, Error> {
+        match Cow::<'tx, [u8]>::decode_val::<K>(txn, data_val)? {
+            Cow::Borrowed(b) => str::from_utf8(b).map(Cow::Borrowed),
+            Cow::Owned(v) => String::from_utf8(v)
+                .map(Cow::Owned)
+                .map_err(|e| e.utf8_error()),
+        }
+        .map(Self)
+        .map_err(|e| Error::DecodeError(e.into()))
+    }
+}
+
+/// A UTF-8 string, borrowed from the database when possible.
+///
+/// Invalid UTF-8 sequences are replaced with [char::REPLACEMENT_CHARACTER], so decoding never
+/// fails.
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Deref)]
+pub struct Utf8Lossy<'tx>(pub Cow<'tx, str>);
+
+impl<'tx> Decodable<'tx> for Utf8Lossy<'tx> {
+    fn decode(data_val: &[u8]) -> Result<Self, Error>
+    where
+        Self: Sized,
+    {
+        Ok(Self(Cow::Owned(
+            String::from_utf8_lossy(data_val).into_owned(),
+        )))
+    }
+
+    #[doc(hidden)]
+    unsafe fn decode_val<K: TransactionKind>(
+        txn: *const ffi::MDBX_txn,
+        data_val: &ffi::MDBX_val,
+    ) -> Result<Self, Error> {
+        Ok(Self(
+            match Cow::<'tx, [u8]>::decode_val::<K>(txn, data_val)? {
+                Cow::Borrowed(b) => String::from_utf8_lossy(b),
+                Cow::Owned(v) => Cow::Owned(
+                    String::from_utf8(v)
+                        .unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned()),
+                ),
+            },
+        ))
+    }
+}
+
 /// If you don't need the data itself, just its length.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Deref, DerefMut)]
 pub struct ObjectLength(pub usize);
@@ -100,15 +220,11 @@ impl<'tx, const LEN: usize> Decodable<'tx> for [u8; LEN] {
     where
         Self: Sized,
     {
-        #[derive(Clone, Debug, Display, Error)]
-        struct InvalidSize<const LEN: usize> {
-            got: usize,
-        }
-
         if data_val.len() != LEN {
-            return Err(Error::DecodeError(Box::new(InvalidSize::<LEN> {
-                got: data_val.len(),
-            })));
+            return Err(Error::WrongValueSize {
+                expected: LEN,
+                actual: data_val.len(),
+            });
         }
         let mut a = [0; LEN];
         a[..].copy_from_slice(data_val);
@@ -0,0 +1,45 @@
+use libc::c_int;
+use std::{cmp::Ordering, slice};
+
+/// A raw MDBX comparison function, as accepted by
+/// [Transaction::create_table_with_comparators](crate::Transaction::create_table_with_comparators).
+pub type ComparatorFn = unsafe extern "C" fn(*const ffi::MDBX_val, *const ffi::MDBX_val) -> c_int;
+
+/// A custom ordering for the keys or duplicate values of a table.
+///
+/// Implement this on a marker type and pass [Comparator::as_raw] to
+/// [Transaction::create_table_with_comparators](crate::Transaction::create_table_with_comparators).
+///
+/// MDBX does not persist comparators: the order of the stored data depends on them, so every
+/// process opening the table must use the same ordering, or the table will appear corrupted.
+pub trait Comparator {
+    fn compare(a: &[u8], b: &[u8]) -> Ordering;
+
+    /// A C-compatible function calling [Comparator::compare].
+    fn as_raw() -> ComparatorFn
+    where
+        Self: Sized,
+    {
+        trampoline::<Self>
+    }
+}
+
+unsafe extern "C" fn trampoline<C: Comparator>(
+    a: *const ffi::MDBX_val,
+    b: *const ffi::MDBX_val,
+) -> c_int {
+    unsafe fn as_slice<'a>(val: *const ffi::MDBX_val) -> &'a [u8] {
+        let val = &*val;
+        if val.iov_len == 0 {
+            &[]
+        } else {
+            slice::from_raw_parts(val.iov_base as *const u8, val.iov_len)
+        }
+    }
+
+    // Unwinding into MDBX is undefined behaviour.
+    match std::panic::catch_unwind(|| C::compare(as_slice(a), as_slice(b))) {
+        Ok(ordering) => ordering as c_int,
+        Err(_) => std::process::abort(),
+    }
+}
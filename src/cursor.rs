@@ -1,11 +1,10 @@
 use crate::{
-    database::TxnPtr,
     error::{mdbx_result, Error, Result},
     flags::*,
     mdbx_try_optional,
     table::Table,
-    transaction::{txn_execute, TransactionKind, RW},
-    DatabaseKind, Decodable, Transaction,
+    transaction::{txn_execute, TransactionKind, TxnState, RO, RW},
+    DatabaseKind, Decodable, Stat, Transaction,
 };
 use ffi::{
     MDBX_cursor_op, MDBX_FIRST, MDBX_FIRST_DUP, MDBX_GET_BOTH, MDBX_GET_BOTH_RANGE,
@@ -15,9 +14,18 @@ use ffi::{
 };
 use libc::{c_uint, c_void};
 use parking_lot::Mutex;
-use std::{borrow::Cow, fmt, marker::PhantomData, mem, ptr, result, sync::Arc};
+use std::{
+    borrow::Cow,
+    fmt,
+    iter::FusedIterator,
+    marker::PhantomData,
+    mem,
+    ops::{self, RangeBounds},
+    ptr, result, slice,
+    sync::Arc,
+};
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct CursorPtr(pub *mut ffi::MDBX_cursor);
 unsafe impl Send for CursorPtr {}
 
@@ -26,9 +34,9 @@ pub struct Cursor<'txn, K>
 where
     K: TransactionKind,
 {
-    txn: Arc<Mutex<TxnPtr>>,
+    txn: Arc<Mutex<TxnState>>,
     cursor: CursorPtr,
-    _marker: PhantomData<fn(&'txn (), K)>,
+    _marker: PhantomData<(&'txn (), fn(K))>,
 }
 
 impl<'txn, K> Cursor<'txn, K>
@@ -42,10 +50,10 @@ where
         let mut cursor: *mut ffi::MDBX_cursor = ptr::null_mut();
 
         let txn = txn.txn_mutex();
-        unsafe {
-            mdbx_result(txn_execute(&txn, |txn| {
-                ffi::mdbx_cursor_open(txn, table.dbi(), &mut cursor)
-            }))?;
+        {
+            let mut state = txn.lock();
+            mdbx_result(unsafe { ffi::mdbx_cursor_open(state.ptr.0, table.dbi(), &mut cursor) })?;
+            state.register(CursorPtr(cursor));
         }
         Ok(Self {
             txn,
@@ -55,20 +63,44 @@ where
     }
 
     fn new_at_position(other: &Self) -> Result<Self> {
-        unsafe {
-            let cursor = ffi::mdbx_cursor_create(ptr::null_mut());
+        let (cursor, res) = {
+            let mut state = other.txn.lock();
+            let cursor = unsafe { ffi::mdbx_cursor_create(ptr::null_mut()) };
+            let res = unsafe { ffi::mdbx_cursor_copy(other.cursor().0, cursor) };
+            // Registered even if copying failed, so that dropping it closes the cursor.
+            state.register(CursorPtr(cursor));
+            (cursor, res)
+        };
+
+        let s = Self {
+            txn: other.txn.clone(),
+            cursor: CursorPtr(cursor),
+            _marker: PhantomData,
+        };
 
-            let res = ffi::mdbx_cursor_copy(other.cursor().0, cursor);
+        mdbx_result(res)?;
 
-            let s = Self {
-                txn: other.txn.clone(),
-                cursor: CursorPtr(cursor),
-                _marker: PhantomData,
-            };
+        Ok(s)
+    }
 
-            mdbx_result(res)?;
+    /// Creates an independent cursor at the same position, or fails if MDBX cannot allocate one.
+    ///
+    /// Moving either cursor does not affect the other. Both still belong to the same
+    /// transaction though: in a read-write transaction, items put or deleted through one cursor
+    /// are seen by the other, which stays positioned as if it had performed the change itself.
+    pub fn try_clone(&self) -> Result<Self> {
+        Self::new_at_position(self)
+    }
 
-            Ok(s)
+    /// Detaches the cursor from its transaction, so that it can be bound to another one with
+    /// [UnboundCursor::bind] instead of opening a new cursor.
+    pub fn unbind(self) -> UnboundCursor {
+        let this = mem::ManuallyDrop::new(self);
+        // The unbound cursor is closed by its own drop, even after the transaction ends.
+        this.txn.lock().deregister(this.cursor);
+        UnboundCursor {
+            txn: Some(unsafe { ptr::read(&this.txn) }),
+            cursor: this.cursor,
         }
     }
 
@@ -145,7 +177,22 @@ where
     {
         let (k, v, _) = mdbx_try_optional!(self.get(key, data, op));
 
-        Ok(Some((k.unwrap(), v)))
+        Ok(Some((self.key_or_current(k)?, v)))
+    }
+
+    /// MDBX leaves the key untouched when it already points into the table, e.g. when
+    /// positioning with a key borrowed from a previous read, so it is fetched explicitly.
+    fn key_or_current<Key>(&self, key: Option<Key>) -> Result<Key>
+    where
+        Key: Decodable<'txn>,
+    {
+        match key {
+            Some(key) => Ok(key),
+            None => Ok(self
+                .get::<Key, ()>(None, None, MDBX_GET_CURRENT)?
+                .0
+                .unwrap()),
+        }
     }
 
     /// Position at first key/data item.
@@ -306,6 +353,80 @@ where
         self.get_full(Some(key), None, MDBX_SET_RANGE)
     }
 
+    /// Returns the number of duplicates stored under the current key.
+    ///
+    /// Always 1 for tables without [TableFlags::DUP_SORT]. Fails with [Error::NoData] if the
+    /// cursor has not been positioned yet.
+    pub fn count(&self) -> Result<usize> {
+        let mut count = 0;
+        match txn_execute(&self.txn, |_| unsafe {
+            ffi::mdbx_cursor_count(self.cursor.0, &mut count)
+        }) {
+            // The cursor itself is valid, so this can only mean it is unpositioned.
+            ffi::MDBX_EINVAL => Err(Error::NoData),
+            rc => mdbx_result(rc).map(|_| count),
+        }
+    }
+
+    /// Estimates the number of items between this cursor and `other`, which must be positioned on
+    /// the same table. Both cursors keep their positions.
+    ///
+    /// See [Transaction::estimate_range] for notes on the accuracy of the estimate.
+    pub fn estimate_distance(&self, other: &Cursor<'txn, K>) -> Result<isize> {
+        let mut distance = 0;
+        mdbx_result(txn_execute(&self.txn, |_| unsafe {
+            ffi::mdbx_estimate_distance(self.cursor.0, other.cursor.0, &mut distance)
+        }))?;
+        Ok(distance)
+    }
+
+    /// Estimates the number of items between the current position and the position
+    /// [Self::set_range] would move to for `key`. The cursor itself is not moved.
+    ///
+    /// See [Transaction::estimate_range] for notes on the accuracy of the estimate.
+    pub fn estimate_set_range(&self, key: &[u8]) -> Result<isize> {
+        self.estimate_move(Some(key), None, MDBX_SET_RANGE)
+    }
+
+    /// Estimates the number of items between the current position and the position `op` would
+    /// move to. The cursor itself is not moved.
+    fn estimate_move(
+        &self,
+        key: Option<&[u8]>,
+        data: Option<&[u8]>,
+        op: MDBX_cursor_op,
+    ) -> Result<isize> {
+        let mut distance = 0;
+        unsafe {
+            let mut key_val = slice_to_val(key);
+            let mut data_val = slice_to_val(data);
+            mdbx_result(txn_execute(&self.txn, |_| {
+                ffi::mdbx_estimate_move(
+                    self.cursor.0,
+                    &mut key_val,
+                    &mut data_val,
+                    op,
+                    &mut distance,
+                )
+            }))?;
+        }
+        Ok(distance)
+    }
+
+    /// Number of items in the table of the cursor.
+    fn entries(&self) -> Result<usize> {
+        let mut stat = Stat::new();
+        mdbx_result(txn_execute(&self.txn, |txn| unsafe {
+            ffi::mdbx_dbi_stat(
+                txn,
+                ffi::mdbx_cursor_dbi(self.cursor.0),
+                stat.mdb_stat(),
+                mem::size_of::<Stat>(),
+            )
+        }))?;
+        Ok(stat.entries())
+    }
+
     /// [TableFlags::DUP_FIXED]-only: Position at previous page and return up to a page of duplicate data items.
     pub fn prev_multiple<Key, Value>(&mut self) -> Result<Option<(Key, Value)>>
     where
@@ -332,7 +453,7 @@ where
     {
         let (k, v, found) = mdbx_try_optional!(self.get(Some(key), value, MDBX_SET_LOWERBOUND));
 
-        Ok(Some((found, k.unwrap(), v)))
+        Ok(Some((found, self.key_or_current(k)?, v)))
     }
 
     /// Iterate over table items. The iterator will begin with item next
@@ -356,13 +477,13 @@ where
     /// For tables with duplicate data items ([TableFlags::DUP_SORT]), the
     /// duplicate data items of each key will be returned before moving on to
     /// the next key.
-    pub fn iter_start<Key, Value>(&mut self) -> Iter<'txn, '_, K, Key, Value>
+    pub fn iter_start<Key, Value>(&mut self) -> ExactIter<Iter<'txn, '_, K, Key, Value>>
     where
         Self: Sized,
         Key: Decodable<'txn>,
         Value: Decodable<'txn>,
     {
-        Iter::new(self, ffi::MDBX_FIRST, ffi::MDBX_NEXT)
+        ExactIter(Iter::new(self, ffi::MDBX_FIRST, ffi::MDBX_NEXT))
     }
 
     /// Iterate over table items starting from the beginning of the table.
@@ -370,12 +491,12 @@ where
     /// For tables with duplicate data items ([TableFlags::DUP_SORT]), the
     /// duplicate data items of each key will be returned before moving on to
     /// the next key.
-    pub fn into_iter_start<Key, Value>(self) -> IntoIter<'txn, K, Key, Value>
+    pub fn into_iter_start<Key, Value>(self) -> ExactIter<IntoIter<'txn, K, Key, Value>>
     where
         Key: Decodable<'txn>,
         Value: Decodable<'txn>,
     {
-        IntoIter::new(self, ffi::MDBX_FIRST, ffi::MDBX_NEXT)
+        ExactIter(IntoIter::new(self, ffi::MDBX_FIRST, ffi::MDBX_NEXT))
     }
 
     /// Iterate over table items starting from the given key.
@@ -412,6 +533,258 @@ where
         IntoIter::new(self, ffi::MDBX_GET_CURRENT, ffi::MDBX_NEXT)
     }
 
+    /// Iterate over the keys of the table items, see [Self::iter]. Values are not decoded.
+    pub fn iter_keys<Key>(&mut self) -> impl Iterator<Item = Result<Key>> + use<'_, 'txn, K, Key>
+    where
+        Key: Decodable<'txn> + 'txn,
+    {
+        self.iter::<Key, ()>().map(|item| item.map(|(key, _)| key))
+    }
+
+    /// Iterate over the keys of the table items starting from the beginning of the table, see
+    /// [Self::iter_start]. Values are not decoded.
+    pub fn iter_keys_start<Key>(
+        &mut self,
+    ) -> impl Iterator<Item = Result<Key>> + use<'_, 'txn, K, Key>
+    where
+        Key: Decodable<'txn> + 'txn,
+    {
+        self.iter_start::<Key, ()>()
+            .map(|item| item.map(|(key, _)| key))
+    }
+
+    /// Iterate over the keys of the table items starting from the given key, see
+    /// [Self::iter_from]. Values are not decoded.
+    pub fn iter_keys_from<Key>(
+        &mut self,
+        key: &[u8],
+    ) -> impl Iterator<Item = Result<Key>> + use<'_, 'txn, K, Key>
+    where
+        Key: Decodable<'txn> + 'txn,
+    {
+        self.iter_from::<Key, ()>(key)
+            .map(|item| item.map(|(key, _)| key))
+    }
+
+    /// Like [Self::iter_keys], but yields each key of a [TableFlags::DUP_SORT] table only once.
+    pub fn iter_unique_keys<Key>(
+        &mut self,
+    ) -> impl Iterator<Item = Result<Key>> + use<'_, 'txn, K, Key>
+    where
+        Key: Decodable<'txn> + 'txn,
+    {
+        Iter::<K, Key, ()>::new(self, ffi::MDBX_NEXT_NODUP, ffi::MDBX_NEXT_NODUP)
+            .map(|item| item.map(|(key, _)| key))
+    }
+
+    /// Like [Self::iter_keys_start], but yields each key of a [TableFlags::DUP_SORT] table only
+    /// once.
+    pub fn iter_unique_keys_start<Key>(
+        &mut self,
+    ) -> impl Iterator<Item = Result<Key>> + use<'_, 'txn, K, Key>
+    where
+        Key: Decodable<'txn> + 'txn,
+    {
+        Iter::<K, Key, ()>::new(self, ffi::MDBX_FIRST, ffi::MDBX_NEXT_NODUP)
+            .map(|item| item.map(|(key, _)| key))
+    }
+
+    /// Like [Self::iter_keys_from], but yields each key of a [TableFlags::DUP_SORT] table only
+    /// once.
+    pub fn iter_unique_keys_from<Key>(
+        &mut self,
+        key: &[u8],
+    ) -> impl Iterator<Item = Result<Key>> + use<'_, 'txn, K, Key>
+    where
+        Key: Decodable<'txn> + 'txn,
+    {
+        let res: Result<Option<((), ())>> = self.set_range(key);
+        let iter = match res {
+            Ok(_) => Iter::<K, Key, ()>::new(self, ffi::MDBX_GET_CURRENT, ffi::MDBX_NEXT_NODUP),
+            Err(error) => Iter::Err(Some(error)),
+        };
+        iter.map(|item| item.map(|(key, _)| key))
+    }
+
+    /// Iterate over the values of the table items, see [Self::iter]. Keys are not decoded.
+    pub fn iter_values<Value>(
+        &mut self,
+    ) -> impl Iterator<Item = Result<Value>> + use<'_, 'txn, K, Value>
+    where
+        Value: Decodable<'txn> + 'txn,
+    {
+        self.iter::<(), Value>()
+            .map(|item| item.map(|(_, value)| value))
+    }
+
+    /// Iterate over the values of the table items starting from the beginning of the table, see
+    /// [Self::iter_start]. Keys are not decoded.
+    pub fn iter_values_start<Value>(
+        &mut self,
+    ) -> impl Iterator<Item = Result<Value>> + use<'_, 'txn, K, Value>
+    where
+        Value: Decodable<'txn> + 'txn,
+    {
+        self.iter_start::<(), Value>()
+            .map(|item| item.map(|(_, value)| value))
+    }
+
+    /// Iterate over the values of the table items starting from the given key, see
+    /// [Self::iter_from]. Keys are not decoded.
+    pub fn iter_values_from<Value>(
+        &mut self,
+        key: &[u8],
+    ) -> impl Iterator<Item = Result<Value>> + use<'_, 'txn, K, Value>
+    where
+        Value: Decodable<'txn> + 'txn,
+    {
+        self.iter_from::<(), Value>(key)
+            .map(|item| item.map(|(_, value)| value))
+    }
+
+    /// Iterate over the table items whose keys fall within `range`.
+    ///
+    /// Keys are compared using the table's own ordering, so custom comparators and
+    /// [TableFlags::INTEGER_KEY] tables are handled correctly. For tables with duplicate data
+    /// items ([TableFlags::DUP_SORT]), all duplicates of the keys within the range are returned.
+    pub fn iter_range<Key, Value, RangeKey>(
+        &mut self,
+        range: impl RangeBounds<RangeKey>,
+    ) -> IterRange<'txn, '_, K, Key, Value>
+    where
+        Key: Decodable<'txn>,
+        Value: Decodable<'txn>,
+        RangeKey: AsRef<[u8]> + ?Sized,
+    {
+        let op = match range.start_bound() {
+            ops::Bound::Included(start) => self
+                .set_range::<(), ()>(start.as_ref())
+                .map(|found| found.map(|_| MDBX_GET_CURRENT)),
+            ops::Bound::Excluded(start) => match self.set_key::<(), ()>(start.as_ref()) {
+                Ok(Some(_)) => Ok(Some(MDBX_NEXT_NODUP)),
+                Ok(None) => self
+                    .set_range::<(), ()>(start.as_ref())
+                    .map(|found| found.map(|_| MDBX_GET_CURRENT)),
+                Err(error) => Err(error),
+            },
+            ops::Bound::Unbounded => Ok(Some(MDBX_FIRST)),
+        };
+
+        match op {
+            Ok(op) => IterRange::Ok {
+                cursor: self,
+                op,
+                end: range.end_bound().map(|end| end.as_ref().to_vec()),
+                duplicates: false,
+                _marker: PhantomData,
+            },
+            Err(error) => IterRange::Err(Some(error)),
+        }
+    }
+
+    /// [TableFlags::DUP_SORT]-only: Iterate over the duplicates of the given key whose values
+    /// fall within `range`.
+    ///
+    /// Values are compared using the table's own ordering of duplicates, so custom comparators
+    /// and [TableFlags::INTEGER_DUP] tables are handled correctly. Yields nothing if the key is
+    /// absent.
+    pub fn iter_dup_range<Key, Value, RangeValue>(
+        &mut self,
+        key: &[u8],
+        range: impl RangeBounds<RangeValue>,
+    ) -> IterRange<'txn, '_, K, Key, Value>
+    where
+        Key: Decodable<'txn>,
+        Value: Decodable<'txn>,
+        RangeValue: AsRef<[u8]> + ?Sized,
+    {
+        let op = match range.start_bound() {
+            ops::Bound::Included(start) => self
+                .get_both_range::<()>(key, start.as_ref())
+                .map(|found| found.map(|_| MDBX_GET_CURRENT)),
+            ops::Bound::Excluded(start) => match self.get_both::<()>(key, start.as_ref()) {
+                Ok(Some(_)) => Ok(Some(MDBX_NEXT_DUP)),
+                Ok(None) => self
+                    .get_both_range::<()>(key, start.as_ref())
+                    .map(|found| found.map(|_| MDBX_GET_CURRENT)),
+                Err(error) => Err(error),
+            },
+            ops::Bound::Unbounded => self
+                .set::<()>(key)
+                .map(|found| found.map(|_| MDBX_GET_CURRENT)),
+        };
+
+        match op {
+            Ok(op) => IterRange::Ok {
+                cursor: self,
+                op,
+                end: range.end_bound().map(|end| end.as_ref().to_vec()),
+                duplicates: true,
+                _marker: PhantomData,
+            },
+            Err(error) => IterRange::Err(Some(error)),
+        }
+    }
+
+    /// Iterate over table items in reverse order. The iterator will begin with the item
+    /// preceding the cursor, and continue until the start of the table. For new cursors, the
+    /// iterator will begin with the last item in the table.
+    ///
+    /// For tables with duplicate data items ([TableFlags::DUP_SORT]), the duplicate data items
+    /// of each key are returned in reverse order as well, before moving on to the previous key.
+    pub fn iter_prev<Key, Value>(&mut self) -> Iter<'txn, '_, K, Key, Value>
+    where
+        Key: Decodable<'txn>,
+        Value: Decodable<'txn>,
+    {
+        Iter::new(self, ffi::MDBX_PREV, ffi::MDBX_PREV)
+    }
+
+    /// Iterate over table items in reverse order, starting from the end of the table.
+    ///
+    /// For tables with duplicate data items ([TableFlags::DUP_SORT]), the duplicate data items
+    /// of each key are returned in reverse order as well, before moving on to the previous key.
+    pub fn iter_end<Key, Value>(&mut self) -> ExactIter<Iter<'txn, '_, K, Key, Value>>
+    where
+        Key: Decodable<'txn>,
+        Value: Decodable<'txn>,
+    {
+        ExactIter(Iter::new(self, ffi::MDBX_LAST, ffi::MDBX_PREV))
+    }
+
+    /// Iterate over table items in reverse order, starting from the given key, or from the
+    /// closest key preceding it if the key is absent.
+    ///
+    /// For tables with duplicate data items ([TableFlags::DUP_SORT]), the duplicate data items
+    /// of each key are returned in reverse order as well, before moving on to the previous key.
+    pub fn iter_from_rev<Key, Value>(&mut self, key: &[u8]) -> Iter<'txn, '_, K, Key, Value>
+    where
+        Key: Decodable<'txn>,
+        Value: Decodable<'txn>,
+    {
+        match self.seek_rev(key) {
+            Ok(op) => Iter::new(self, op, ffi::MDBX_PREV),
+            Err(error) => Iter::Err(Some(error)),
+        }
+    }
+
+    /// Positions the cursor just past the last item with a key not greater than `key`, and
+    /// returns the operation which retrieves that item.
+    fn seek_rev(&mut self, key: &[u8]) -> Result<MDBX_cursor_op> {
+        // Step past all duplicates of an exact match, so that they are all included.
+        let next = if self.set_key::<(), ()>(key)?.is_some() {
+            self.next_nodup::<(), ()>()?
+        } else {
+            self.set_range::<(), ()>(key)?
+        };
+
+        Ok(if next.is_some() {
+            ffi::MDBX_PREV
+        } else {
+            ffi::MDBX_LAST
+        })
+    }
+
     /// Iterate over duplicate table items. The iterator will begin with the
     /// item next after the cursor, and continue until the end of the table.
     /// Each item will be returned as an iterator of its duplicates.
@@ -448,39 +821,62 @@ where
     }
 
     /// Iterate over the duplicates of the item in the table with the given key.
-    pub fn iter_dup_of<Key, Value>(&mut self, key: &[u8]) -> Iter<'txn, '_, K, Key, Value>
+    pub fn iter_dup_of<Key, Value>(
+        &mut self,
+        key: &[u8],
+    ) -> ExactIter<Iter<'txn, '_, K, Key, Value>>
     where
         Key: Decodable<'txn>,
         Value: Decodable<'txn>,
     {
         let res: Result<Option<()>> = self.set(key);
-        match res {
-            Ok(Some(_)) => (),
-            Ok(None) => {
-                let _: Result<Option<((), ())>> = self.last();
-                return Iter::new(self, ffi::MDBX_NEXT, ffi::MDBX_NEXT);
-            }
-            Err(error) => return Iter::Err(Some(error)),
-        };
-        Iter::new(self, ffi::MDBX_GET_CURRENT, ffi::MDBX_NEXT_DUP)
+        ExactIter(match res {
+            Ok(Some(_)) => Iter::new(self, ffi::MDBX_GET_CURRENT, ffi::MDBX_NEXT_DUP),
+            Ok(None) => Iter::Err(None),
+            Err(error) => Iter::Err(Some(error)),
+        })
     }
 
     /// Iterate over the duplicates of the item in the table with the given key.
-    pub fn into_iter_dup_of<Key, Value>(mut self, key: &[u8]) -> IntoIter<'txn, K, Key, Value>
+    pub fn into_iter_dup_of<Key, Value>(
+        mut self,
+        key: &[u8],
+    ) -> ExactIter<IntoIter<'txn, K, Key, Value>>
     where
         Key: Decodable<'txn>,
         Value: Decodable<'txn>,
     {
         let res: Result<Option<()>> = self.set(key);
-        match res {
-            Ok(Some(_)) => (),
-            Ok(None) => {
-                let _: Result<Option<((), ())>> = self.last();
-                return IntoIter::new(self, ffi::MDBX_NEXT, ffi::MDBX_NEXT);
-            }
-            Err(error) => return IntoIter::Err(Some(error)),
+        ExactIter(match res {
+            Ok(Some(_)) => IntoIter::new(self, ffi::MDBX_GET_CURRENT, ffi::MDBX_NEXT_DUP),
+            Ok(None) => IntoIter::Err(None),
+            Err(error) => IntoIter::Err(Some(error)),
+        })
+    }
+
+    /// [TableFlags::DUP_FIXED]-only: Iterate over the duplicates of the given key, fetching them
+    /// a page at a time with [Self::get_multiple] and [Self::next_multiple].
+    ///
+    /// The size of every value is taken from the first duplicate. Yields nothing if the key is
+    /// absent.
+    pub fn iter_dup_fixed_of<Value>(&mut self, key: &[u8]) -> IterDupFixed<'txn, '_, K, Value>
+    where
+        Value: Decodable<'txn>,
+    {
+        let res: Result<Option<Cow<'txn, [u8]>>> = self.set(key);
+        let (op, value_size) = match res {
+            Ok(Some(first)) => (Some(MDBX_GET_MULTIPLE), first.len()),
+            Ok(None) => (None, 0),
+            Err(error) => return IterDupFixed::Err(Some(error)),
         };
-        IntoIter::new(self, ffi::MDBX_GET_CURRENT, ffi::MDBX_NEXT_DUP)
+        IterDupFixed::Ok {
+            cursor: self,
+            op,
+            value_size,
+            page: (0, 0),
+            offset: 0,
+            _marker: PhantomData,
+        }
     }
 }
 
@@ -505,6 +901,144 @@ impl<'txn> Cursor<'txn, RW> {
         Ok(())
     }
 
+    /// Puts a key/data pair into the table unless the key is already present, in which case the
+    /// existing value is returned, see [Transaction::put_no_overwrite]. The cursor will be
+    /// positioned at the new or the existing item.
+    pub fn put_no_overwrite<Value>(&mut self, key: &[u8], data: &[u8]) -> Result<Option<Value>>
+    where
+        Value: Decodable<'txn>,
+    {
+        let key_val: ffi::MDBX_val = ffi::MDBX_val {
+            iov_len: key.len(),
+            iov_base: key.as_ptr() as *mut c_void,
+        };
+        let mut data_val: ffi::MDBX_val = ffi::MDBX_val {
+            iov_len: data.len(),
+            iov_base: data.as_ptr() as *mut c_void,
+        };
+        txn_execute(&self.txn, |txn| unsafe {
+            match ffi::mdbx_cursor_put(
+                self.cursor.0,
+                &key_val,
+                &mut data_val,
+                ffi::MDBX_NOOVERWRITE,
+            ) {
+                ffi::MDBX_SUCCESS => Ok(None),
+                ffi::MDBX_KEYEXIST => Value::decode_val::<RW>(txn, &data_val).map(Some),
+                err_code => Err(Error::from_err_code(err_code)),
+            }
+        })
+    }
+
+    /// Returns a buffer which can be used to write a value into the item at the given key and with
+    /// the given length, see [Transaction::reserve]. The buffer must be completely filled by the
+    /// caller. The cursor will be positioned at the new data item.
+    ///
+    /// Fails with [Error::Incompatible] on [TableFlags::DUP_SORT] tables, which do not support
+    /// reserving space.
+    pub fn reserve(&mut self, key: &[u8], len: usize, flags: WriteFlags) -> Result<&'txn mut [u8]> {
+        let key_val: ffi::MDBX_val = ffi::MDBX_val {
+            iov_len: key.len(),
+            iov_base: key.as_ptr() as *mut c_void,
+        };
+        let mut data_val: ffi::MDBX_val = ffi::MDBX_val {
+            iov_len: len,
+            iov_base: ptr::null_mut::<c_void>(),
+        };
+        mdbx_result(unsafe {
+            txn_execute(&self.txn, |_| {
+                ffi::mdbx_cursor_put(
+                    self.cursor.0,
+                    &key_val,
+                    &mut data_val,
+                    flags.bits() | ffi::MDBX_RESERVE,
+                )
+            })
+        })?;
+
+        Ok(unsafe { slice::from_raw_parts_mut(data_val.iov_base as *mut u8, data_val.iov_len) })
+    }
+
+    /// Stores multiple contiguous fixed-size data items under `key` in a single call, for tables
+    /// opened with [TableFlags::DUP_FIXED].
+    ///
+    /// `values` is the concatenation of the items, each `value_size` bytes long. Returns how many
+    /// items were actually written, which may be fewer than supplied if MDBX stops early.
+    ///
+    /// Fails with [Error::UnalignedValues] if `values` is not a whole number of items, or with
+    /// [Error::Incompatible] if the table is not [TableFlags::DUP_FIXED].
+    pub fn put_multiple(
+        &mut self,
+        key: &[u8],
+        values: &[u8],
+        value_size: usize,
+        flags: WriteFlags,
+    ) -> Result<usize> {
+        if value_size == 0 || !values.len().is_multiple_of(value_size) {
+            return Err(Error::UnalignedValues {
+                value_size,
+                len: values.len(),
+            });
+        }
+        if values.is_empty() {
+            return Ok(0);
+        }
+
+        let key_val: ffi::MDBX_val = ffi::MDBX_val {
+            iov_len: key.len(),
+            iov_base: key.as_ptr() as *mut c_void,
+        };
+        // MDBX expects the item size in the first element and the item count in the second, and
+        // reports back the number of items written in the latter.
+        let mut data_vals: [ffi::MDBX_val; 2] = [
+            ffi::MDBX_val {
+                iov_len: value_size,
+                iov_base: values.as_ptr() as *mut c_void,
+            },
+            ffi::MDBX_val {
+                iov_len: values.len() / value_size,
+                iov_base: ptr::null_mut::<c_void>(),
+            },
+        ];
+        mdbx_result(unsafe {
+            txn_execute(&self.txn, |txn| {
+                let mut table_flags: c_uint = 0;
+                let mut state: c_uint = 0;
+                let rc = ffi::mdbx_dbi_flags_ex(
+                    txn,
+                    ffi::mdbx_cursor_dbi(self.cursor.0),
+                    &mut table_flags,
+                    &mut state,
+                );
+                if rc != ffi::MDBX_SUCCESS {
+                    return rc;
+                }
+                if !TableFlags::from_bits_truncate(table_flags).contains(TableFlags::DUP_FIXED) {
+                    return ffi::MDBX_INCOMPATIBLE;
+                }
+                ffi::mdbx_cursor_put(
+                    self.cursor.0,
+                    &key_val,
+                    data_vals.as_mut_ptr(),
+                    flags.bits() | ffi::MDBX_MULTIPLE,
+                )
+            })
+        })?;
+
+        Ok(data_vals[1].iov_len)
+    }
+
+    /// Appends a key/data pair to the end of the table, see [Transaction::append].
+    pub fn append(&mut self, key: &[u8], data: &[u8]) -> Result<()> {
+        self.put(key, data, WriteFlags::APPEND)
+    }
+
+    /// Appends a data item to the end of the duplicates of `key`, see
+    /// [Transaction::append_dup].
+    pub fn append_dup(&mut self, key: &[u8], data: &[u8]) -> Result<()> {
+        self.put(key, data, WriteFlags::APPEND_DUP)
+    }
+
     /// Deletes the current key/data pair.
     ///
     /// ### Flags
@@ -527,7 +1061,7 @@ where
     K: TransactionKind,
 {
     fn clone(&self) -> Self {
-        txn_execute(&self.txn, |_| Self::new_at_position(self).unwrap())
+        self.try_clone().unwrap()
     }
 }
 
@@ -545,12 +1079,91 @@ where
     K: TransactionKind,
 {
     fn drop(&mut self) {
-        txn_execute(&self.txn, |_| unsafe {
-            ffi::mdbx_cursor_close(self.cursor.0)
+        // A cursor left open when its transaction ended has been closed by the transaction.
+        let mut state = self.txn.lock();
+        if state.deregister(self.cursor) {
+            unsafe { ffi::mdbx_cursor_close(self.cursor.0) }
+        }
+    }
+}
+
+/// A cursor which is not usable until bound to a transaction.
+///
+/// Binding reuses the cursor's allocation, which is cheaper than opening a new cursor in every
+/// transaction. The resulting [Cursor] borrows the transaction as usual, so a cursor can never
+/// outlive the transaction it is bound to.
+pub struct UnboundCursor {
+    /// The transaction the cursor was last bound to. MDBX detaches the cursor from it when
+    /// binding or closing the cursor, so it must be locked meanwhile.
+    txn: Option<Arc<Mutex<TxnState>>>,
+    cursor: CursorPtr,
+}
+
+impl UnboundCursor {
+    /// Allocates a new cursor.
+    pub fn new() -> Result<Self> {
+        let cursor = unsafe { ffi::mdbx_cursor_create(ptr::null_mut()) };
+        if cursor.is_null() {
+            return Err(Error::Other(libc::ENOMEM));
+        }
+        Ok(Self {
+            txn: None,
+            cursor: CursorPtr(cursor),
+        })
+    }
+
+    /// Binds the cursor to the given table in the given transaction. The cursor is unpositioned
+    /// afterwards.
+    pub fn bind<'txn, K, E>(
+        mut self,
+        txn: &'txn Transaction<K, E>,
+        table: &Table<'txn>,
+    ) -> Result<Cursor<'txn, K>>
+    where
+        K: TransactionKind,
+        E: DatabaseKind,
+    {
+        let new_txn = txn.txn_mutex();
+        let cursor = self.cursor.0;
+        let bind =
+            |txn: *mut ffi::MDBX_txn| unsafe { ffi::mdbx_cursor_bind(txn, cursor, table.dbi()) };
+        // Both transactions are locked in a consistent order, so that concurrently rebinding
+        // cursors cannot deadlock.
+        let rc = match self.txn.as_ref().filter(|old| !Arc::ptr_eq(old, &new_txn)) {
+            None => txn_execute(&new_txn, bind),
+            Some(old) if Arc::as_ptr(old) < Arc::as_ptr(&new_txn) => {
+                txn_execute(old, |_| txn_execute(&new_txn, bind))
+            }
+            Some(old) => txn_execute(&new_txn, |txn| txn_execute(old, |_| bind(txn))),
+        };
+        mdbx_result(rc)?;
+        new_txn.lock().register(CursorPtr(cursor));
+
+        self.txn = None;
+        mem::forget(self);
+        Ok(Cursor {
+            txn: new_txn,
+            cursor: CursorPtr(cursor),
+            _marker: PhantomData,
         })
     }
 }
 
+impl fmt::Debug for UnboundCursor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> result::Result<(), fmt::Error> {
+        f.debug_struct("UnboundCursor").finish()
+    }
+}
+
+impl Drop for UnboundCursor {
+    fn drop(&mut self) {
+        match &self.txn {
+            Some(txn) => txn_execute(txn, |_| unsafe { ffi::mdbx_cursor_close(self.cursor.0) }),
+            None => unsafe { ffi::mdbx_cursor_close(self.cursor.0) },
+        }
+    }
+}
+
 unsafe fn slice_to_val(slice: Option<&[u8]>) -> ffi::MDBX_val {
     match slice {
         Some(slice) => ffi::MDBX_val {
@@ -584,11 +1197,13 @@ where
     Key: Decodable<'txn>,
     Value: Decodable<'txn>,
 {
-    /// An iterator that returns an error on every call to [Iter::next()].
+    /// An iterator that returns an error on the first call to [Iter::next()], and then ends.
     /// Cursor.iter*() creates an Iter of this type when MDBX returns an error
     /// on retrieval of a cursor.  Using this variant instead of returning
     /// an error makes Cursor.iter()* methods infallible, so consumers only
     /// need to check the result of Iter.next().
+    ///
+    /// `Err(None)` is an empty iterator, e.g. for the duplicates of a missing key.
     Err(Option<Error>),
 
     /// An iterator that returns an Item on calls to [Iter::next()].
@@ -605,6 +1220,9 @@ where
         /// The next and subsequent operations to perform.
         next_op: ffi::MDBX_cursor_op,
 
+        /// State of iteration from the back, see [DoubleEndedIterator].
+        ends: IterEnds<'txn, K>,
+
         _marker: PhantomData<fn(&'txn (), K, Key, Value)>,
     },
 }
@@ -621,6 +1239,7 @@ where
             cursor,
             op,
             next_op,
+            ends: IterEnds::new(op, next_op),
             _marker: PhantomData,
         }
     }
@@ -640,44 +1259,49 @@ where
                 cursor,
                 op,
                 next_op,
+                ends,
                 _marker,
-            } => {
-                let mut key = ffi::MDBX_val {
-                    iov_len: 0,
-                    iov_base: ptr::null_mut(),
-                };
-                let mut data = ffi::MDBX_val {
-                    iov_len: 0,
-                    iov_base: ptr::null_mut(),
-                };
-                let op = mem::replace(op, *next_op);
-                unsafe {
-                    txn_execute(&cursor.txn, |txn| {
-                        match ffi::mdbx_cursor_get(cursor.cursor().0, &mut key, &mut data, op) {
-                            ffi::MDBX_SUCCESS => {
-                                let key = match Key::decode_val::<K>(txn, &key) {
-                                    Ok(v) => v,
-                                    Err(e) => return Some(Err(e)),
-                                };
-                                let data = match Value::decode_val::<K>(txn, &data) {
-                                    Ok(v) => v,
-                                    Err(e) => return Some(Err(e)),
-                                };
-                                Some(Ok((key, data)))
-                            }
-                            // MDBX_ENODATA can occur when the cursor was previously seeked to a non-existent value,
-                            // e.g. iter_from with a key greater than all values in the table.
-                            ffi::MDBX_NOTFOUND | ffi::MDBX_ENODATA => None,
-                            error => Some(Err(Error::from_err_code(error))),
-                        }
-                    })
-                }
-            }
+            } => ends.next(cursor, op, *next_op),
+            Self::Err(err) => err.take().map(Err),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Self::Ok { cursor, ends, .. } => ends.size_hint(cursor),
+            Self::Err(err) => (err.is_some() as usize, Some(err.is_some() as usize)),
+        }
+    }
+}
+
+impl<'txn, K, Key, Value> DoubleEndedIterator for IntoIter<'txn, K, Key, Value>
+where
+    K: TransactionKind,
+    Key: Decodable<'txn>,
+    Value: Decodable<'txn>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Self::Ok {
+                cursor,
+                op,
+                next_op,
+                ends,
+                _marker,
+            } => ends.next_back(cursor, op, *next_op),
             Self::Err(err) => err.take().map(Err),
         }
     }
 }
 
+impl<'txn, K, Key, Value> FusedIterator for IntoIter<'txn, K, Key, Value>
+where
+    K: TransactionKind,
+    Key: Decodable<'txn>,
+    Value: Decodable<'txn>,
+{
+}
+
 /// An iterator over the key/value pairs in an MDBX table.
 #[derive(Debug)]
 pub enum Iter<'txn, 'cur, K, Key, Value>
@@ -686,11 +1310,13 @@ where
     Key: Decodable<'txn>,
     Value: Decodable<'txn>,
 {
-    /// An iterator that returns an error on every call to [Iter::next()].
+    /// An iterator that returns an error on the first call to [Iter::next()], and then ends.
     /// Cursor.iter*() creates an Iter of this type when MDBX returns an error
     /// on retrieval of a cursor.  Using this variant instead of returning
     /// an error makes Cursor.iter()* methods infallible, so consumers only
     /// need to check the result of Iter.next().
+    ///
+    /// `Err(None)` is an empty iterator, e.g. for the duplicates of a missing key.
     Err(Option<Error>),
 
     /// An iterator that returns an Item on calls to [Iter::next()].
@@ -707,6 +1333,9 @@ where
         /// The next and subsequent operations to perform.
         next_op: ffi::MDBX_cursor_op,
 
+        /// State of iteration from the back, see [DoubleEndedIterator].
+        ends: IterEnds<'txn, K>,
+
         _marker: PhantomData<fn(&'txn (Key, Value))>,
     },
 }
@@ -727,6 +1356,7 @@ where
             cursor,
             op,
             next_op,
+            ends: IterEnds::new(op, next_op),
             _marker: PhantomData,
         }
     }
@@ -746,6 +1376,153 @@ where
                 cursor,
                 op,
                 next_op,
+                ends,
+                ..
+            } => ends.next(cursor, op, *next_op),
+            Iter::Err(err) => err.take().map(Err),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            Iter::Ok { cursor, ends, .. } => ends.size_hint(cursor),
+            Iter::Err(err) => (err.is_some() as usize, Some(err.is_some() as usize)),
+        }
+    }
+}
+
+impl<'txn, 'cur, K, Key, Value> DoubleEndedIterator for Iter<'txn, 'cur, K, Key, Value>
+where
+    K: TransactionKind,
+    Key: Decodable<'txn>,
+    Value: Decodable<'txn>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match self {
+            Iter::Ok {
+                cursor,
+                op,
+                next_op,
+                ends,
+                ..
+            } => ends.next_back(cursor, op, *next_op),
+            Iter::Err(err) => err.take().map(Err),
+        }
+    }
+}
+
+impl<'txn, 'cur, K, Key, Value> FusedIterator for Iter<'txn, 'cur, K, Key, Value>
+where
+    K: TransactionKind,
+    Key: Decodable<'txn>,
+    Value: Decodable<'txn>,
+{
+}
+
+/// An iterator over all key/value pairs in an MDBX table, or over all duplicates of a single key.
+///
+/// Created by [Cursor::iter_start], [Cursor::iter_end], [Cursor::iter_dup_of] and their `into_`
+/// counterparts. The number of remaining items is exact in read-only transactions, for which
+/// [ExactSizeIterator] is implemented.
+#[derive(Debug)]
+pub struct ExactIter<I>(I);
+
+impl<I> ExactIter<I> {
+    /// Returns the underlying [Iter] or [IntoIter].
+    pub fn into_inner(self) -> I {
+        self.0
+    }
+}
+
+impl<I> Iterator for ExactIter<I>
+where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.0.size_hint()
+    }
+}
+
+impl<I> DoubleEndedIterator for ExactIter<I>
+where
+    I: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.0.next_back()
+    }
+}
+
+impl<I> FusedIterator for ExactIter<I> where I: FusedIterator {}
+
+impl<'txn, 'cur, Key, Value> ExactSizeIterator for ExactIter<Iter<'txn, 'cur, RO, Key, Value>>
+where
+    Key: Decodable<'txn>,
+    Value: Decodable<'txn>,
+{
+}
+
+impl<'txn, Key, Value> ExactSizeIterator for ExactIter<IntoIter<'txn, RO, Key, Value>>
+where
+    Key: Decodable<'txn>,
+    Value: Decodable<'txn>,
+{
+}
+
+/// An iterator over the key/value pairs within a range of keys in an MDBX table, or within a
+/// range of the duplicates of a single key.
+///
+/// Created by [Cursor::iter_range] and [Cursor::iter_dup_range].
+#[derive(Debug)]
+pub enum IterRange<'txn, 'cur, K, Key, Value>
+where
+    K: TransactionKind,
+    Key: Decodable<'txn>,
+    Value: Decodable<'txn>,
+{
+    /// An iterator that returns an error on the first call to [IterRange::next()], and then ends.
+    /// Cursor.iter_range() creates an IterRange of this type when MDBX returns an error
+    /// while seeking to the start of the range.
+    Err(Option<Error>),
+
+    /// An iterator that returns an Item on calls to [IterRange::next()].
+    Ok {
+        /// The MDBX cursor with which to iterate.
+        cursor: &'cur mut Cursor<'txn, K>,
+
+        /// The next operation to perform, or [None] once the end of the range has been reached.
+        op: Option<ffi::MDBX_cursor_op>,
+
+        /// The end bound of the range.
+        end: ops::Bound<Vec<u8>>,
+
+        /// Whether the range bounds the duplicates of a single key, rather than the keys.
+        duplicates: bool,
+
+        _marker: PhantomData<fn(&'txn (Key, Value))>,
+    },
+}
+
+impl<'txn, 'cur, K, Key, Value> Iterator for IterRange<'txn, 'cur, K, Key, Value>
+where
+    K: TransactionKind,
+    Key: Decodable<'txn>,
+    Value: Decodable<'txn>,
+{
+    type Item = Result<(Key, Value)>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            IterRange::Ok {
+                cursor,
+                op,
+                end,
+                duplicates,
                 ..
             } => {
                 let mut key = ffi::MDBX_val {
@@ -756,11 +1533,35 @@ where
                     iov_len: 0,
                     iov_base: ptr::null_mut(),
                 };
-                let op = mem::replace(op, *next_op);
+                let this_op = op.replace(if *duplicates {
+                    MDBX_NEXT_DUP
+                } else {
+                    MDBX_NEXT
+                })?;
                 unsafe {
                     txn_execute(&cursor.txn, |txn| {
-                        match ffi::mdbx_cursor_get(cursor.cursor().0, &mut key, &mut data, op) {
+                        match ffi::mdbx_cursor_get(cursor.cursor().0, &mut key, &mut data, this_op)
+                        {
                             ffi::MDBX_SUCCESS => {
+                                let past_end = |end: &[u8]| {
+                                    let end = slice_to_val(Some(end));
+                                    let dbi = ffi::mdbx_cursor_dbi(cursor.cursor().0);
+                                    if *duplicates {
+                                        ffi::mdbx_dcmp(txn, dbi, &data, &end)
+                                    } else {
+                                        ffi::mdbx_cmp(txn, dbi, &key, &end)
+                                    }
+                                };
+                                let past_end = match end {
+                                    ops::Bound::Included(end) => past_end(end) > 0,
+                                    ops::Bound::Excluded(end) => past_end(end) >= 0,
+                                    ops::Bound::Unbounded => false,
+                                };
+                                if past_end {
+                                    *op = None;
+                                    return None;
+                                }
+
                                 let key = match Key::decode_val::<K>(txn, &key) {
                                     Ok(v) => v,
                                     Err(e) => return Some(Err(e)),
@@ -771,15 +1572,295 @@ where
                                 };
                                 Some(Ok((key, data)))
                             }
-                            // MDBX_NODATA can occur when the cursor was previously seeked to a non-existent value,
-                            // e.g. iter_from with a key greater than all values in the table.
-                            ffi::MDBX_NOTFOUND | ffi::MDBX_ENODATA => None,
-                            error => Some(Err(Error::from_err_code(error))),
+                            ffi::MDBX_NOTFOUND | ffi::MDBX_ENODATA => {
+                                *op = None;
+                                None
+                            }
+                            error => {
+                                *op = None;
+                                Some(Err(Error::from_err_code(error)))
+                            }
                         }
                     })
                 }
             }
-            Iter::Err(err) => err.take().map(Err),
+            IterRange::Err(err) => err.take().map(Err),
+        }
+    }
+
+    /// In read-only transactions, the upper bound is the number of items in the table, or of
+    /// duplicates of the key. MDBX only estimates the length of a range, which may exceed the
+    /// actual one, so the lower bound is zero. Write transactions may add or remove items during
+    /// iteration, so no hint is given.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            IterRange::Ok {
+                cursor,
+                op,
+                duplicates,
+                ..
+            } => {
+                if op.is_none() {
+                    return (0, Some(0));
+                }
+                if !K::ONLY_CLEAN {
+                    return (0, None);
+                }
+
+                let total = if *duplicates {
+                    cursor.count()
+                } else {
+                    cursor.entries()
+                };
+                match total {
+                    Ok(total) => (0, Some(total)),
+                    Err(_) => (0, None),
+                }
+            }
+            IterRange::Err(err) => (err.is_some() as usize, Some(err.is_some() as usize)),
+        }
+    }
+}
+
+impl<'txn, 'cur, K, Key, Value> FusedIterator for IterRange<'txn, 'cur, K, Key, Value>
+where
+    K: TransactionKind,
+    Key: Decodable<'txn>,
+    Value: Decodable<'txn>,
+{
+}
+
+/// Position of an item, identified by the addresses of its key and data within the
+/// transaction's view of the database.
+type ItemPos = (usize, usize);
+
+/// Retrieves and decodes the item the given cursor operation moves to, along with its position.
+fn fetch<'txn, K, Key, Value>(
+    cursor: &Cursor<'txn, K>,
+    op: MDBX_cursor_op,
+) -> Option<(Option<ItemPos>, Result<(Key, Value)>)>
+where
+    K: TransactionKind,
+    Key: Decodable<'txn>,
+    Value: Decodable<'txn>,
+{
+    let mut key = ffi::MDBX_val {
+        iov_len: 0,
+        iov_base: ptr::null_mut(),
+    };
+    let mut data = ffi::MDBX_val {
+        iov_len: 0,
+        iov_base: ptr::null_mut(),
+    };
+    unsafe {
+        txn_execute(&cursor.txn, |txn| {
+            let mut res = ffi::mdbx_cursor_get(cursor.cursor().0, &mut key, &mut data, op);
+            if res == ffi::MDBX_SUCCESS && key.iov_base.is_null() {
+                // Some operations, like MDBX_LAST_DUP, only return the data.
+                res =
+                    ffi::mdbx_cursor_get(cursor.cursor().0, &mut key, &mut data, MDBX_GET_CURRENT);
+            }
+            match res {
+                ffi::MDBX_SUCCESS => {
+                    let pos = (key.iov_base as usize, data.iov_base as usize);
+                    let item = Key::decode_val::<K>(txn, &key)
+                        .and_then(|key| Value::decode_val::<K>(txn, &data).map(|data| (key, data)));
+                    Some((Some(pos), item))
+                }
+                // MDBX_ENODATA can occur when the cursor was previously seeked to a non-existent value,
+                // e.g. iter_from with a key greater than all values in the table.
+                ffi::MDBX_NOTFOUND | ffi::MDBX_ENODATA => None,
+                error => Some((None, Err(Error::from_err_code(error)))),
+            }
+        })
+    }
+}
+
+/// Bookkeeping which lets [Iter] and [IntoIter] be consumed from both ends.
+///
+/// The back end walks a second cursor in the opposite direction. Both ends remember the last item
+/// they yielded, and iteration stops as soon as one end reaches an item already yielded by the
+/// other.
+#[derive(Debug)]
+pub struct IterEnds<'txn, K>
+where
+    K: TransactionKind,
+{
+    /// The cursor of the back end, with its next and subsequent operations.
+    back: Option<(Cursor<'txn, K>, MDBX_cursor_op, MDBX_cursor_op)>,
+    front_last: Option<ItemPos>,
+    back_last: Option<ItemPos>,
+    /// The first item of the front end, if the back end started before the front end did.
+    front_first: Option<ItemPos>,
+    /// Number of items yielded from both ends.
+    yielded: usize,
+    /// Whether the iteration covers the whole table, or all duplicates of a single key.
+    extent: Extent,
+    done: bool,
+}
+
+#[derive(Debug, PartialEq)]
+enum Extent {
+    Table,
+    Duplicates,
+    Range,
+}
+
+impl<'txn, K> IterEnds<'txn, K>
+where
+    K: TransactionKind,
+{
+    fn new(op: MDBX_cursor_op, next_op: MDBX_cursor_op) -> Self {
+        let extent = match (op, next_op) {
+            (MDBX_FIRST, MDBX_NEXT) | (MDBX_LAST, MDBX_PREV) => Extent::Table,
+            (MDBX_GET_CURRENT, MDBX_NEXT_DUP) => Extent::Duplicates,
+            _ => Extent::Range,
+        };
+        Self {
+            back: None,
+            front_last: None,
+            back_last: None,
+            front_first: None,
+            yielded: 0,
+            extent,
+            done: false,
+        }
+    }
+
+    /// In read-only transactions, the number of remaining items is exact when iterating over a
+    /// whole table or all duplicates of a key. Other iterations are only bounded by the number of
+    /// items in the table.
+    ///
+    /// Write transactions may add or remove items during iteration, so no hint is given.
+    fn size_hint(&self, cursor: &Cursor<'txn, K>) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        if !K::ONLY_CLEAN {
+            return (0, None);
+        }
+
+        let total = if self.extent == Extent::Duplicates {
+            cursor.count()
+        } else {
+            cursor.entries()
+        };
+        let Ok(total) = total else {
+            return (0, None);
+        };
+
+        let remaining = total.saturating_sub(self.yielded);
+        match self.extent {
+            Extent::Table | Extent::Duplicates => (remaining, Some(remaining)),
+            // MDBX only estimates the length of a range, which may exceed the actual one.
+            Extent::Range => (0, Some(remaining)),
+        }
+    }
+
+    fn next<Key, Value>(
+        &mut self,
+        cursor: &Cursor<'txn, K>,
+        op: &mut MDBX_cursor_op,
+        next_op: MDBX_cursor_op,
+    ) -> Option<Result<(Key, Value)>>
+    where
+        Key: Decodable<'txn>,
+        Value: Decodable<'txn>,
+    {
+        if self.done {
+            return None;
+        }
+
+        let op = mem::replace(op, next_op);
+        match fetch(cursor, op) {
+            Some((Some(pos), _)) if self.back_last == Some(pos) => {
+                self.done = true;
+                None
+            }
+            Some((pos, item)) => {
+                self.front_last = pos.or(self.front_last);
+                self.yielded += pos.is_some() as usize;
+                // Without a position, the item is an error from MDBX rather than from decoding.
+                self.done |= pos.is_none();
+                Some(item)
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+
+    fn next_back<Key, Value>(
+        &mut self,
+        cursor: &Cursor<'txn, K>,
+        op: &mut MDBX_cursor_op,
+        next_op: MDBX_cursor_op,
+    ) -> Option<Result<(Key, Value)>>
+    where
+        Key: Decodable<'txn>,
+        Value: Decodable<'txn>,
+    {
+        if self.done {
+            return None;
+        }
+
+        if self.back.is_none() {
+            let (back_op, back_next_op) = match next_op {
+                MDBX_NEXT => (MDBX_LAST, MDBX_PREV),
+                MDBX_NEXT_DUP => (MDBX_LAST_DUP, MDBX_PREV_DUP),
+                MDBX_PREV => (MDBX_FIRST, MDBX_NEXT),
+                MDBX_PREV_DUP => (MDBX_FIRST_DUP, MDBX_NEXT_DUP),
+                _ => {
+                    self.done = true;
+                    return Some(Err(Error::Incompatible));
+                }
+            };
+
+            if self.front_last.is_none() {
+                // Move the front end onto its first item, so that the back end knows where to
+                // stop.
+                match fetch::<K, (), ()>(cursor, mem::replace(op, MDBX_GET_CURRENT)) {
+                    Some((pos, Ok(_))) => self.front_first = pos,
+                    Some((_, Err(e))) => {
+                        self.done = true;
+                        return Some(Err(e));
+                    }
+                    None => {
+                        self.done = true;
+                        return None;
+                    }
+                }
+            }
+
+            match Cursor::new_at_position(cursor) {
+                Ok(back_cursor) => self.back = Some((back_cursor, back_op, back_next_op)),
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e));
+                }
+            }
+        }
+
+        let (back_cursor, back_op, back_next_op) = self.back.as_mut().unwrap();
+        let back_op = mem::replace(back_op, *back_next_op);
+        match fetch(back_cursor, back_op) {
+            Some((Some(pos), _)) if self.front_last == Some(pos) => {
+                self.done = true;
+                None
+            }
+            Some((pos, item)) => {
+                self.back_last = pos.or(self.back_last);
+                self.yielded += pos.is_some() as usize;
+                if pos.is_none() || (pos == self.front_first && self.front_last.is_none()) {
+                    self.done = true;
+                }
+                Some(item)
+            }
+            None => {
+                self.done = true;
+                None
+            }
         }
     }
 }
@@ -794,7 +1875,7 @@ where
     Key: Decodable<'txn>,
     Value: Decodable<'txn>,
 {
-    /// An iterator that returns an error on every call to Iter.next().
+    /// An iterator that returns an error on the first call to Iter.next(), and then ends.
     /// Cursor.iter*() creates an Iter of this type when MDBX returns an error
     /// on retrieval of a cursor.  Using this variant instead of returning
     /// an error makes Cursor.iter()* methods infallible, so consumers only
@@ -864,22 +1945,153 @@ where
                 };
                 let op = mem::replace(op, ffi::MDBX_NEXT_NODUP);
 
-                txn_execute(&cursor.txn, |_| {
-                    let err_code =
-                        unsafe { ffi::mdbx_cursor_get(cursor.cursor().0, &mut key, &mut data, op) };
-
-                    if err_code == ffi::MDBX_SUCCESS {
-                        Some(IntoIter::new(
-                            Cursor::new_at_position(&**cursor).unwrap(),
-                            ffi::MDBX_GET_CURRENT,
-                            ffi::MDBX_NEXT_DUP,
-                        ))
-                    } else {
-                        None
-                    }
-                })
+                let err_code = txn_execute(&cursor.txn, |_| unsafe {
+                    ffi::mdbx_cursor_get(cursor.cursor().0, &mut key, &mut data, op)
+                });
+
+                let item = match err_code {
+                    ffi::MDBX_SUCCESS => match Cursor::new_at_position(&**cursor) {
+                        Ok(cursor) => {
+                            return Some(IntoIter::new(
+                                cursor,
+                                ffi::MDBX_GET_CURRENT,
+                                ffi::MDBX_NEXT_DUP,
+                            ))
+                        }
+                        Err(e) => Some(IntoIter::Err(Some(e))),
+                    },
+                    ffi::MDBX_NOTFOUND | ffi::MDBX_ENODATA => None,
+                    error => Some(IntoIter::Err(Some(Error::from_err_code(error)))),
+                };
+                // Stay exhausted, even if items are added past the cursor meanwhile.
+                *self = IterDup::Err(None);
+                item
             }
             IterDup::Err(err) => err.take().map(|e| IntoIter::Err(Some(e))),
         }
     }
 }
+
+impl<'txn, 'cur, K, Key, Value> FusedIterator for IterDup<'txn, 'cur, K, Key, Value>
+where
+    K: TransactionKind,
+    Key: Decodable<'txn>,
+    Value: Decodable<'txn>,
+{
+}
+
+/// An iterator over the fixed-size duplicates of a single key, see [Cursor::iter_dup_fixed_of].
+pub enum IterDupFixed<'txn, 'cur, K, Value>
+where
+    K: TransactionKind,
+    Value: Decodable<'txn>,
+{
+    /// An iterator that returns an error on the first call to next(), and then ends.
+    Err(Option<Error>),
+
+    /// An iterator that returns the values of the current page before fetching the next one.
+    Ok {
+        /// The MDBX cursor with which to iterate.
+        cursor: &'cur mut Cursor<'txn, K>,
+
+        /// The operation fetching the next page, or [None] once all pages were fetched.
+        op: Option<MDBX_cursor_op>,
+
+        /// The size of every value.
+        value_size: usize,
+
+        /// Address and length of the current page of values.
+        page: (usize, usize),
+
+        /// Offset of the next value within the current page.
+        offset: usize,
+
+        _marker: PhantomData<fn(&'txn Value)>,
+    },
+}
+
+impl<'txn, 'cur, K, Value> fmt::Debug for IterDupFixed<'txn, 'cur, K, Value>
+where
+    K: TransactionKind,
+    Value: Decodable<'txn>,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> result::Result<(), fmt::Error> {
+        f.debug_struct("IterDupFixed").finish()
+    }
+}
+
+impl<'txn, 'cur, K, Value> Iterator for IterDupFixed<'txn, 'cur, K, Value>
+where
+    K: TransactionKind,
+    Value: Decodable<'txn>,
+{
+    type Item = Result<Value>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            IterDupFixed::Ok {
+                cursor,
+                op,
+                value_size,
+                page,
+                offset,
+                ..
+            } => txn_execute(&cursor.txn, |txn| loop {
+                if *offset < page.1 {
+                    let value = ffi::MDBX_val {
+                        iov_len: *value_size,
+                        iov_base: (page.0 + *offset) as *mut c_void,
+                    };
+                    *offset += *value_size;
+                    return Some(unsafe { Value::decode_val::<K>(txn, &value) });
+                }
+
+                let mut key = ffi::MDBX_val {
+                    iov_len: 0,
+                    iov_base: ptr::null_mut(),
+                };
+                let mut data = ffi::MDBX_val {
+                    iov_len: 0,
+                    iov_base: ptr::null_mut(),
+                };
+                let this_op = op.replace(MDBX_NEXT_MULTIPLE)?;
+                let mut rc = unsafe {
+                    ffi::mdbx_cursor_get(cursor.cursor().0, &mut key, &mut data, this_op)
+                };
+                if this_op == MDBX_GET_MULTIPLE && rc == ffi::MDBX_NOTFOUND {
+                    // A key with a single value has no page of duplicates to return.
+                    rc = unsafe {
+                        ffi::mdbx_cursor_get(
+                            cursor.cursor().0,
+                            &mut key,
+                            &mut data,
+                            MDBX_GET_CURRENT,
+                        )
+                    };
+                }
+                match rc {
+                    ffi::MDBX_SUCCESS => {
+                        *page = (data.iov_base as usize, data.iov_len);
+                        *offset = 0;
+                    }
+                    ffi::MDBX_NOTFOUND | ffi::MDBX_ENODATA => {
+                        *op = None;
+                        return None;
+                    }
+                    error => {
+                        *op = None;
+                        return Some(Err(Error::from_err_code(error)));
+                    }
+                }
+            }),
+            IterDupFixed::Err(err) => err.take().map(Err),
+        }
+    }
+}
+
+impl<'txn, 'cur, K, Value> FusedIterator for IterDupFixed<'txn, 'cur, K, Value>
+where
+    K: TransactionKind,
+    Value: Decodable<'txn>,
+{
+}
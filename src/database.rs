@@ -1,29 +1,43 @@
 use crate::{
     error::{mdbx_result, Error, Result},
+    slow_reader,
     table::Table,
-    transaction::{RO, RW},
-    Mode, ReadWriteOptions, SyncMode, Transaction, TransactionKind,
+    transaction::{duration_from_16dot16, txn_execute, CommitLatency, RO, RW},
+    CopyFlags, DeleteMode, Geometry, Mode, ReadWriteOptions, RuntimeFlags, SyncMode, TableFlags,
+    Transaction, TransactionKind, WarmupFlags,
 };
-use libc::c_uint;
+use libc::{c_int, c_uint, c_void};
 use mem::size_of;
+use parking_lot::Mutex;
 use sealed::sealed;
 use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
     ffi::CString,
     fmt,
     fmt::Debug,
+    fs::File,
+    io,
     marker::PhantomData,
     mem,
     ops::Deref,
-    os::unix::ffi::OsStrExt,
+    os::{
+        fd::{AsFd, AsRawFd, BorrowedFd},
+        unix::ffi::OsStrExt,
+    },
     path::Path,
     ptr, result,
-    sync::mpsc::{sync_channel, SyncSender},
+    sync::{
+        mpsc::{sync_channel, SyncSender},
+        Arc,
+    },
     thread::sleep,
-    time::Duration,
+    time::{Duration, Instant},
 };
 
 #[sealed]
-pub trait DatabaseKind: Debug + 'static {
+pub trait DatabaseKind: Debug + Send + Sync + 'static {
     const EXTRA_FLAGS: ffi::MDBX_env_flags_t;
 }
 
@@ -41,6 +55,33 @@ impl DatabaseKind for WriteMap {
     const EXTRA_FLAGS: ffi::MDBX_env_flags_t = ffi::MDBX_WRITEMAP;
 }
 
+/// A database kind which always opens the database in [Mode::ReadOnly], whatever the options.
+///
+/// Read-write transactions cannot even be started, as this kind is not [Writable]:
+///
+/// ```compile_fail
+/// # use libmdbx::{Database, ReadOnly};
+/// let dir = tempfile::tempdir().unwrap();
+/// let db = Database::<ReadOnly>::open(&dir).unwrap();
+/// db.begin_rw_txn().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct ReadOnly;
+
+#[sealed]
+impl DatabaseKind for ReadOnly {
+    const EXTRA_FLAGS: ffi::MDBX_env_flags_t = ffi::MDBX_RDONLY;
+}
+
+/// A [DatabaseKind] on which read-write transactions can be started.
+#[sealed]
+pub trait Writable: DatabaseKind {}
+
+#[sealed]
+impl Writable for NoWriteMap {}
+#[sealed]
+impl Writable for WriteMap {}
+
 #[derive(Copy, Clone, Debug)]
 pub struct TxnPtr(pub *mut ffi::MDBX_txn);
 unsafe impl Send for TxnPtr {}
@@ -62,7 +103,7 @@ pub(crate) enum TxnManagerMessage {
     },
     Commit {
         tx: TxnPtr,
-        sender: SyncSender<Result<bool>>,
+        sender: SyncSender<Result<(bool, CommitLatency)>>,
     },
 }
 
@@ -73,29 +114,124 @@ where
 {
     inner: DbPtr,
     pub(crate) txn_manager: Option<SyncSender<TxnManagerMessage>>,
+    user_ctx: Mutex<Option<Box<UserContexts>>>,
+    /// Custom comparators each table has been opened with, by name.
+    pub(crate) comparators: Mutex<HashMap<Option<String>, (Option<usize>, Option<usize>)>>,
+    /// Handles of named tables which stay valid across transactions, by name.
+    pub(crate) dbis: Mutex<HashMap<String, ffi::MDBX_dbi>>,
     _marker: PhantomData<E>,
 }
 
+/// User contexts attached to a database, by type.
+type UserContexts = HashMap<TypeId, Arc<dyn Any + Send + Sync>>;
+
+fn sync_mode_flags(sync_mode: SyncMode) -> ffi::MDBX_env_flags_t {
+    match sync_mode {
+        SyncMode::Durable => ffi::MDBX_SYNC_DURABLE,
+        SyncMode::NoMetaSync => ffi::MDBX_NOMETASYNC,
+        SyncMode::SafeNoSync => ffi::MDBX_SAFE_NOSYNC,
+        SyncMode::UtterlyNoSync => ffi::MDBX_UTTERLY_NOSYNC,
+    }
+}
+
+/// Runtime tuning knobs, mirroring `MDBX_option_t`.
+///
+/// See the MDBX documentation of the corresponding `MDBX_opt_*` constant for the meaning and valid
+/// range of each option.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum MdbxOption {
+    MaxTables,
+    MaxReaders,
+    /// Amount of unsynced data in bytes after which a write transaction flushes the database to
+    /// disk, for the lazy [SyncMode]s. Zero disables it.
+    SyncBytes,
+    /// Time after which a write transaction flushes unsynced data to disk, for the lazy
+    /// [SyncMode]s, in 1/65536 seconds. Zero disables it.
+    SyncPeriod,
+    RpAugmentLimit,
+    LooseLimit,
+    DpReserveLimit,
+    TxnDpLimit,
+    TxnDpInitial,
+    SpillMaxDenominator,
+    SpillMinDenominator,
+    SpillParent4ChildDenominator,
+    /// Fill threshold of a page below which it is merged with a neighbour, in 16.16 fixed point
+    /// where 65536 is a full page. Between 8192 (12.5%) and 32768 (50%), defaults to 16384 (25%).
+    MergeThreshold16Dot16Percent,
+    WritethroughThreshold,
+    PrefaultWriteEnable,
+}
+
+impl MdbxOption {
+    fn as_raw(self) -> ffi::MDBX_option_t {
+        match self {
+            MdbxOption::MaxTables => ffi::MDBX_opt_max_db,
+            MdbxOption::MaxReaders => ffi::MDBX_opt_max_readers,
+            MdbxOption::SyncBytes => ffi::MDBX_opt_sync_bytes,
+            MdbxOption::SyncPeriod => ffi::MDBX_opt_sync_period,
+            MdbxOption::RpAugmentLimit => ffi::MDBX_opt_rp_augment_limit,
+            MdbxOption::LooseLimit => ffi::MDBX_opt_loose_limit,
+            MdbxOption::DpReserveLimit => ffi::MDBX_opt_dp_reserve_limit,
+            MdbxOption::TxnDpLimit => ffi::MDBX_opt_txn_dp_limit,
+            MdbxOption::TxnDpInitial => ffi::MDBX_opt_txn_dp_initial,
+            MdbxOption::SpillMaxDenominator => ffi::MDBX_opt_spill_max_denominator,
+            MdbxOption::SpillMinDenominator => ffi::MDBX_opt_spill_min_denominator,
+            MdbxOption::SpillParent4ChildDenominator => {
+                ffi::MDBX_opt_spill_parent4child_denominator
+            }
+            MdbxOption::MergeThreshold16Dot16Percent => {
+                ffi::MDBX_opt_merge_threshold_16dot16_percent
+            }
+            MdbxOption::WritethroughThreshold => ffi::MDBX_opt_writethrough_threshold,
+            MdbxOption::PrefaultWriteEnable => ffi::MDBX_opt_prefault_write_enable,
+        }
+    }
+}
+
 #[derive(Clone, Default)]
 pub struct DatabaseOptions {
+    /// Unix permission bits for newly created data and lock files. Defaults to `0o644`.
     pub permissions: Option<ffi::mdbx_mode_t>,
     pub max_readers: Option<c_uint>,
     pub max_tables: Option<u64>,
+    /// Maximal number of free pages gathered from the GC to find a run of pages for a large value,
+    /// before allocating the run at the end of the database instead. Defaults to a third of the
+    /// database pages.
     pub rp_augment_limit: Option<u64>,
     pub loose_limit: Option<u64>,
     pub dp_reserve_limit: Option<u64>,
+    /// Maximal number of dirty pages a write transaction keeps in memory before spilling some to
+    /// disk. Raise it if large transactions fail with [Error::TxnFull]. Defaults to 65536.
     pub txn_dp_limit: Option<u64>,
+    /// Number of dirty pages a write transaction makes room for initially. Capped by
+    /// [DatabaseOptions::txn_dp_limit], and raises it if that is unset. Defaults to 1024.
+    pub txn_dp_initial: Option<u64>,
+    /// Fill threshold of a page in percent, below which it is merged with a neighbour. Between
+    /// 12.5 and 50, defaults to 25. Raising it keeps the database more compact when deleting.
+    pub merge_threshold_percent: Option<f64>,
+    /// Spill all but 1/N of the dirty pages at most when spilling, or all of them with 0.
+    /// Defaults to 8.
     pub spill_max_denominator: Option<u64>,
+    /// Spill 1/N of the dirty pages at least when spilling, or just as many as needed with 0.
+    /// Defaults to 8.
     pub spill_min_denominator: Option<u64>,
     pub page_size: Option<PageSize>,
     pub no_sub_dir: bool,
+    /// Open the database for use by this handle only, e.g. for maintenance. Opening fails with
+    /// [Error::Busy] while another handle or process uses the database, and other handles cannot
+    /// open it meanwhile.
     pub exclusive: bool,
+    /// Adopt the sync mode and other mode flags of the processes already using the database,
+    /// instead of failing with [Error::Incompatible] if they differ, e.g. for a monitoring process.
     pub accede: bool,
     pub mode: Mode,
     pub no_rdahead: bool,
     pub no_meminit: bool,
     pub coalesce: bool,
     pub liforeclaim: bool,
+    /// Additional options applied before opening, after the dedicated fields above.
+    pub extra_options: Vec<(MdbxOption, u64)>,
 }
 
 impl DatabaseOptions {
@@ -119,12 +255,7 @@ impl DatabaseOptions {
                 flags |= ffi::MDBX_RDONLY;
             }
             Mode::ReadWrite(ReadWriteOptions { sync_mode, .. }) => {
-                flags |= match sync_mode {
-                    SyncMode::Durable => ffi::MDBX_SYNC_DURABLE,
-                    SyncMode::NoMetaSync => ffi::MDBX_NOMETASYNC,
-                    SyncMode::SafeNoSync => ffi::MDBX_SAFE_NOSYNC,
-                    SyncMode::UtterlyNoSync => ffi::MDBX_UTTERLY_NOSYNC,
-                };
+                flags |= sync_mode_flags(sync_mode);
             }
         }
 
@@ -159,10 +290,27 @@ where
         Self::open_with_options(path, Default::default())
     }
 
+    /// Open an existing database in read-only mode.
+    ///
+    /// Fails if the database does not exist. Read-write transactions cannot be started on the
+    /// returned handle.
+    pub fn open_read_only(path: impl AsRef<Path>) -> Result<Database<E>> {
+        Self::open_with_options(
+            path,
+            DatabaseOptions {
+                mode: Mode::ReadOnly,
+                ..Default::default()
+            },
+        )
+    }
+
     pub fn open_with_options(
         path: impl AsRef<Path>,
-        options: DatabaseOptions,
+        mut options: DatabaseOptions,
     ) -> Result<Database<E>> {
+        if E::EXTRA_FLAGS & ffi::MDBX_RDONLY != 0 {
+            options.mode = Mode::ReadOnly;
+        }
         let mut db: *mut ffi::MDBX_env = ptr::null_mut();
         unsafe {
             mdbx_result(ffi::mdbx_env_create(&mut db))?;
@@ -190,35 +338,50 @@ where
                     ))?;
                 }
                 for (opt, v) in [
-                    (ffi::MDBX_opt_max_db, options.max_tables),
-                    (ffi::MDBX_opt_rp_augment_limit, options.rp_augment_limit),
-                    (ffi::MDBX_opt_loose_limit, options.loose_limit),
-                    (ffi::MDBX_opt_dp_reserve_limit, options.dp_reserve_limit),
-                    (ffi::MDBX_opt_txn_dp_limit, options.txn_dp_limit),
+                    (MdbxOption::MaxTables, options.max_tables),
+                    (MdbxOption::MaxReaders, options.max_readers.map(u64::from)),
+                    (MdbxOption::RpAugmentLimit, options.rp_augment_limit),
+                    (MdbxOption::LooseLimit, options.loose_limit),
+                    (MdbxOption::DpReserveLimit, options.dp_reserve_limit),
+                    // Before the limit, which would otherwise be raised to the initial value.
+                    (MdbxOption::TxnDpInitial, options.txn_dp_initial),
+                    (MdbxOption::TxnDpLimit, options.txn_dp_limit),
+                    (
+                        MdbxOption::MergeThreshold16Dot16Percent,
+                        options
+                            .merge_threshold_percent
+                            .map(|percent| (percent * 65536.0 / 100.0).round() as u64),
+                    ),
                     (
-                        ffi::MDBX_opt_spill_max_denominator,
+                        MdbxOption::SpillMaxDenominator,
                         options.spill_max_denominator,
                     ),
                     (
-                        ffi::MDBX_opt_spill_min_denominator,
+                        MdbxOption::SpillMinDenominator,
                         options.spill_min_denominator,
                     ),
-                ] {
-                    if let Some(v) = v {
-                        mdbx_result(ffi::mdbx_env_set_option(db, opt, v))?;
-                    }
+                ]
+                .into_iter()
+                .filter_map(|(opt, v)| Some((opt, v?)))
+                .chain(options.extra_options.iter().copied())
+                {
+                    mdbx_result(ffi::mdbx_env_set_option(db, opt.as_raw(), v))?;
                 }
 
                 let path = match CString::new(path.as_ref().as_os_str().as_bytes()) {
                     Ok(path) => path,
                     Err(..) => return Err(crate::Error::Invalid),
                 };
-                mdbx_result(ffi::mdbx_env_open(
+                match mdbx_result(ffi::mdbx_env_open(
                     db,
                     path.as_ptr(),
                     options.make_flags() | E::EXTRA_FLAGS,
                     options.permissions.unwrap_or(0o644),
-                ))?;
+                )) {
+                    // The lock file is held in a mode which conflicts with `exclusive`.
+                    Err(Error::Other(libc::EAGAIN)) => return Err(Error::Busy),
+                    result => result?,
+                };
 
                 Ok(())
             })() {
@@ -231,6 +394,9 @@ where
         let mut db = Database {
             inner: DbPtr(db),
             txn_manager: None,
+            user_ctx: Mutex::new(None),
+            comparators: Mutex::new(HashMap::new()),
+            dbis: Mutex::new(HashMap::new()),
             _marker: PhantomData,
         };
 
@@ -269,10 +435,14 @@ where
                                 .unwrap();
                         }
                         TxnManagerMessage::Commit { tx, sender } => {
+                            let mut latency = CommitLatency::new();
                             sender
-                                .send(mdbx_result(unsafe {
-                                    ffi::mdbx_txn_commit_ex(tx.0, ptr::null_mut())
-                                }))
+                                .send(
+                                    mdbx_result(unsafe {
+                                        ffi::mdbx_txn_commit_ex(tx.0, latency.mdb_commit_latency())
+                                    })
+                                    .map(|v| (v, latency)),
+                                )
                                 .unwrap();
                         }
                     },
@@ -286,6 +456,22 @@ where
         Ok(db)
     }
 
+    /// Delete the files of the database at `path` in a multiprocess-safe way.
+    ///
+    /// Returns `false` if there were no files to delete.
+    pub fn remove_files(path: impl AsRef<Path>, mode: DeleteMode) -> Result<bool> {
+        let path =
+            CString::new(path.as_ref().as_os_str().as_bytes()).map_err(|_| Error::Invalid)?;
+        let mode = match mode {
+            DeleteMode::JustDelete => ffi::MDBX_ENV_JUST_DELETE,
+            DeleteMode::EnsureUnused => ffi::MDBX_ENV_ENSURE_UNUSED,
+            DeleteMode::WaitForUnused => ffi::MDBX_ENV_WAIT_FOR_UNUSED,
+        };
+        Ok(!mdbx_result(unsafe {
+            ffi::mdbx_env_delete(path.as_ptr(), mode)
+        })?)
+    }
+
     /// Returns a raw pointer to the underlying MDBX database.
     ///
     /// The caller **must** ensure that the pointer is not dereferenced after the lifetime of the
@@ -294,40 +480,333 @@ where
         self.inner
     }
 
+    /// Returns the file descriptor of the data file, e.g. to give the kernel hints about its use
+    /// or to monitor it. It stays open as long as the database and must not be closed.
+    pub fn fd(&self) -> Result<BorrowedFd<'_>> {
+        let mut fd = -1;
+        mdbx_result(unsafe { ffi::mdbx_env_get_fd(self.ptr().0, &mut fd) })?;
+        Ok(unsafe { BorrowedFd::borrow_raw(fd) })
+    }
+
+    /// Whether the database was opened in [Mode::ReadOnly].
+    pub fn is_read_only(&self) -> bool {
+        self.txn_manager.is_none()
+    }
+
+    /// Returns the sync mode the database was opened with.
+    ///
+    /// Another process may open the database with [SyncMode::Durable] or [SyncMode::NoMetaSync]
+    /// whatever this process uses, but [SyncMode::SafeNoSync] and [SyncMode::UtterlyNoSync] have
+    /// to be used by all processes alike, and with the same [DatabaseKind], or opening fails with
+    /// [Error::Incompatible]. Read-only databases never write, and report [SyncMode::Durable].
+    pub fn sync_mode(&self) -> Result<SyncMode> {
+        let mut flags: c_uint = 0;
+        mdbx_result(unsafe { ffi::mdbx_env_get_flags(self.ptr().0, &mut flags) })?;
+
+        let has = |flag: ffi::MDBX_env_flags_t| flags & flag == flag;
+        Ok(if has(ffi::MDBX_UTTERLY_NOSYNC) {
+            SyncMode::UtterlyNoSync
+        } else if has(ffi::MDBX_SAFE_NOSYNC) {
+            SyncMode::SafeNoSync
+        } else if has(ffi::MDBX_NOMETASYNC) {
+            SyncMode::NoMetaSync
+        } else {
+            SyncMode::Durable
+        })
+    }
+
+    /// Switch the sync mode of the open database, e.g. to [SyncMode::SafeNoSync] for a bulk load
+    /// and back to [SyncMode::Durable] afterwards.
+    ///
+    /// Switching to a more durable mode does not flush the data written so far, call
+    /// [Database::sync] for that. The compatibility rules of [Database::sync_mode] with other
+    /// processes still apply. This waits for the current read-write transaction to finish, so it
+    /// must not be called while holding one.
+    pub fn set_sync_mode(&self, sync_mode: SyncMode) -> Result<()> {
+        let all = ffi::MDBX_NOMETASYNC | ffi::MDBX_UTTERLY_NOSYNC;
+        unsafe {
+            mdbx_result(ffi::mdbx_env_set_flags(self.ptr().0, all, false))?;
+            mdbx_result(ffi::mdbx_env_set_flags(
+                self.ptr().0,
+                sync_mode_flags(sync_mode),
+                true,
+            ))?;
+        }
+        Ok(())
+    }
+
+    /// Returns which of the [RuntimeFlags] are set.
+    pub fn flags(&self) -> Result<RuntimeFlags> {
+        let mut flags: c_uint = 0;
+        mdbx_result(unsafe { ffi::mdbx_env_get_flags(self.ptr().0, &mut flags) })?;
+        Ok(RuntimeFlags::from_bits_truncate(flags))
+    }
+
+    /// Set or clear `flags` on the open database, leaving the other flags unchanged.
+    ///
+    /// Like [Database::set_sync_mode], this waits for the current read-write transaction to
+    /// finish, and fails with [Error::Access] on a read-only database.
+    pub fn set_flags(&self, flags: RuntimeFlags, on: bool) -> Result<()> {
+        mdbx_result(unsafe { ffi::mdbx_env_set_flags(self.ptr().0, flags.bits(), on) })?;
+        Ok(())
+    }
+
+    /// Returns the names of all named tables, using a short-lived read-only transaction.
+    ///
+    /// See [Transaction::table_names] for details.
+    pub fn table_names(&self) -> Result<Vec<String>> {
+        self.begin_ro_txn()?.table_names()
+    }
+
+    /// Number of named tables whose handles are cached, see [Transaction::open_table].
+    pub fn cached_tables(&self) -> usize {
+        self.dbis.lock().len()
+    }
+
+    /// Removes a closed table handle from the cache.
+    pub(crate) fn forget_dbi(&self, dbi: ffi::MDBX_dbi) {
+        self.dbis.lock().retain(|_, cached| *cached != dbi);
+    }
+
     /// Create a read-only transaction for use with the database.
     pub fn begin_ro_txn(&self) -> Result<Transaction<'_, RO, E>> {
         Transaction::new(self)
     }
 
-    /// Create a read-write transaction for use with the database. This method will block while
-    /// there are any other read-write transactions open on the database.
-    pub fn begin_rw_txn(&self) -> Result<Transaction<'_, RW, E>> {
-        let sender = self.txn_manager.as_ref().ok_or(Error::Access)?;
-        let txn = loop {
-            let (tx, rx) = sync_channel(0);
-            sender
-                .send(TxnManagerMessage::Begin {
-                    parent: TxnPtr(ptr::null_mut()),
-                    flags: RW::OPEN_FLAGS,
-                    sender: tx,
-                })
-                .unwrap();
-            let res = rx.recv().unwrap();
-            if let Err(Error::Busy) = &res {
-                sleep(Duration::from_millis(250));
-                continue;
-            }
-
-            break res;
-        }?;
-        Ok(Transaction::new_from_ptr(self, txn.0))
+    /// Runs `f` in a read-only transaction, which is released afterwards.
+    pub fn with_ro_txn<T, R, F>(&self, f: F) -> result::Result<T, R>
+    where
+        F: FnOnce(&Transaction<'_, RO, E>) -> result::Result<T, R>,
+        R: From<Error>,
+    {
+        let txn = self.begin_ro_txn()?;
+        f(&txn)
     }
 
     /// Flush the database data buffers to disk.
+    ///
+    /// Only needed with the lazy [SyncMode]s. Without `force`, data is only flushed once one of
+    /// the [MdbxOption::SyncBytes] and [MdbxOption::SyncPeriod] thresholds is reached. Returns
+    /// `true` if there was nothing to flush. Waits for the current read-write transaction to
+    /// finish, so it must not be called while holding one.
     pub fn sync(&self, force: bool) -> Result<bool> {
         mdbx_result(unsafe { ffi::mdbx_env_sync_ex(self.ptr().0, force, false) })
     }
 
+    /// Flush the database data buffers to disk if one of the [MdbxOption::SyncBytes] and
+    /// [MdbxOption::SyncPeriod] thresholds is reached, like [Database::sync] without `force`.
+    ///
+    /// Instead of waiting for a running read-write transaction, fails with [Error::Busy].
+    pub fn sync_poll(&self) -> Result<bool> {
+        mdbx_result(unsafe { ffi::mdbx_env_sync_ex(self.ptr().0, false, true) })
+    }
+
+    /// Load the used part of the database into the page cache, to avoid page faults going to disk
+    /// after opening it.
+    ///
+    /// This may run concurrently with transactions. The `timeout` is only checked while reading
+    /// pages with [WarmupFlags::FORCE], with `None` waiting until all pages are read. Returns
+    /// whether the warmup completed, i.e. `false` if the timeout was reached.
+    pub fn warmup(&self, flags: WarmupFlags, timeout: Option<Duration>) -> Result<bool> {
+        // MDBX takes the timeout in 16.16 fixed-point seconds, where 0 means no timeout.
+        let timeout = timeout.map_or(0, |timeout| {
+            let timeout = (timeout.as_secs_f64() * 65536.0).ceil();
+            (timeout as c_uint).max(1)
+        });
+        let timed_out = mdbx_result(unsafe {
+            ffi::mdbx_env_warmup(self.ptr().0, ptr::null(), flags.bits(), timeout)
+        })?;
+        Ok(!timed_out)
+    }
+
+    /// Write a consistent copy of the database to an already opened file, e.g. for a backup.
+    ///
+    /// A regular file is overwritten from its start, while pipes and sockets are written to
+    /// sequentially, so the copy can be streamed elsewhere. The copy is made in a read-only
+    /// transaction of its own, and can be opened as a database once stored as `mdbx.dat`.
+    ///
+    /// With `compact` set, free pages are omitted and the pages are renumbered sequentially. MDBX
+    /// then needs to rewrite the meta pages once the copy is done, so the file must be seekable;
+    /// otherwise [Error::Io] is returned before anything is written.
+    pub fn copy_to_file(&self, file: &File, compact: bool) -> Result<()> {
+        let flags = if compact {
+            CopyFlags::COMPACT
+        } else {
+            CopyFlags::empty()
+        };
+        self.copy_to_fd(file, flags)
+    }
+
+    /// Write a consistent copy of the database to an open file descriptor, such as a pipe to a
+    /// compressor or a socket, see [Database::copy_to_file].
+    ///
+    /// [CopyFlags::COMPACT] requires a seekable target, and fails with [Error::Io] otherwise.
+    pub fn copy_to_fd(&self, fd: impl AsFd, flags: CopyFlags) -> Result<()> {
+        let fd = fd.as_fd().as_raw_fd();
+        if flags.contains(CopyFlags::COMPACT) && unsafe { libc::lseek(fd, 0, libc::SEEK_CUR) } < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        mdbx_result(unsafe { ffi::mdbx_env_copy2fd(self.ptr().0, fd, flags.bits()) })?;
+        Ok(())
+    }
+
+    /// Write a consistent copy of the database to a new file at `path`, e.g. for a backup.
+    ///
+    /// The file must not exist yet. The copy is made in a read-only transaction of its own, so
+    /// writers are not blocked, and can be opened as a database once stored as `mdbx.dat`.
+    pub fn copy_to_path(&self, path: impl AsRef<Path>, flags: CopyFlags) -> Result<()> {
+        let path =
+            CString::new(path.as_ref().as_os_str().as_bytes()).map_err(|_| Error::Invalid)?;
+        mdbx_result(unsafe { ffi::mdbx_env_copy(self.ptr().0, path.as_ptr(), flags.bits()) })?;
+        Ok(())
+    }
+
+    /// Close the database, returning any error reported by MDBX instead of ignoring it as
+    /// [Drop] does.
+    ///
+    /// With `dont_sync` set the final flush to disk is skipped, which is useful when the database
+    /// is about to be deleted anyway.
+    ///
+    /// The database cannot be closed while transactions started from it are still alive:
+    ///
+    /// ```compile_fail
+    /// # use libmdbx::{Database, NoWriteMap};
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let db = Database::<NoWriteMap>::open(&dir).unwrap();
+    /// let txn = db.begin_ro_txn().unwrap();
+    /// db.close(false).unwrap();
+    /// drop(txn);
+    /// ```
+    pub fn close(self, dont_sync: bool) -> Result<()> {
+        let mut db = mem::ManuallyDrop::new(self);
+        db.txn_manager.take();
+        let res = mdbx_result(unsafe { ffi::mdbx_env_close_ex(db.inner.0, dont_sync) });
+        slow_reader::remove_handler(db.inner.0);
+        db.user_ctx.get_mut().take();
+        mem::take(db.comparators.get_mut());
+        mem::take(db.dbis.get_mut());
+        res?;
+        Ok(())
+    }
+
+    /// Attach a user context to the database, replacing (and releasing) any previous one of the
+    /// same type. Contexts of different types coexist.
+    ///
+    /// The contexts are also registered with `mdbx_env_set_userctx`, so they can be reached from
+    /// raw MDBX callbacks as a pointer to a `HashMap<TypeId, Arc<dyn Any + Send + Sync>>`. They
+    /// are released when the database is closed.
+    pub fn set_user_context<T>(&self, ctx: Arc<T>) -> Result<()>
+    where
+        T: Send + Sync + 'static,
+    {
+        let mut user_ctx = self.user_ctx.lock();
+        let contexts = match &mut *user_ctx {
+            Some(contexts) => contexts,
+            None => {
+                let mut contexts = Box::<UserContexts>::default();
+                mdbx_result(unsafe {
+                    ffi::mdbx_env_set_userctx(
+                        self.inner.0,
+                        &mut *contexts as *mut UserContexts as *mut c_void,
+                    )
+                })?;
+                user_ctx.insert(contexts)
+            }
+        };
+        contexts.insert(TypeId::of::<T>(), ctx);
+        Ok(())
+    }
+
+    /// Returns the user context of type `T` set with [Database::set_user_context], or [None] if
+    /// there is none.
+    pub fn get_user_context<T>(&self) -> Option<Arc<T>>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.user_ctx
+            .lock()
+            .as_deref()?
+            .get(&TypeId::of::<T>())
+            .cloned()
+            .and_then(|ctx| ctx.downcast().ok())
+    }
+
+    /// Detach and return the user context of type `T` set with [Database::set_user_context],
+    /// leaving contexts of other types in place.
+    pub fn take_user_context<T>(&self) -> Option<Arc<T>>
+    where
+        T: Send + Sync + 'static,
+    {
+        self.user_ctx
+            .lock()
+            .as_deref_mut()?
+            .remove(&TypeId::of::<T>())
+            .and_then(|ctx| ctx.downcast().ok())
+    }
+
+    /// Change a runtime option of the open database.
+    ///
+    /// Some options can only be set before opening, in which case [Error::Permission] is
+    /// returned. Note that options affecting write transactions wait for the current read-write
+    /// transaction to finish, so this must not be called while holding one.
+    pub fn set_option(&self, option: MdbxOption, value: u64) -> Result<()> {
+        mdbx_result(unsafe { ffi::mdbx_env_set_option(self.inner.0, option.as_raw(), value) })?;
+        Ok(())
+    }
+
+    /// Change the size bounds of the open database, without reopening it.
+    ///
+    /// The new bounds also apply to other processes using the database. The upper bound cannot
+    /// drop below the space in use, which fails with [Error::MapFull]. Raising it above the bound
+    /// the database was opened with needs the address space following the memory map, and fails
+    /// with [Error::UnableExtendMapsize] when that is taken, so prefer opening with a generous
+    /// upper bound and lowering it. Like [Database::set_option], this waits for the current
+    /// read-write transaction to finish, so it must not be called while holding one.
+    pub fn set_geometry(&self, geometry: Geometry) -> Result<()> {
+        // MDBX resets omitted sizes to their defaults, rather than keeping them.
+        let current = self.geometry()?;
+        let size = |size: Option<isize>, current: u64| size.unwrap_or(current as isize);
+        mdbx_result(unsafe {
+            ffi::mdbx_env_set_geometry(
+                self.inner.0,
+                size(geometry.min_size, current.min()),
+                -1,
+                size(geometry.max_size, current.max()),
+                size(geometry.growth_step, current.growth_step()),
+                size(geometry.shrink_threshold, current.shrink_threshold()),
+                -1,
+            )
+        })?;
+        Ok(())
+    }
+
+    /// Current value of a runtime option.
+    pub fn get_option(&self, option: MdbxOption) -> Result<u64> {
+        let mut value = 0;
+        mdbx_result(unsafe {
+            ffi::mdbx_env_get_option(self.inner.0, option.as_raw(), &mut value)
+        })?;
+        Ok(value)
+    }
+
+    /// Maximal key size in bytes for a table with the given flags.
+    ///
+    /// Keys of [TableFlags::DUP_SORT] tables are limited further than keys of other tables. See
+    /// [limits::max_key_size](crate::limits::max_key_size) for the limit of any page size.
+    pub fn max_key_size(&self, flags: TableFlags) -> Result<usize> {
+        let size = unsafe { ffi::mdbx_env_get_maxkeysize_ex(self.inner.0, flags.bits()) };
+        usize::try_from(size).map_err(|_| Error::InvalidValue)
+    }
+
+    /// Maximal value size in bytes for a table with the given flags.
+    ///
+    /// For [TableFlags::DUP_SORT] tables values are stored like keys, so the limit is much lower.
+    /// See [limits::max_value_size](crate::limits::max_value_size) for the limit of any page size.
+    pub fn max_value_size(&self, flags: TableFlags) -> Result<usize> {
+        let size = unsafe { ffi::mdbx_env_get_maxvalsize_ex(self.inner.0, flags.bits()) };
+        usize::try_from(size).map_err(|_| Error::InvalidValue)
+    }
+
     /// Retrieves statistics about this database.
     pub fn stat(&self) -> Result<Stat> {
         unsafe {
@@ -356,6 +835,24 @@ where
         }
     }
 
+    /// Retrieves the geometry of the database, with sizes as adjusted by MDBX, see
+    /// [ReadWriteOptions].
+    pub fn geometry(&self) -> Result<GeometryInfo> {
+        Ok(self.info()?.geometry())
+    }
+
+    /// Clears reader slots left behind by processes or threads which ended without finishing their
+    /// read transactions, returning the number of slots cleared.
+    ///
+    /// Stale readers hold back the reuse of freed pages, and eventually make new read transactions
+    /// fail with [Error::ReadersFull]. MDBX also clears them automatically when the reader table
+    /// fills up, so calling this is only needed to reclaim space early.
+    pub fn check_readers(&self) -> Result<usize> {
+        let mut dead: c_int = 0;
+        mdbx_result(unsafe { ffi::mdbx_reader_check(self.inner.0, &mut dead) })?;
+        Ok(dead as usize)
+    }
+
     /// Retrieves the total number of pages on the freelist.
     ///
     /// Along with [Database::info()], this can be used to calculate the exact number
@@ -401,11 +898,205 @@ where
 
         Ok(freelist)
     }
+
+    /// Retrieves statistics of the main table, every named table and the GC table, along with
+    /// the database info, all taken from the same snapshot.
+    ///
+    /// Opens a handle to each named table, see [Transaction::table_names].
+    pub fn all_stats(&self) -> Result<DatabaseStats> {
+        let txn = self.begin_ro_txn()?;
+        let main = txn.table_stat(&txn.open_table(None)?)?;
+        let tables = txn
+            .table_names()?
+            .into_iter()
+            .map(|name| {
+                let stat = txn.table_stat(&txn.open_table(Some(&name))?)?;
+                Ok((name, stat))
+            })
+            .collect::<Result<_>>()?;
+        let gc = txn.table_stat(&Table::freelist_table())?;
+        let info = unsafe {
+            let mut info = Info(mem::zeroed());
+            mdbx_result(txn_execute(&txn.txn_mutex(), |txn| {
+                ffi::mdbx_env_info_ex(self.ptr().0, txn, &mut info.0, size_of::<Info>())
+            }))?;
+            info
+        };
+
+        Ok(DatabaseStats {
+            main,
+            tables,
+            gc,
+            info,
+        })
+    }
+}
+
+impl<E> Database<E>
+where
+    E: Writable,
+{
+    /// Create a read-write transaction for use with the database. This method will block while
+    /// there are any other read-write transactions open on the database.
+    ///
+    /// Returns [Error::Access] if the database was opened in [Mode::ReadOnly].
+    pub fn begin_rw_txn(&self) -> Result<Transaction<'_, RW, E>> {
+        self.begin_rw_txn_until(None)
+    }
+
+    /// Like [Database::begin_rw_txn], but gives up with [Error::Timeout] if no read-write
+    /// transaction could be started within `timeout`.
+    pub fn begin_rw_txn_with_timeout(&self, timeout: Duration) -> Result<Transaction<'_, RW, E>> {
+        self.begin_rw_txn_until(Some(Instant::now() + timeout))
+            .map_err(|e| match e {
+                Error::Busy => Error::Timeout,
+                other => other,
+            })
+    }
+
+    /// Like [Database::begin_rw_txn], but fails immediately with [Error::Busy] if another
+    /// read-write transaction is open.
+    pub fn try_begin_rw_txn(&self) -> Result<Transaction<'_, RW, E>> {
+        self.begin_rw_txn_until(Some(Instant::now()))
+    }
+
+    /// Runs `f` in a read-write transaction, which is committed if `f` returns `Ok` and aborted
+    /// if it returns an error or panics. A failing commit is returned as an error.
+    ///
+    /// Calling this again from within `f` fails with [Error::TxnOverlapping], rather than
+    /// waiting forever for the outer transaction to finish.
+    pub fn with_rw_txn<T, R, F>(&self, f: F) -> result::Result<T, R>
+    where
+        F: FnOnce(&Transaction<'_, RW, E>) -> result::Result<T, R>,
+        R: From<Error>,
+    {
+        let _guard = RwTxnGuard::enter(self.ptr())?;
+        let txn = self.begin_rw_txn()?;
+        let value = f(&txn)?;
+        txn.commit()?;
+        Ok(value)
+    }
+
+    fn begin_rw_txn_until(&self, deadline: Option<Instant>) -> Result<Transaction<'_, RW, E>> {
+        let sender = self.txn_manager.as_ref().ok_or(Error::Access)?;
+        // Without MDBX_TXN_TRY, MDBX blocks while another process holds the writer lock.
+        let flags = match deadline {
+            Some(_) => RW::OPEN_FLAGS | ffi::MDBX_TXN_TRY,
+            None => RW::OPEN_FLAGS,
+        };
+        let mut backoff = Duration::from_millis(1);
+        let txn = loop {
+            let (tx, rx) = sync_channel(0);
+            sender
+                .send(TxnManagerMessage::Begin {
+                    parent: TxnPtr(ptr::null_mut()),
+                    flags,
+                    sender: tx,
+                })
+                .unwrap();
+            let res = rx.recv().unwrap();
+            if let Err(Error::Busy) = &res {
+                let mut delay = backoff;
+                if let Some(deadline) = deadline {
+                    let now = Instant::now();
+                    if now >= deadline {
+                        break res;
+                    }
+                    delay = delay.min(deadline - now);
+                }
+                sleep(delay);
+                backoff = (backoff * 2).min(Duration::from_millis(250));
+                continue;
+            }
+
+            break res;
+        }?;
+        Ok(Transaction::new_from_ptr(self, txn.0))
+    }
+}
+
+/// Statistics of all tables in a database, returned by [Database::all_stats].
+#[derive(Clone, Debug)]
+pub struct DatabaseStats {
+    /// The main table, which also records the names of the named tables.
+    pub main: Stat,
+    /// The named tables, in key order.
+    pub tables: Vec<(String, Stat)>,
+    /// The GC table, recording the pages which are free for reuse.
+    pub gc: Stat,
+    pub info: Info,
+}
+
+impl DatabaseStats {
+    fn all(&self) -> impl Iterator<Item = &Stat> {
+        [&self.main, &self.gc]
+            .into_iter()
+            .chain(self.tables.iter().map(|(_, stat)| stat))
+    }
+
+    /// Statistics of the named table `name`.
+    pub fn table(&self, name: &str) -> Option<&Stat> {
+        self.tables
+            .binary_search_by(|(table, _)| table.as_str().cmp(name))
+            .ok()
+            .map(|i| &self.tables[i].1)
+    }
+
+    /// Number of pages used by all tables, including the GC table.
+    pub fn pages(&self) -> usize {
+        self.branch_pages() + self.leaf_pages() + self.overflow_pages()
+    }
+
+    /// Number of branch pages used by all tables, including the GC table.
+    pub fn branch_pages(&self) -> usize {
+        self.all().map(Stat::branch_pages).sum()
+    }
+
+    /// Number of leaf pages used by all tables, including the GC table.
+    pub fn leaf_pages(&self) -> usize {
+        self.all().map(Stat::leaf_pages).sum()
+    }
+
+    /// Number of overflow pages used by all tables, including the GC table.
+    pub fn overflow_pages(&self) -> usize {
+        self.all().map(Stat::overflow_pages).sum()
+    }
+
+    /// Number of data items in the main and the named tables.
+    pub fn entries(&self) -> usize {
+        self.main.entries()
+            + self
+                .tables
+                .iter()
+                .map(|(_, stat)| stat.entries())
+                .sum::<usize>()
+    }
+
+    /// Depth of the deepest B-tree.
+    pub fn depth(&self) -> u32 {
+        self.all().map(Stat::depth).max().unwrap_or_default()
+    }
+}
+
+impl fmt::Display for DatabaseStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} tables, depth {}, {} pages ({} overflow), {} entries; {}",
+            self.tables.len(),
+            self.depth(),
+            self.pages(),
+            self.overflow_pages(),
+            self.entries(),
+            self.info
+        )
+    }
 }
 
 /// Database statistics.
 ///
 /// Contains information about the size and layout of an MDBX database or table.
+#[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct Stat(ffi::MDBX_stat);
 
@@ -458,6 +1149,12 @@ impl Stat {
         self.0.ms_entries as usize
     }
 
+    /// ID of the transaction which last modified the table.
+    #[inline]
+    pub const fn mod_txnid(&self) -> u64 {
+        self.0.ms_mod_txnid
+    }
+
     /// Total size in bytes.
     #[inline]
     pub const fn total_size(&self) -> u64 {
@@ -466,18 +1163,126 @@ impl Stat {
     }
 }
 
+impl fmt::Debug for Stat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Stat")
+            .field("page_size", &self.page_size())
+            .field("depth", &self.depth())
+            .field("branch_pages", &self.branch_pages())
+            .field("leaf_pages", &self.leaf_pages())
+            .field("overflow_pages", &self.overflow_pages())
+            .field("entries", &self.entries())
+            .field("mod_txnid", &self.mod_txnid())
+            .finish()
+    }
+}
+
+impl fmt::Display for Stat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "depth {}, {} branch / {} leaf / {} overflow pages of {} bytes, {} entries",
+            self.depth(),
+            self.branch_pages(),
+            self.leaf_pages(),
+            self.overflow_pages(),
+            self.page_size(),
+            self.entries()
+        )
+    }
+}
+
+/// Size limits and the current size of a database, see [Database::geometry].
+#[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct GeometryInfo(ffi::MDBX_envinfo__bindgen_ty_1);
 
 impl GeometryInfo {
+    /// Lower bound of the database size.
     pub fn min(&self) -> u64 {
         self.0.lower
     }
+
+    /// Upper bound of the database size.
+    pub fn max(&self) -> u64 {
+        self.0.upper
+    }
+
+    /// Current size of the database.
+    pub fn current(&self) -> u64 {
+        self.0.current
+    }
+
+    /// Step by which the database grows when it runs out of space.
+    pub fn growth_step(&self) -> u64 {
+        self.0.grow
+    }
+
+    /// Amount of unused space at the end of the database above which it is shrunk.
+    pub fn shrink_threshold(&self) -> u64 {
+        self.0.shrink
+    }
+}
+
+impl fmt::Debug for GeometryInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GeometryInfo")
+            .field("min", &self.min())
+            .field("max", &self.max())
+            .field("current", &self.current())
+            .field("growth_step", &self.growth_step())
+            .field("shrink_threshold", &self.shrink_threshold())
+            .finish()
+    }
+}
+
+impl fmt::Display for GeometryInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} bytes in [{}, {}], growth step {}, shrink threshold {}",
+            self.current(),
+            self.min(),
+            self.max(),
+            self.growth_step(),
+            self.shrink_threshold()
+        )
+    }
+}
+
+/// Counters of page operations since the database was opened, see [Info::page_ops].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct PageOps {
+    /// Pages newly allocated.
+    pub newly: u64,
+    /// Pages copied for update.
+    pub cow: u64,
+    /// Parent pages cloned for nested transactions.
+    pub clone: u64,
+    /// Page splits.
+    pub split: u64,
+    /// Page merges.
+    pub merge: u64,
+    /// Dirty pages spilled to disk.
+    pub spill: u64,
+    /// Spilled pages read back.
+    pub unspill: u64,
+    /// Write operations on the data file.
+    pub wops: u64,
+    /// Pages prefaulted into memory.
+    pub prefault: u64,
+    /// `mincore()` calls.
+    pub mincore: u64,
+    /// Explicit `msync()` calls.
+    pub msync: u64,
+    /// Explicit `fsync()` calls.
+    pub fsync: u64,
 }
 
 /// Database information.
 ///
 /// Contains database information about the map size, readers, last txn id etc.
+#[derive(Clone, Copy)]
 #[repr(transparent)]
 pub struct Info(ffi::MDBX_envinfo);
 
@@ -504,6 +1309,36 @@ impl Info {
         self.0.mi_recent_txnid as usize
     }
 
+    /// Transaction ID of the oldest snapshot still in use by a reader.
+    #[inline]
+    pub fn latter_reader_txnid(&self) -> u64 {
+        self.0.mi_latter_reader_txnid
+    }
+
+    /// Transaction ID of the oldest snapshot still in use by a reader of this process.
+    #[inline]
+    pub fn self_latter_reader_txnid(&self) -> u64 {
+        self.0.mi_self_latter_reader_txnid
+    }
+
+    /// Transaction IDs of the three meta pages.
+    #[inline]
+    pub fn meta_txnids(&self) -> [u64; 3] {
+        [
+            self.0.mi_meta0_txnid,
+            self.0.mi_meta1_txnid,
+            self.0.mi_meta2_txnid,
+        ]
+    }
+
+    /// ID of the current boot of the system, or zeros if the system provides none. A database with
+    /// unsynced writes can only be recovered without a rollback during the same boot.
+    #[inline]
+    pub fn boot_id(&self) -> [u64; 2] {
+        let current = &self.0.mi_bootid.current;
+        [current.x, current.y]
+    }
+
     /// Max reader slots in the database
     #[inline]
     pub fn max_readers(&self) -> usize {
@@ -515,6 +1350,208 @@ impl Info {
     pub fn num_readers(&self) -> usize {
         self.0.mi_numreaders as usize
     }
+
+    /// Size of a database page.
+    #[inline]
+    pub fn page_size(&self) -> u32 {
+        self.0.mi_dxb_pagesize
+    }
+
+    /// Size of a page of the operating system.
+    #[inline]
+    pub fn sys_page_size(&self) -> u32 {
+        self.0.mi_sys_pagesize
+    }
+
+    /// Bytes written since the last sync to disk.
+    #[inline]
+    pub fn unsync_volume(&self) -> u64 {
+        self.0.mi_unsync_volume
+    }
+
+    /// Volume of unsynced writes which triggers a sync, or 0 if disabled.
+    #[inline]
+    pub fn autosync_threshold(&self) -> u64 {
+        self.0.mi_autosync_threshold
+    }
+
+    /// Time since the last sync to disk.
+    #[inline]
+    pub fn since_sync(&self) -> Duration {
+        duration_from_16dot16(self.0.mi_since_sync_seconds16dot16)
+    }
+
+    /// Period after which unsynced writes are synced, or zero if disabled.
+    #[inline]
+    pub fn autosync_period(&self) -> Duration {
+        duration_from_16dot16(self.0.mi_autosync_period_seconds16dot16)
+    }
+
+    /// Time since the reader table was last checked for stale entries.
+    #[inline]
+    pub fn since_reader_check(&self) -> Duration {
+        duration_from_16dot16(self.0.mi_since_reader_check_seconds16dot16)
+    }
+
+    /// Counters of page operations.
+    pub fn page_ops(&self) -> PageOps {
+        let stat = &self.0.mi_pgop_stat;
+        PageOps {
+            newly: stat.newly,
+            cow: stat.cow,
+            clone: stat.clone,
+            split: stat.split,
+            merge: stat.merge,
+            spill: stat.spill,
+            unspill: stat.unspill,
+            wops: stat.wops,
+            prefault: stat.prefault,
+            mincore: stat.mincore,
+            msync: stat.msync,
+            fsync: stat.fsync,
+        }
+    }
+}
+
+impl fmt::Debug for Info {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Info")
+            .field("geometry", &self.geometry())
+            .field("map_size", &self.map_size())
+            .field("last_pgno", &self.last_pgno())
+            .field("last_txnid", &self.last_txnid())
+            .field("latter_reader_txnid", &self.latter_reader_txnid())
+            .field("self_latter_reader_txnid", &self.self_latter_reader_txnid())
+            .field("meta_txnids", &self.meta_txnids())
+            .field("boot_id", &self.boot_id())
+            .field("max_readers", &self.max_readers())
+            .field("num_readers", &self.num_readers())
+            .field("page_size", &self.page_size())
+            .field("sys_page_size", &self.sys_page_size())
+            .field("unsync_volume", &self.unsync_volume())
+            .field("autosync_threshold", &self.autosync_threshold())
+            .field("since_sync", &self.since_sync())
+            .field("autosync_period", &self.autosync_period())
+            .field("since_reader_check", &self.since_reader_check())
+            .field("page_ops", &self.page_ops())
+            .finish()
+    }
+}
+
+impl fmt::Display for Info {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "map size {}, last pgno {}, last txnid {}, {}/{} readers",
+            self.map_size(),
+            self.last_pgno(),
+            self.last_txnid(),
+            self.num_readers(),
+            self.max_readers()
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serialize {
+    use super::{DatabaseStats, GeometryInfo, Info, PageOps, Stat};
+    use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+    impl Serialize for DatabaseStats {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            // Table names are unique, so the tables serialize as a map by name.
+            struct Tables<'a>(&'a [(String, Stat)]);
+            impl Serialize for Tables<'_> {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_map(self.0.iter().map(|(name, stat)| (name, stat)))
+                }
+            }
+
+            let mut s = serializer.serialize_struct("DatabaseStats", 4)?;
+            s.serialize_field("main", &self.main)?;
+            s.serialize_field("tables", &Tables(&self.tables))?;
+            s.serialize_field("gc", &self.gc)?;
+            s.serialize_field("info", &self.info)?;
+            s.end()
+        }
+    }
+
+    impl Serialize for Stat {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut s = serializer.serialize_struct("Stat", 7)?;
+            s.serialize_field("page_size", &self.page_size())?;
+            s.serialize_field("depth", &self.depth())?;
+            s.serialize_field("branch_pages", &self.branch_pages())?;
+            s.serialize_field("leaf_pages", &self.leaf_pages())?;
+            s.serialize_field("overflow_pages", &self.overflow_pages())?;
+            s.serialize_field("entries", &self.entries())?;
+            s.serialize_field("mod_txnid", &self.mod_txnid())?;
+            s.end()
+        }
+    }
+
+    impl Serialize for GeometryInfo {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut s = serializer.serialize_struct("GeometryInfo", 5)?;
+            s.serialize_field("min", &self.min())?;
+            s.serialize_field("max", &self.max())?;
+            s.serialize_field("current", &self.current())?;
+            s.serialize_field("growth_step", &self.growth_step())?;
+            s.serialize_field("shrink_threshold", &self.shrink_threshold())?;
+            s.end()
+        }
+    }
+
+    impl Serialize for PageOps {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut s = serializer.serialize_struct("PageOps", 12)?;
+            s.serialize_field("newly", &self.newly)?;
+            s.serialize_field("cow", &self.cow)?;
+            s.serialize_field("clone", &self.clone)?;
+            s.serialize_field("split", &self.split)?;
+            s.serialize_field("merge", &self.merge)?;
+            s.serialize_field("spill", &self.spill)?;
+            s.serialize_field("unspill", &self.unspill)?;
+            s.serialize_field("wops", &self.wops)?;
+            s.serialize_field("prefault", &self.prefault)?;
+            s.serialize_field("mincore", &self.mincore)?;
+            s.serialize_field("msync", &self.msync)?;
+            s.serialize_field("fsync", &self.fsync)?;
+            s.end()
+        }
+    }
+
+    /// Durations serialize as fractional seconds, which is what metrics pipelines expect.
+    impl Serialize for Info {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut s = serializer.serialize_struct("Info", 18)?;
+            s.serialize_field("geometry", &self.geometry())?;
+            s.serialize_field("map_size", &self.map_size())?;
+            s.serialize_field("last_pgno", &self.last_pgno())?;
+            s.serialize_field("last_txnid", &self.last_txnid())?;
+            s.serialize_field("latter_reader_txnid", &self.latter_reader_txnid())?;
+            s.serialize_field("self_latter_reader_txnid", &self.self_latter_reader_txnid())?;
+            s.serialize_field("meta_txnids", &self.meta_txnids())?;
+            s.serialize_field("boot_id", &self.boot_id())?;
+            s.serialize_field("max_readers", &self.max_readers())?;
+            s.serialize_field("num_readers", &self.num_readers())?;
+            s.serialize_field("page_size", &self.page_size())?;
+            s.serialize_field("sys_page_size", &self.sys_page_size())?;
+            s.serialize_field("unsync_volume", &self.unsync_volume())?;
+            s.serialize_field("autosync_threshold", &self.autosync_threshold())?;
+            s.serialize_field("since_sync_secs", &self.since_sync().as_secs_f64())?;
+            s.serialize_field(
+                "autosync_period_secs",
+                &self.autosync_period().as_secs_f64(),
+            )?;
+            s.serialize_field(
+                "since_reader_check_secs",
+                &self.since_reader_check().as_secs_f64(),
+            )?;
+            s.serialize_field("page_ops", &self.page_ops())?;
+            s.end()
+        }
+    }
 }
 
 impl<E> fmt::Debug for Database<E>
@@ -534,6 +1571,33 @@ where
         unsafe {
             ffi::mdbx_env_close_ex(self.inner.0, false);
         }
+        slow_reader::remove_handler(self.inner.0);
+    }
+}
+
+thread_local! {
+    /// Databases for which [Database::with_rw_txn] is running on this thread.
+    static RW_TXN_DBS: RefCell<Vec<*mut ffi::MDBX_env>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Marks a database as being written to by [Database::with_rw_txn] on this thread, until dropped.
+struct RwTxnGuard(*mut ffi::MDBX_env);
+
+impl RwTxnGuard {
+    fn enter(db: DbPtr) -> Result<Self> {
+        RW_TXN_DBS.with_borrow_mut(|dbs| {
+            if dbs.contains(&db.0) {
+                return Err(Error::TxnOverlapping);
+            }
+            dbs.push(db.0);
+            Ok(Self(db.0))
+        })
+    }
+}
+
+impl Drop for RwTxnGuard {
+    fn drop(&mut self) {
+        RW_TXN_DBS.with_borrow_mut(|dbs| dbs.retain(|db| *db != self.0));
     }
 }
 
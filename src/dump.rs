@@ -0,0 +1,364 @@
+use crate::{
+    cancellation::CancellationToken,
+    database::DatabaseKind,
+    error::{Error, Result},
+    flags::{TableFlags, WriteFlags},
+    table::Table,
+    transaction::{Transaction, TransactionKind, RW},
+};
+use std::{
+    borrow::Cow,
+    fmt,
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    result, str,
+};
+
+const BINARY_MAGIC: &[u8; 8] = b"MDBXDUMP";
+
+/// Flags preserved in a dump header, with their names in the text format.
+const HEADER_FLAGS: [(TableFlags, &str); 6] = [
+    (TableFlags::REVERSE_KEY, "reversekey"),
+    (TableFlags::DUP_SORT, "duplicates"),
+    (TableFlags::INTEGER_KEY, "integerkey"),
+    (TableFlags::DUP_FIXED, "dupfixed"),
+    (TableFlags::INTEGER_DUP, "integerdup"),
+    (TableFlags::REVERSE_DUP, "reversedup"),
+];
+
+/// Serialization format used by [Transaction::dump_to] and [Transaction::load_from].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DumpFormat {
+    /// The `mdb_dump -p` text format: a header of `name=value` lines, then one line per key and
+    /// per value, each starting with a space. Printable ASCII is kept as is, other bytes are
+    /// written as `\xx` hex escapes.
+    Text,
+    /// The `MDBXDUMP` magic and the table flags as a little-endian `u32`, followed by keys and
+    /// values, each prefixed by its length as a little-endian `u64`.
+    Binary,
+}
+
+/// A dump could not be parsed by [Transaction::load_from].
+///
+/// Reported as [Error::DecodeError].
+#[derive(Debug)]
+pub struct MalformedDump {
+    pub format: DumpFormat,
+    /// The 1-based line for [DumpFormat::Text], the byte offset for [DumpFormat::Binary].
+    pub position: u64,
+    pub reason: String,
+}
+
+impl fmt::Display for MalformedDump {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.format {
+            DumpFormat::Text => write!(f, "malformed dump at line {}", self.position)?,
+            DumpFormat::Binary => write!(f, "malformed dump at offset {}", self.position)?,
+        }
+        write!(f, ": {}", self.reason)
+    }
+}
+
+impl std::error::Error for MalformedDump {}
+
+fn malformed(format: DumpFormat, position: u64, reason: impl Into<String>) -> Error {
+    Error::DecodeError(Box::new(MalformedDump {
+        format,
+        position,
+        reason: reason.into(),
+    }))
+}
+
+impl<K, E> Transaction<'_, K, E>
+where
+    K: TransactionKind,
+    E: DatabaseKind,
+{
+    /// Writes the flags and all items of the table, including every duplicate, to `w`.
+    ///
+    /// Returns the number of key/value pairs written. Load the dump back with
+    /// [Transaction::load_from]. `cancel` is polled before each pair; once cancelled, the pairs
+    /// so far are flushed to `w` and the dump stops with [Error::Cancelled] counting them.
+    pub fn dump_to<'txn>(
+        &'txn self,
+        table: &Table<'txn>,
+        w: impl Write,
+        format: DumpFormat,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<u64> {
+        let flags = self.table_flags(table)?;
+        let mut w = BufWriter::new(w);
+        match format {
+            DumpFormat::Text => {
+                writeln!(w, "VERSION=3\nformat=print\ntype=btree")?;
+                for (flag, name) in HEADER_FLAGS {
+                    if flags.contains(flag) {
+                        writeln!(w, "{name}=1")?;
+                    }
+                }
+                writeln!(w, "HEADER=END")?;
+            }
+            DumpFormat::Binary => {
+                w.write_all(BINARY_MAGIC)?;
+                w.write_all(&header_flags(flags).bits().to_le_bytes())?;
+            }
+        }
+
+        let mut count = 0;
+        for item in self
+            .cursor(table)?
+            .into_iter_start::<Cow<'_, [u8]>, Cow<'_, [u8]>>()
+        {
+            let (key, value) = item?;
+            if let Err(e) = check(cancel, count) {
+                w.flush()?;
+                return Err(e);
+            }
+            for data in [key, value] {
+                match format {
+                    DumpFormat::Text => write_escaped(&mut w, &data)?,
+                    DumpFormat::Binary => {
+                        w.write_all(&(data.len() as u64).to_le_bytes())?;
+                        w.write_all(&data)?;
+                    }
+                }
+            }
+            count += 1;
+        }
+
+        if format == DumpFormat::Text {
+            writeln!(w, "DATA=END")?;
+        }
+        w.flush()?;
+
+        Ok(count)
+    }
+}
+
+impl<E> Transaction<'_, RW, E>
+where
+    E: DatabaseKind,
+{
+    /// Creates the table if necessary, using the flags from the dump header, and stores all items
+    /// read from `r`, as written by [Transaction::dump_to].
+    ///
+    /// Returns the number of key/value pairs loaded. Malformed input fails with
+    /// [Error::DecodeError] wrapping a [MalformedDump]. `cancel` is polled before each pair; once
+    /// cancelled, the load stops with [Error::Cancelled] counting the pairs stored so far, which
+    /// remain in the transaction.
+    pub fn load_from(
+        &self,
+        name: Option<&str>,
+        r: impl Read,
+        format: DumpFormat,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<u64> {
+        let mut r = BufReader::new(r);
+        match format {
+            DumpFormat::Text => self.load_text(name, r, cancel),
+            DumpFormat::Binary => {
+                let mut magic = [0; 8];
+                let mut flags = [0; 4];
+                if !read_exact_or_eof(&mut r, &mut magic)? || &magic != BINARY_MAGIC {
+                    return Err(malformed(format, 0, "missing MDBXDUMP header"));
+                }
+                if !read_exact_or_eof(&mut r, &mut flags)? {
+                    return Err(malformed(format, 8, "truncated header"));
+                }
+                let flags = TableFlags::from_bits(u32::from_le_bytes(flags))
+                    .filter(|flags| header_flags(*flags) == *flags)
+                    .ok_or_else(|| malformed(format, 8, "unknown table flags"))?;
+
+                let table = self.create_table(name, flags)?;
+                let mut offset = 12;
+                let mut count = 0;
+                loop {
+                    check(cancel, count)?;
+                    let start = offset;
+                    let Some(key) = read_binary_data(&mut r, &mut offset)? else {
+                        return Ok(count);
+                    };
+                    let value = read_binary_data(&mut r, &mut offset)?
+                        .ok_or_else(|| malformed(format, start, "key without a value"))?;
+                    self.put(&table, key, value, WriteFlags::empty())?;
+                    count += 1;
+                }
+            }
+        }
+    }
+
+    fn load_text(
+        &self,
+        name: Option<&str>,
+        r: impl BufRead,
+        cancel: Option<&CancellationToken>,
+    ) -> Result<u64> {
+        let mut lines = Lines {
+            r,
+            line: Vec::new(),
+            line_no: 0,
+        };
+
+        let mut flags = TableFlags::empty();
+        loop {
+            let line = lines.next("missing HEADER=END")?;
+            let Some((key, value)) = str::from_utf8(line)
+                .ok()
+                .and_then(|line| line.split_once('='))
+            else {
+                return Err(lines.malformed("expected a name=value header line"));
+            };
+            match (key, value) {
+                ("HEADER", "END") => break,
+                ("VERSION", "3") | ("type", "btree") | ("format", "print") => {}
+                ("VERSION" | "type" | "format", _) => {
+                    let reason = format!("unsupported {key} {value}");
+                    return Err(lines.malformed(reason));
+                }
+                (key, value) => {
+                    if let Some((flag, _)) = HEADER_FLAGS.iter().find(|(_, name)| *name == key) {
+                        flags.set(*flag, value == "1");
+                    }
+                }
+            }
+        }
+
+        let table = self.create_table(name, flags)?;
+        let mut count = 0;
+        loop {
+            check(cancel, count)?;
+            let line = lines.next("missing DATA=END")?;
+            if line == b"DATA=END" {
+                return Ok(count);
+            }
+            let key = unescape(line).map_err(|reason| lines.malformed(reason))?;
+            let line = lines.next("key without a value")?;
+            let value = unescape(line).map_err(|reason| lines.malformed(reason))?;
+
+            self.put(&table, key, value, WriteFlags::empty())?;
+            count += 1;
+        }
+    }
+}
+
+struct Lines<R> {
+    r: R,
+    line: Vec<u8>,
+    line_no: u64,
+}
+
+impl<R: BufRead> Lines<R> {
+    /// Reads the next line, without its terminator, failing with `reason` at the end of input.
+    fn next(&mut self, reason: &str) -> Result<&[u8]> {
+        self.line.clear();
+        self.line_no += 1;
+        if self.r.read_until(b'\n', &mut self.line)? == 0 {
+            return Err(self.malformed(reason));
+        }
+        if self.line.last() == Some(&b'\n') {
+            self.line.pop();
+        }
+        Ok(&self.line)
+    }
+
+    fn malformed(&self, reason: impl Into<String>) -> Error {
+        malformed(DumpFormat::Text, self.line_no, reason)
+    }
+}
+
+fn check(cancel: Option<&CancellationToken>, count: u64) -> Result<()> {
+    cancel.map_or(Ok(()), |cancel| cancel.check(count))
+}
+
+fn header_flags(flags: TableFlags) -> TableFlags {
+    HEADER_FLAGS
+        .iter()
+        .map(|(flag, _)| *flag)
+        .filter(|flag| flags.contains(*flag))
+        .collect()
+}
+
+fn write_escaped(w: &mut impl Write, data: &[u8]) -> io::Result<()> {
+    w.write_all(b" ")?;
+    for &byte in data {
+        match byte {
+            b'\\' => w.write_all(b"\\\\")?,
+            b' '..=b'~' => w.write_all(&[byte])?,
+            _ => write!(w, "\\{byte:02x}")?,
+        }
+    }
+    w.write_all(b"\n")
+}
+
+fn unescape(line: &[u8]) -> result::Result<Vec<u8>, &'static str> {
+    let Some(line) = line.strip_prefix(b" ") else {
+        return Err("data lines must start with a space");
+    };
+
+    let mut data = Vec::with_capacity(line.len());
+    let mut bytes = line.iter();
+    while let Some(&byte) = bytes.next() {
+        if byte != b'\\' {
+            data.push(byte);
+            continue;
+        }
+        match bytes.next() {
+            Some(b'\\') => data.push(b'\\'),
+            Some(&high) => {
+                let low = *bytes.next().ok_or("truncated escape sequence")?;
+                let hex = str::from_utf8(&[high, low])
+                    .ok()
+                    .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+                    .ok_or("invalid escape sequence")?;
+                data.push(hex);
+            }
+            None => return Err("truncated escape sequence"),
+        }
+    }
+
+    Ok(data)
+}
+
+/// Like [Read::read_exact], but returns `false` if the reader is exhausted before anything is
+/// read.
+fn read_exact_or_eof(r: &mut impl Read, mut buf: &mut [u8]) -> io::Result<bool> {
+    let len = buf.len();
+    while !buf.is_empty() {
+        match r.read(buf) {
+            Ok(0) if buf.len() == len => return Ok(false),
+            Ok(0) => return Err(io::ErrorKind::UnexpectedEof.into()),
+            Ok(n) => buf = &mut buf[n..],
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(true)
+}
+
+/// Reads a length-prefixed key or value, returning [None] on a clean end of input.
+fn read_binary_data(r: &mut impl Read, offset: &mut u64) -> Result<Option<Vec<u8>>> {
+    let format = DumpFormat::Binary;
+    let start = *offset;
+    let mut len = [0; 8];
+    match read_exact_or_eof(r, &mut len) {
+        Ok(true) => {}
+        Ok(false) => return Ok(None),
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+            return Err(malformed(format, start, "truncated length"))
+        }
+        Err(e) => return Err(e.into()),
+    }
+    let len = u64::from_le_bytes(len);
+    *offset += 8;
+
+    let mut data = Vec::new();
+    if r.take(len).read_to_end(&mut data)? as u64 != len {
+        return Err(malformed(
+            format,
+            *offset,
+            format!("expected {len} bytes of data"),
+        ));
+    }
+    *offset += len;
+
+    Ok(Some(data))
+}
@@ -1,5 +1,5 @@
 use libc::c_int;
-use std::{ffi::CStr, fmt, result, str};
+use std::{ffi::CStr, fmt, io, result, str};
 
 /// An MDBX error kind.
 #[derive(Debug)]
@@ -28,11 +28,46 @@ pub enum Error {
     Busy,
     Multival,
     WannaRecovery,
+    /// An appended key or value does not sort after the last one in the table.
     KeyMismatch,
     InvalidValue,
     Access,
+    Permission,
     TooLarge,
+    ThreadMismatch,
+    TxnOverlapping,
     DecodeError(Box<dyn std::error::Error + Send + Sync + 'static>),
+    /// A fixed-size value was decoded from data of a different length.
+    WrongValueSize {
+        expected: usize,
+        actual: usize,
+    },
+    /// Packed fixed-size values do not add up to a whole number of items.
+    UnalignedValues {
+        value_size: usize,
+        len: usize,
+    },
+    /// A value modified in the current read-write transaction cannot be borrowed, as later
+    /// writes may overwrite it.
+    CannotBorrow,
+    /// The operation was aborted through a [crate::CancellationToken]; `progress` is the number of
+    /// items processed before it stopped.
+    Cancelled {
+        progress: u64,
+    },
+    /// Incrementing a table sequence would overflow it.
+    SequenceOverflow,
+    /// The table cannot be dropped while other handles to it are in use.
+    TableInUse,
+    /// A read-write transaction could not be started before the deadline.
+    Timeout,
+    /// Input to a bulk load was not sorted; `index` is the position of the first item out of
+    /// order.
+    Unsorted {
+        index: u64,
+    },
+    /// Reading or writing data outside the database failed.
+    Io(io::Error),
     Other(c_int),
 }
 
@@ -67,11 +102,41 @@ impl Error {
             ffi::MDBX_EKEYMISMATCH => Error::KeyMismatch,
             ffi::MDBX_EINVAL => Error::InvalidValue,
             ffi::MDBX_EACCESS => Error::Access,
+            ffi::MDBX_EPERM => Error::Permission,
             ffi::MDBX_TOO_LARGE => Error::TooLarge,
+            ffi::MDBX_THREAD_MISMATCH => Error::ThreadMismatch,
+            ffi::MDBX_TXN_OVERLAPPING => Error::TxnOverlapping,
             other => Error::Other(other),
         }
     }
 
+    /// The raw error code.
+    ///
+    /// Errors raised by this crate rather than by MDBX map to the closest `errno` value.
+    pub fn code(&self) -> c_int {
+        self.to_err_code()
+    }
+
+    /// Whether the database has reached its maximum size.
+    pub fn is_map_full(&self) -> bool {
+        matches!(self, Error::MapFull)
+    }
+
+    /// Whether the database or a table is in use elsewhere.
+    pub fn is_busy(&self) -> bool {
+        matches!(self, Error::Busy | Error::TableInUse)
+    }
+
+    /// Whether the requested item or table does not exist.
+    pub fn is_not_found(&self) -> bool {
+        matches!(self, Error::NotFound)
+    }
+
+    /// Whether the key or key/value pair already exists.
+    pub fn is_key_exist(&self) -> bool {
+        matches!(self, Error::KeyExist)
+    }
+
     /// Converts an [Error] to the raw error code.
     fn to_err_code(&self) -> c_int {
         match self {
@@ -102,9 +167,21 @@ impl Error {
             Error::KeyMismatch => ffi::MDBX_EKEYMISMATCH,
             Error::InvalidValue => ffi::MDBX_EINVAL,
             Error::Access => ffi::MDBX_EACCESS,
+            Error::Permission => ffi::MDBX_EPERM,
             Error::TooLarge => ffi::MDBX_TOO_LARGE,
+            Error::ThreadMismatch => ffi::MDBX_THREAD_MISMATCH,
+            Error::TxnOverlapping => ffi::MDBX_TXN_OVERLAPPING,
+            Error::DecodeError(_)
+            | Error::WrongValueSize { .. }
+            | Error::UnalignedValues { .. }
+            | Error::CannotBorrow => ffi::MDBX_EINVAL,
+            Error::Cancelled { .. } => libc::ECANCELED,
+            Error::SequenceOverflow => libc::EOVERFLOW,
+            Error::TableInUse => libc::EBUSY,
+            Error::Timeout => libc::ETIMEDOUT,
+            Error::Unsorted { .. } => ffi::MDBX_EKEYMISMATCH,
+            Error::Io(e) => e.raw_os_error().unwrap_or(libc::EIO),
             Error::Other(err_code) => *err_code,
-            _ => unreachable!(),
         }
     }
 }
@@ -113,6 +190,27 @@ impl fmt::Display for Error {
     fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
         match self {
             Error::DecodeError(reason) => write!(fmt, "{reason}"),
+            Error::WrongValueSize { expected, actual } => {
+                write!(
+                    fmt,
+                    "wrong value size: expected {expected} bytes, got {actual}"
+                )
+            }
+            Error::UnalignedValues { value_size, len } => {
+                write!(
+                    fmt,
+                    "{len} bytes of values is not a multiple of the value size {value_size}"
+                )
+            }
+            Error::CannotBorrow => write!(fmt, "value cannot be borrowed from the database"),
+            Error::Cancelled { progress } => {
+                write!(fmt, "operation cancelled after {progress} items")
+            }
+            Error::SequenceOverflow => write!(fmt, "table sequence overflow"),
+            Error::TableInUse => write!(fmt, "table is still in use"),
+            Error::Timeout => write!(fmt, "timed out waiting for a read-write transaction"),
+            Error::Unsorted { index } => write!(fmt, "input item {index} is out of order"),
+            Error::Io(e) => write!(fmt, "{e}"),
             other => {
                 write!(fmt, "{}", unsafe {
                     let err = ffi::mdbx_strerror(other.to_err_code());
@@ -123,7 +221,59 @@ impl fmt::Display for Error {
     }
 }
 
-impl std::error::Error for Error {}
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::DecodeError(reason) => Some(&**reason),
+            Error::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(error: Error) -> Self {
+        let kind = match error {
+            Error::NotFound => io::ErrorKind::NotFound,
+            Error::KeyExist => io::ErrorKind::AlreadyExists,
+            Error::Access | Error::Permission => io::ErrorKind::PermissionDenied,
+            Error::Busy | Error::TableInUse => io::ErrorKind::ResourceBusy,
+            Error::MapFull
+            | Error::DbsFull
+            | Error::ReadersFull
+            | Error::TxnFull
+            | Error::CursorFull
+            | Error::PageFull
+            | Error::UnableExtendMapsize => io::ErrorKind::StorageFull,
+            Error::Corrupted
+            | Error::PageNotFound
+            | Error::Invalid
+            | Error::VersionMismatch
+            | Error::DecodeError(_)
+            | Error::WrongValueSize { .. } => io::ErrorKind::InvalidData,
+            Error::InvalidValue
+            | Error::BadValSize
+            | Error::BadDbi
+            | Error::KeyMismatch
+            | Error::TooLarge
+            | Error::Incompatible
+            | Error::UnalignedValues { .. }
+            | Error::Unsorted { .. } => io::ErrorKind::InvalidInput,
+            Error::Cancelled { .. } => io::ErrorKind::Interrupted,
+            Error::Timeout => io::ErrorKind::TimedOut,
+            Error::Io(e) => return e,
+            Error::Other(code) if code > 0 => return io::Error::from_raw_os_error(code),
+            _ => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, error)
+    }
+}
+
+impl From<io::Error> for Error {
+    fn from(error: io::Error) -> Self {
+        Error::Io(error)
+    }
+}
 
 /// An MDBX result.
 pub type Result<T> = result::Result<T, Error>;
@@ -160,4 +310,37 @@ mod test {
             Error::Invalid.to_string()
         );
     }
+
+    #[test]
+    fn test_code() {
+        for code in [
+            ffi::MDBX_MAP_FULL,
+            ffi::MDBX_TXN_OVERLAPPING,
+            ffi::MDBX_THREAD_MISMATCH,
+            ffi::MDBX_EKEYMISMATCH,
+            12345,
+        ] {
+            assert_eq!(Error::from_err_code(code).code(), code);
+        }
+        assert_eq!(Error::Timeout.code(), libc::ETIMEDOUT);
+    }
+
+    #[test]
+    fn test_io_error() {
+        let err = io::Error::from(Error::NotFound);
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+        assert!(matches!(
+            err.get_ref().unwrap().downcast_ref::<Error>(),
+            Some(Error::NotFound)
+        ));
+
+        assert_eq!(
+            io::Error::from(Error::MapFull).kind(),
+            io::ErrorKind::StorageFull
+        );
+        assert_eq!(
+            io::Error::from(Error::Other(libc::ENOENT)).raw_os_error(),
+            Some(libc::ENOENT)
+        );
+    }
 }
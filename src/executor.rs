@@ -0,0 +1,315 @@
+use crate::{
+    database::{Database, DatabaseKind, Writable},
+    error::Result,
+    transaction::{Transaction, RO, RW},
+};
+use parking_lot::Mutex;
+use std::{
+    fmt,
+    future::Future,
+    io,
+    panic::{self, AssertUnwindSafe},
+    pin::Pin,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc, Arc,
+    },
+    task::{Context, Poll, Waker},
+    thread::{self, JoinHandle},
+};
+
+type Job<E> = Box<dyn FnOnce(&Database<E>) + Send>;
+
+/// A [Database] usable from async code.
+///
+/// MDBX transactions block and are bound to the thread that started them, so closures passed to
+/// [AsyncDatabase::read] and [AsyncDatabase::write] run off the async runtime and hand their
+/// result back through the returned [Pending] future. Reads run on a bounded pool of reader
+/// threads, while writes are queued to a single writer thread and executed one after another, in
+/// the order they were submitted. Threads are started as they are needed.
+///
+/// The futures do not depend on any particular runtime. Dropping the handle waits for the queued
+/// reads and writes to finish.
+pub struct AsyncDatabase<E>
+where
+    E: DatabaseKind,
+{
+    db: Arc<Database<E>>,
+    readers: Workers<E>,
+    writer: Workers<E>,
+}
+
+impl<E> AsyncDatabase<E>
+where
+    E: DatabaseKind,
+{
+    /// Takes ownership of the database, with up to one reader thread per available CPU.
+    pub fn new(db: Database<E>) -> Self {
+        let readers = thread::available_parallelism().map_or(1, |n| n.get());
+        Self::with_readers(db, readers)
+    }
+
+    /// Takes ownership of the database, with up to `readers` reader threads.
+    pub fn with_readers(db: Database<E>, readers: usize) -> Self {
+        Self {
+            db: Arc::new(db),
+            readers: Workers::new("mdbx-reader", readers.max(1)),
+            writer: Workers::new("mdbx-writer", 1),
+        }
+    }
+
+    /// The underlying database, for synchronous use.
+    pub fn database(&self) -> &Arc<Database<E>> {
+        &self.db
+    }
+
+    /// Runs `f` in a new read-only transaction on a reader thread.
+    ///
+    /// If `f` panics, the panic is resumed when the returned future is polled. If no reader
+    /// thread could be started, the future resolves to [Error::Io](crate::Error::Io).
+    pub fn read<F, T>(&self, f: F) -> Pending<Result<T>>
+    where
+        F: for<'db> FnOnce(&Transaction<'db, RO, E>) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        self.submit(&self.readers, move |db| {
+            db.begin_ro_txn().map(|txn| f(&txn))
+        })
+    }
+
+    /// Runs `f` on one of `workers`, handing its outcome over to the returned future.
+    fn submit<F, T>(&self, workers: &Workers<E>, f: F) -> Pending<Result<T>>
+    where
+        F: FnOnce(&Database<E>) -> Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let (sender, pending) = oneshot();
+        match workers.start(&self.db) {
+            Ok(()) => workers.send(Box::new(move |db: &Database<E>| sender.send(|| f(db)))),
+            Err(error) => sender.send(|| Err(error.into())),
+        }
+        pending
+    }
+}
+
+impl<E> AsyncDatabase<E>
+where
+    E: Writable,
+{
+    /// Runs `f` in a read-write transaction on the writer thread.
+    ///
+    /// The transaction is committed if `f` returns `Ok`, and aborted if it returns an error or
+    /// panics. A panic is resumed when the returned future is polled; the writer thread carries on
+    /// with the next queued write. If the writer thread could not be started, the future resolves
+    /// to [Error::Io](crate::Error::Io).
+    pub fn write<F, T>(&self, f: F) -> Pending<Result<T>>
+    where
+        F: for<'db> FnOnce(&Transaction<'db, RW, E>) -> Result<T> + Send + 'static,
+        T: Send + 'static,
+    {
+        self.submit(&self.writer, move |db| {
+            let txn = db.begin_rw_txn()?;
+            let value = f(&txn)?;
+            txn.commit()?;
+            Ok(value)
+        })
+    }
+}
+
+impl<E> From<Database<E>> for AsyncDatabase<E>
+where
+    E: DatabaseKind,
+{
+    fn from(db: Database<E>) -> Self {
+        Self::new(db)
+    }
+}
+
+impl<E> fmt::Debug for AsyncDatabase<E>
+where
+    E: DatabaseKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AsyncDatabase")
+            .field("db", &self.db)
+            .finish()
+    }
+}
+
+/// Threads running jobs from a shared queue, started on demand up to a limit.
+struct Workers<E>
+where
+    E: DatabaseKind,
+{
+    name: &'static str,
+    limit: usize,
+    state: Mutex<WorkersState<E>>,
+}
+
+struct WorkersState<E>
+where
+    E: DatabaseKind,
+{
+    /// Dropped to let the threads exit.
+    sender: Option<mpsc::Sender<Job<E>>>,
+    receiver: Arc<Mutex<mpsc::Receiver<Job<E>>>>,
+    threads: Vec<JoinHandle<()>>,
+    /// Number of threads waiting for a job.
+    idle: Arc<AtomicUsize>,
+}
+
+impl<E> Workers<E>
+where
+    E: DatabaseKind,
+{
+    fn new(name: &'static str, limit: usize) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        Self {
+            name,
+            limit,
+            state: Mutex::new(WorkersState {
+                sender: Some(sender),
+                receiver: Arc::new(Mutex::new(receiver)),
+                threads: Vec::new(),
+                idle: Arc::new(AtomicUsize::new(0)),
+            }),
+        }
+    }
+
+    /// Starts another thread if none is idle and the limit is not reached yet. Fails only if no
+    /// thread is running and none can be started.
+    fn start(&self, db: &Arc<Database<E>>) -> io::Result<()> {
+        let mut state = self.state.lock();
+        if state.idle.load(Ordering::SeqCst) > 0 || state.threads.len() >= self.limit {
+            return Ok(());
+        }
+
+        let receiver = state.receiver.clone();
+        let idle = state.idle.clone();
+        let db = db.clone();
+        // Counted before the thread starts, so it never takes a job before being counted.
+        state.idle.fetch_add(1, Ordering::SeqCst);
+        let spawned = thread::Builder::new()
+            .name(self.name.into())
+            .spawn(move || loop {
+                let Ok(job) = receiver.lock().recv() else {
+                    break;
+                };
+                idle.fetch_sub(1, Ordering::SeqCst);
+                job(&db);
+                idle.fetch_add(1, Ordering::SeqCst);
+            });
+        match spawned {
+            Ok(thread) => {
+                state.threads.push(thread);
+                Ok(())
+            }
+            Err(error) => {
+                state.idle.fetch_sub(1, Ordering::SeqCst);
+                if state.threads.is_empty() {
+                    Err(error)
+                } else {
+                    Ok(())
+                }
+            }
+        }
+    }
+
+    /// Queues `job` for the next idle thread.
+    fn send(&self, job: Job<E>) {
+        // The threads only exit once the sender is dropped.
+        let _ = self.state.lock().sender.as_ref().unwrap().send(job);
+    }
+}
+
+impl<E> Drop for Workers<E>
+where
+    E: DatabaseKind,
+{
+    fn drop(&mut self) {
+        let state = self.state.get_mut();
+        state.sender.take();
+        for thread in state.threads.drain(..) {
+            let _ = thread.join();
+        }
+    }
+}
+
+struct Slot<T> {
+    value: Option<thread::Result<T>>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+fn oneshot<T>() -> (Sender<T>, Pending<T>) {
+    let slot = Arc::new(Mutex::new(Slot {
+        value: None,
+        waker: None,
+        closed: false,
+    }));
+    (Sender(slot.clone()), Pending(slot))
+}
+
+struct Sender<T>(Arc<Mutex<Slot<T>>>);
+
+impl<T> Sender<T> {
+    /// Runs `f`, catching any panic, and hands the outcome over to the [Pending] future.
+    fn send(self, f: impl FnOnce() -> T) {
+        let value = panic::catch_unwind(AssertUnwindSafe(f));
+        let waker = {
+            let mut slot = self.0.lock();
+            slot.value = Some(value);
+            slot.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<T> Drop for Sender<T> {
+    fn drop(&mut self) {
+        let waker = {
+            let mut slot = self.0.lock();
+            slot.closed = true;
+            slot.waker.take()
+        };
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+/// The result of a closure submitted to an [AsyncDatabase].
+///
+/// The closure runs whether or not this future is polled; dropping it only discards the result.
+#[must_use = "dropping the future discards the result of the closure"]
+pub struct Pending<T>(Arc<Mutex<Slot<T>>>);
+
+impl<T> Future for Pending<T> {
+    type Output = T;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<T> {
+        let mut slot = self.0.lock();
+        match slot.value.take() {
+            Some(Ok(value)) => Poll::Ready(value),
+            Some(Err(payload)) => {
+                drop(slot);
+                panic::resume_unwind(payload)
+            }
+            None if slot.closed => panic!("the closure was dropped without running"),
+            None => {
+                slot.waker = Some(cx.waker().clone());
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<T> fmt::Debug for Pending<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Pending")
+            .field("ready", &self.0.lock().value.is_some())
+            .finish()
+    }
+}
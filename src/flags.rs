@@ -1,12 +1,22 @@
 use bitflags::bitflags;
 use ffi::*;
-use libc::c_uint;
+use libc::{c_int, c_uint};
 
 /// MDBX sync mode
-#[derive(Clone, Copy, Debug)]
+///
+/// In short, on a system crash:
+/// - [SyncMode::Durable] loses nothing;
+/// - [SyncMode::NoMetaSync] may undo the last committed transaction;
+/// - [SyncMode::SafeNoSync] may undo the transactions since the last sync;
+/// - [SyncMode::UtterlyNoSync] may corrupt the database.
+///
+/// An application crash loses nothing in any mode. All processes using a database must use
+/// compatible modes, see [Database::sync_mode()](crate::Database::sync_mode).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub enum SyncMode {
     /// Default robust and durable sync mode.
     /// Metadata is written and flushed to disk after a data is written and flushed, which guarantees the integrity of the database in the event of a crash at any time.
+    #[default]
     Durable,
 
     /// Don't sync the meta-page after commit.
@@ -68,14 +78,24 @@ pub enum SyncMode {
     UtterlyNoSync,
 }
 
-impl Default for SyncMode {
-    fn default() -> Self {
-        Self::Durable
-    }
+/// How [Database::remove_files()](crate::Database::remove_files) treats databases that are still
+/// in use by other handles.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DeleteMode {
+    /// Delete the files without checking whether the database is in use.
+    #[default]
+    JustDelete,
+    /// Fail with [Error::Busy](crate::Error::Busy) if the database is in use, e.g. to remove a
+    /// cache that may have been opened by another process.
+    EnsureUnused,
+    /// Wait until the database is no longer in use, then delete it.
+    WaitForUnused,
 }
 
 #[derive(Clone, Copy, Debug)]
 pub enum Mode {
+    /// Open an existing database with `MDBX_RDONLY`. No files are created and read-write
+    /// transactions are refused.
     ReadOnly,
     ReadWrite(ReadWriteOptions),
 }
@@ -86,12 +106,42 @@ impl Default for Mode {
     }
 }
 
+/// Options for databases opened for writing.
+///
+/// The database file grows automatically by `growth_step` whenever a write needs more space, up
+/// to `max_size`. Writes only fail with [Error::MapFull](crate::Error::MapFull) once `max_size`
+/// is reached. Sizes are in bytes, [None] keeps the MDBX default.
+///
+/// MDBX rounds the sizes to whole pages, and rounds the growth step and shrink threshold up to
+/// multiples of the system allocation granularity before storing them with limited precision.
+/// Rather than an error, the adjusted geometry is reported by
+/// [Database::geometry()](crate::Database::geometry).
 #[derive(Clone, Copy, Debug, Default)]
 pub struct ReadWriteOptions {
     pub sync_mode: SyncMode,
+    /// Lower bound of the database size.
+    pub min_size: Option<isize>,
+    /// Upper bound of the database size.
+    pub max_size: Option<isize>,
+    /// Step by which the database grows when it runs out of space.
+    pub growth_step: Option<isize>,
+    /// Amount of unused space at the end of the database above which it is shrunk.
+    pub shrink_threshold: Option<isize>,
+}
+
+/// New size bounds for an open database, see [Database::set_geometry()](crate::Database::set_geometry).
+///
+/// Sizes are in bytes and adjusted like those of [ReadWriteOptions]. [None] keeps the current
+/// value.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Geometry {
+    /// Lower bound of the database size.
     pub min_size: Option<isize>,
+    /// Upper bound of the database size.
     pub max_size: Option<isize>,
+    /// Step by which the database grows when it runs out of space.
     pub growth_step: Option<isize>,
+    /// Amount of unused space at the end of the database above which it is shrunk.
     pub shrink_threshold: Option<isize>,
 }
 
@@ -125,3 +175,87 @@ bitflags! {
         const MULTIPLE = MDBX_MULTIPLE;
     }
 }
+
+bitflags! {
+    #[doc="Transaction options and state."]
+    #[derive(Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+    pub struct TransactionFlags: c_int {
+        const READ_ONLY = MDBX_TXN_RDONLY;
+        const TRY = MDBX_TXN_TRY;
+        const NO_META_SYNC = MDBX_TXN_NOMETASYNC;
+        const NO_SYNC = MDBX_TXN_NOSYNC;
+        const INVALID = MDBX_TXN_INVALID;
+        const FINISHED = MDBX_TXN_FINISHED;
+        const ERROR = MDBX_TXN_ERROR;
+        const DIRTY = MDBX_TXN_DIRTY;
+        const SPILLS = MDBX_TXN_SPILLS;
+        const HAS_CHILD = MDBX_TXN_HAS_CHILD;
+    }
+}
+
+bitflags! {
+    #[doc="Runtime debug options of MDBX, see [crate::setup_debug]."]
+    #[derive(Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+    pub struct DebugFlags: c_int {
+        /// Enable assertions. Only effective if libmdbx is built with `MDBX_DEBUG`.
+        const ASSERT = MDBX_DBG_ASSERT;
+        /// Enable internal consistency audits. Only effective if libmdbx is built with
+        /// `MDBX_DEBUG`.
+        const AUDIT = MDBX_DBG_AUDIT;
+        /// Enable small random delays in critical points. Only effective if libmdbx is built with
+        /// `MDBX_DEBUG`.
+        const JITTER = MDBX_DBG_JITTER;
+        /// Include the whole database in core dumps.
+        const DUMP = MDBX_DBG_DUMP;
+        const LEGACY_MULTIOPEN = MDBX_DBG_LEGACY_MULTIOPEN;
+        const LEGACY_OVERLAP = MDBX_DBG_LEGACY_OVERLAP;
+        /// Do not upgrade the database format when opening it.
+        const DONT_UPGRADE = MDBX_DBG_DONT_UPGRADE;
+    }
+}
+
+bitflags! {
+    #[doc="Options of [crate::Database::warmup]. Without any flags, the OS is only asked to prefetch the database pages asynchronously."]
+    #[derive(Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+    pub struct WarmupFlags: c_uint {
+        /// Read all allocated pages, including unused ones, to force them into memory.
+        const FORCE = MDBX_warmup_force;
+        /// With [WarmupFlags::FORCE], read the pages through system calls rather than directly,
+        /// which is slower but avoids the OOM killer when memory runs short. POSIX only.
+        const OOM_SAFE = MDBX_warmup_oomsafe;
+        /// Lock the allocated pages in memory until the database is closed or its geometry
+        /// changes. This requires adequate resource limits, see [WarmupFlags::TOUCH_LIMIT].
+        const LOCK = MDBX_warmup_lock;
+        /// Raise the resource limits of the process to fit the current database size. Raising
+        /// them past the hard limits requires privileges, otherwise the warmup fails with
+        /// [Error::Permission](crate::Error::Permission).
+        const TOUCH_LIMIT = MDBX_warmup_touchlimit;
+        /// Release pages locked by an earlier [WarmupFlags::LOCK].
+        const RELEASE = MDBX_warmup_release;
+    }
+}
+
+bitflags! {
+    #[doc="Options of [crate::Database::copy_to_path] and [crate::Database::copy_to_fd]."]
+    #[derive(Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+    pub struct CopyFlags: c_uint {
+        /// Omit free pages and renumber the pages sequentially, producing a smaller copy.
+        const COMPACT = MDBX_CP_COMPACT;
+        /// Lower the size bound of the copy to the minimum, so it can shrink even if the database
+        /// has a fixed size.
+        const FORCE_DYNAMIC_SIZE = MDBX_CP_FORCE_DYNAMIC_SIZE;
+    }
+}
+
+bitflags! {
+    #[doc="Flags which can be changed on an open database with [crate::Database::set_flags]. The sync mode is changed with [crate::Database::set_sync_mode] instead."]
+    #[derive(Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Clone, Copy)]
+    pub struct RuntimeFlags: c_uint {
+        /// Do not zero the unused parts of pages before writing them, see
+        /// [DatabaseOptions::no_meminit](crate::DatabaseOptions::no_meminit).
+        const NO_MEMINIT = MDBX_NOMEMINIT;
+        /// Merge the GC records of freed pages, see
+        /// [DatabaseOptions::coalesce](crate::DatabaseOptions::coalesce).
+        const COALESCE = MDBX_COALESCE;
+    }
+}
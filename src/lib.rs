@@ -3,22 +3,47 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 
 pub use crate::{
+    cancellation::{Cancellable, CancellationToken},
+    check::{CheckLevel, CheckReport, Inconsistency, TableReport},
     codec::*,
-    cursor::{Cursor, IntoIter, Iter, IterDup},
+    comparator::{Comparator, ComparatorFn},
+    cursor::{Cursor, ExactIter, IntoIter, Iter, IterDup, IterDupFixed, IterRange, UnboundCursor},
     database::{
-        Database, DatabaseKind, DatabaseOptions, Info, NoWriteMap, PageSize, Stat, WriteMap,
+        Database, DatabaseKind, DatabaseOptions, DatabaseStats, GeometryInfo, Info, MdbxOption,
+        NoWriteMap, PageOps, PageSize, ReadOnly, Stat, Writable, WriteMap,
     },
+    dump::{DumpFormat, MalformedDump},
     error::{Error, Result},
     flags::*,
-    table::Table,
-    transaction::{Transaction, TransactionKind, RO, RW},
+    logging::{setup_debug, LogLevel, LogRecord, Logger},
+    owned::OwnedRoTransaction,
+    page_walk::{PageInfo, PageKind, PageOwner},
+    pool::{CursorPool, PooledCursor},
+    slow_reader::{SlowReader, SlowReaderAction},
+    sys_ram::SysRamInfo,
+    table::{FixedValueTable, IntegerKeyTable, Table},
+    transaction::{Canary, CommitLatency, Transaction, TransactionKind, TxnInfo, RO, RW},
 };
 
+mod bulk;
+mod cancellation;
+mod check;
 mod codec;
+mod comparator;
 mod cursor;
 mod database;
+mod dump;
 mod error;
+#[cfg(feature = "async")]
+mod executor;
 mod flags;
+pub mod limits;
+mod logging;
+mod owned;
+mod page_walk;
+mod pool;
+mod slow_reader;
+mod sys_ram;
 mod table;
 mod transaction;
 
@@ -27,6 +52,10 @@ mod transaction;
 #[cfg_attr(docsrs, doc(cfg(feature = "orm")))]
 pub mod orm;
 
+#[cfg(feature = "async")]
+#[cfg_attr(docsrs, doc(cfg(feature = "async")))]
+pub use crate::executor::{AsyncDatabase, Pending};
+
 #[cfg(feature = "orm")]
 mod orm_uses {
     #[doc(hidden)]
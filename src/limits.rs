@@ -0,0 +1,79 @@
+//! Limits of MDBX by page size, e.g. to validate a configuration before opening a database.
+//!
+//! The page size is either 0 for the [default_page_size], or a power of two between
+//! [MIN_PAGE_SIZE] and [MAX_PAGE_SIZE]. Otherwise every function fails with
+//! [Error::InvalidValue].
+
+use crate::{
+    error::{Error, Result},
+    flags::TableFlags,
+};
+
+/// Smallest page size supported.
+pub const MIN_PAGE_SIZE: usize = ffi::MDBX_MIN_PAGESIZE as usize;
+
+/// Largest page size supported.
+pub const MAX_PAGE_SIZE: usize = ffi::MDBX_MAX_PAGESIZE as usize;
+
+/// Page size used when none is set, i.e. the page size of the operating system.
+pub fn default_page_size() -> usize {
+    unsafe { ffi::mdbx_default_pagesize() }
+}
+
+/// Smallest database size in bytes.
+pub fn min_db_size(page_size: usize) -> Result<usize> {
+    limit(page_size, |page_size| unsafe {
+        ffi::mdbx_limits_dbsize_min(page_size)
+    })
+}
+
+/// Largest database size in bytes.
+pub fn max_db_size(page_size: usize) -> Result<usize> {
+    limit(page_size, |page_size| unsafe {
+        ffi::mdbx_limits_dbsize_max(page_size)
+    })
+}
+
+/// Largest key size in bytes for a table with the given flags.
+pub fn max_key_size(page_size: usize, flags: TableFlags) -> Result<usize> {
+    limit(page_size, |page_size| unsafe {
+        ffi::mdbx_limits_keysize_max(page_size, flags.bits())
+    })
+}
+
+/// Largest value size in bytes for a table with the given flags.
+///
+/// For [TableFlags::DUP_SORT] tables values are stored like keys, so the limit is much lower.
+pub fn max_value_size(page_size: usize, flags: TableFlags) -> Result<usize> {
+    limit(page_size, |page_size| unsafe {
+        ffi::mdbx_limits_valsize_max(page_size, flags.bits())
+    })
+}
+
+/// Largest size in bytes of a key and value stored together in a leaf page, for a table with the
+/// given flags. Larger values are stored in separate large pages.
+pub fn max_inline_pair_size(page_size: usize, flags: TableFlags) -> Result<usize> {
+    limit(page_size, |page_size| unsafe {
+        ffi::mdbx_limits_pairsize4page_max(page_size, flags.bits())
+    })
+}
+
+/// Largest value size in bytes stored within a leaf page, for a table with the given flags.
+/// Larger values are stored in separate large pages.
+pub fn max_inline_value_size(page_size: usize, flags: TableFlags) -> Result<usize> {
+    limit(page_size, |page_size| unsafe {
+        ffi::mdbx_limits_valsize4page_max(page_size, flags.bits())
+    })
+}
+
+/// Largest size in bytes of the changes of a single write transaction.
+pub fn max_txn_size(page_size: usize) -> Result<usize> {
+    limit(page_size, |page_size| unsafe {
+        ffi::mdbx_limits_txnsize_max(page_size)
+    })
+}
+
+fn limit(page_size: usize, f: impl FnOnce(isize) -> isize) -> Result<usize> {
+    let page_size = isize::try_from(page_size).map_err(|_| Error::InvalidValue)?;
+    usize::try_from(f(page_size)).map_err(|_| Error::InvalidValue)
+}
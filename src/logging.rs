@@ -0,0 +1,121 @@
+use crate::flags::DebugFlags;
+use libc::{c_char, c_int, c_void};
+use parking_lot::RwLock;
+use std::{ffi::CStr, mem, panic};
+
+/// Verbosity of the messages MDBX passes to the logger, see [setup_debug].
+///
+/// Unless libmdbx itself is built with `MDBX_DEBUG`, messages more verbose than
+/// [LogLevel::Notice] are compiled out.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Fatal,
+    Error,
+    Warn,
+    Notice,
+    Verbose,
+    Debug,
+    Trace,
+    Extra,
+}
+
+impl LogLevel {
+    fn from_raw(level: ffi::MDBX_log_level_t) -> Self {
+        match level {
+            ffi::MDBX_LOG_FATAL => Self::Fatal,
+            ffi::MDBX_LOG_ERROR => Self::Error,
+            ffi::MDBX_LOG_WARN => Self::Warn,
+            ffi::MDBX_LOG_NOTICE => Self::Notice,
+            ffi::MDBX_LOG_VERBOSE => Self::Verbose,
+            ffi::MDBX_LOG_DEBUG => Self::Debug,
+            ffi::MDBX_LOG_TRACE => Self::Trace,
+            _ => Self::Extra,
+        }
+    }
+
+    fn as_raw(self) -> ffi::MDBX_log_level_t {
+        match self {
+            Self::Fatal => ffi::MDBX_LOG_FATAL,
+            Self::Error => ffi::MDBX_LOG_ERROR,
+            Self::Warn => ffi::MDBX_LOG_WARN,
+            Self::Notice => ffi::MDBX_LOG_NOTICE,
+            Self::Verbose => ffi::MDBX_LOG_VERBOSE,
+            Self::Debug => ffi::MDBX_LOG_DEBUG,
+            Self::Trace => ffi::MDBX_LOG_TRACE,
+            Self::Extra => ffi::MDBX_LOG_EXTRA,
+        }
+    }
+}
+
+/// A message logged by MDBX.
+#[derive(Clone, Copy, Debug)]
+pub struct LogRecord<'a> {
+    pub level: LogLevel,
+    /// The MDBX function which logged the message, if known.
+    pub function: Option<&'a str>,
+    /// The source line which logged the message, or 0 if unknown.
+    pub line: u32,
+    /// The formatted message, without a trailing newline.
+    pub message: &'a str,
+}
+
+/// Receives the messages logged by MDBX, see [setup_debug].
+///
+/// Forwarding to a logging framework such as `log` only takes mapping the [LogLevel] and passing
+/// the message on, e.g. with `"mdbx"` as the target.
+pub type Logger = fn(&LogRecord<'_>);
+
+static LOGGER: RwLock<Option<Logger>> = RwLock::new(None);
+
+/// Longest message passed to a [Logger]; longer ones are truncated.
+const MAX_MESSAGE_LEN: usize = 1024;
+
+/// Set the level and debug flags of MDBX's internal logging, and where messages go.
+///
+/// With a `logger`, messages up to `level` are formatted and passed to it; without one, MDBX
+/// prints them to stderr. This is process-wide and applies to all databases.
+pub fn setup_debug(level: LogLevel, flags: DebugFlags, logger: Option<Logger>) {
+    *LOGGER.write() = logger;
+    // MDBX passes its `va_list` argument on, and all supported targets pass a `va_list` argument
+    // as a single pointer, so the trampoline can treat it as an opaque one.
+    let raw_logger: ffi::MDBX_debug_func = logger.map(|_| unsafe {
+        mem::transmute(trampoline as unsafe extern "C" fn(_, _, _, _, *mut c_void))
+    });
+    unsafe {
+        ffi::mdbx_setup_debug(level.as_raw(), flags.bits(), raw_logger);
+    }
+}
+
+extern "C" {
+    fn vsnprintf(buf: *mut c_char, size: usize, fmt: *const c_char, args: *mut c_void) -> c_int;
+}
+
+unsafe extern "C" fn trampoline(
+    level: ffi::MDBX_log_level_t,
+    function: *const c_char,
+    line: c_int,
+    fmt: *const c_char,
+    args: *mut c_void,
+) {
+    let Some(logger) = *LOGGER.read() else {
+        return;
+    };
+
+    let mut buf = [0u8; MAX_MESSAGE_LEN + 1];
+    if vsnprintf(buf.as_mut_ptr().cast(), buf.len(), fmt, args) < 0 {
+        return;
+    }
+    let message = CStr::from_bytes_until_nul(&buf).unwrap_or_default();
+    let message = String::from_utf8_lossy(message.to_bytes());
+    let function = (!function.is_null()).then(|| CStr::from_ptr(function).to_string_lossy());
+
+    let record = LogRecord {
+        level: LogLevel::from_raw(level),
+        function: function.as_deref(),
+        line: line.max(0) as u32,
+        message: message.trim_end_matches('\n'),
+    };
+    // Unwinding into MDBX is undefined behaviour, and a failing logger should not bring down the
+    // database it is reporting on.
+    let _ = panic::catch_unwind(|| logger(&record));
+}
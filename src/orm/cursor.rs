@@ -103,7 +103,7 @@ where
     pub fn walk(
         self,
         start: Option<T::SeekKey>,
-    ) -> impl Iterator<Item = anyhow::Result<(T::Key, T::Value)>>
+    ) -> impl Iterator<Item = anyhow::Result<(T::Key, T::Value)>> + 'tx
     where
         T: Table,
         T::Key: Decodable,
@@ -153,7 +153,7 @@ where
     pub fn walk_back(
         self,
         start: Option<T::SeekKey>,
-    ) -> impl Iterator<Item = anyhow::Result<(T::Key, T::Value)>>
+    ) -> impl Iterator<Item = anyhow::Result<(T::Key, T::Value)>> + 'tx
     where
         T: Table,
         T::Key: Decodable,
@@ -268,7 +268,7 @@ where
         self,
         start: T::Key,
         seek_value: Option<T::SeekValue>,
-    ) -> impl Iterator<Item = anyhow::Result<T::Value>>
+    ) -> impl Iterator<Item = anyhow::Result<T::Value>> + 'tx
     where
         T::Key: Clone + Decodable,
     {
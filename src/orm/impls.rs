@@ -77,6 +77,7 @@ impl Encodable for String {
     }
 }
 
+/// Decodes valid UTF-8 only, failing with the [std::string::FromUtf8Error] otherwise.
 impl Decodable for String {
     fn decode(b: &[u8]) -> anyhow::Result<Self> {
         Ok(String::from_utf8(b.into())?)
@@ -184,10 +185,82 @@ macro_rules! table_integer {
     };
 }
 
+// Unsigned integers are stored big-endian, so that the bytewise order of keys matches their
+// numeric order.
+table_integer!(u16 => u16);
 table_integer!(u32 => u32);
 table_integer!(u64 => u64);
 table_integer!(u128 => u128);
 
+/// Stored big-endian with the sign bit flipped, so that negative keys sort before positive ones.
+impl Encodable for i64 {
+    type Encoded = [u8; 8];
+
+    fn encode(self) -> Self::Encoded {
+        ((self as u64) ^ (1 << 63)).to_be_bytes()
+    }
+}
+
+impl Decodable for i64 {
+    fn decode(b: &[u8]) -> anyhow::Result<Self> {
+        Ok((u64::decode(b)? ^ (1 << 63)) as i64)
+    }
+}
+
+macro_rules! table_native_integer {
+    ($(#[$docs:meta])* $name:ident => $real_ty:ident) => {
+        $(#[$docs])*
+        #[derive(
+            Clone,
+            Copy,
+            Debug,
+            Deref,
+            DerefMut,
+            Default,
+            Display,
+            PartialEq,
+            Eq,
+            From,
+            PartialOrd,
+            Ord,
+            Hash,
+        )]
+        pub struct $name(pub $real_ty);
+
+        impl Encodable for $name {
+            type Encoded = [u8; $real_ty::BITS as usize / 8];
+
+            fn encode(self) -> Self::Encoded {
+                self.0.to_ne_bytes()
+            }
+        }
+
+        impl Decodable for $name {
+            fn decode(b: &[u8]) -> anyhow::Result<Self> {
+                const EXPECTED: usize = $real_ty::BITS as usize / 8;
+
+                match b.len() {
+                    EXPECTED => Ok(Self($real_ty::from_ne_bytes(*arrayref::array_ref!(
+                        &*b, 0, EXPECTED
+                    )))),
+                    other => Err(BadLength::<EXPECTED> { received: other }.into()),
+                }
+            }
+        }
+    };
+}
+
+table_native_integer!(
+    /// A [u32] stored native-endian, as expected by [TableFlags::INTEGER_KEY](crate::TableFlags::INTEGER_KEY)
+    /// and [TableFlags::INTEGER_DUP](crate::TableFlags::INTEGER_DUP) tables.
+    NativeU32 => u32
+);
+table_native_integer!(
+    /// A [u64] stored native-endian, as expected by [TableFlags::INTEGER_KEY](crate::TableFlags::INTEGER_KEY)
+    /// and [TableFlags::INTEGER_DUP](crate::TableFlags::INTEGER_DUP) tables.
+    NativeU64 => u64
+);
+
 impl<T, const LEN: usize> Encodable for CutStart<T>
 where
     T: Encodable<Encoded = [u8; LEN]>,
@@ -82,10 +82,16 @@
 mod cursor;
 mod database;
 mod impls;
+#[cfg(feature = "serde")]
+mod serde_codec;
 mod traits;
 mod transaction;
+mod typed_table;
 
-pub use self::{cursor::*, database::*, impls::*, traits::*, transaction::*};
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub use self::serde_codec::*;
+pub use self::{cursor::*, database::*, impls::*, traits::*, transaction::*, typed_table::*};
 pub use crate::{
     dupsort, table, table_info, DatabaseKind, DatabaseOptions, Mode, NoWriteMap, ReadWriteOptions,
     SyncMode, TransactionKind, WriteMap, RO, RW,
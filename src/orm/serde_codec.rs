@@ -0,0 +1,68 @@
+use super::traits::*;
+use derive_more::*;
+use serde::{de::DeserializeOwned, Serialize};
+
+/// Stores any serde-compatible value as JSON.
+///
+/// Failing to deserialize a stored value is reported as a decode error carrying the
+/// [serde_json::Error].
+#[derive(
+    Clone, Copy, Debug, Deref, DerefMut, Default, PartialEq, Eq, From, PartialOrd, Ord, Hash,
+)]
+pub struct SerdeJson<T>(pub T);
+
+impl<T> Encodable for SerdeJson<T>
+where
+    T: Serialize + Send + Sync,
+{
+    type Encoded = Vec<u8>;
+
+    /// Panics if the value cannot be represented as JSON, e.g. maps with non-string keys.
+    fn encode(self) -> Self::Encoded {
+        serde_json::to_vec(&self.0).unwrap()
+    }
+}
+
+impl<T> Decodable for SerdeJson<T>
+where
+    T: DeserializeOwned + Send + Sync,
+{
+    fn decode(b: &[u8]) -> anyhow::Result<Self> {
+        Ok(Self(serde_json::from_slice(b)?))
+    }
+}
+
+/// Stores any serde-compatible value as CBOR, a compact binary encoding.
+///
+/// Failing to deserialize a stored value is reported as a decode error carrying the
+/// [ciborium::de::Error].
+#[cfg(feature = "cbor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cbor")))]
+#[derive(
+    Clone, Copy, Debug, Deref, DerefMut, Default, PartialEq, Eq, From, PartialOrd, Ord, Hash,
+)]
+pub struct SerdeCbor<T>(pub T);
+
+#[cfg(feature = "cbor")]
+impl<T> Encodable for SerdeCbor<T>
+where
+    T: Serialize + Send + Sync,
+{
+    type Encoded = Vec<u8>;
+
+    fn encode(self) -> Self::Encoded {
+        let mut v = vec![];
+        ciborium::ser::into_writer(&self.0, &mut v).unwrap();
+        v
+    }
+}
+
+#[cfg(feature = "cbor")]
+impl<T> Decodable for SerdeCbor<T>
+where
+    T: DeserializeOwned + Send + Sync,
+{
+    fn decode(b: &[u8]) -> anyhow::Result<Self> {
+        Ok(Self(ciborium::de::from_reader(b)?))
+    }
+}
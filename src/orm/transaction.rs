@@ -73,6 +73,48 @@ where
             )?
             .map(|v| v.0))
     }
+
+    pub fn contains_key<T>(&self, key: T::Key) -> anyhow::Result<bool>
+    where
+        T: Table,
+    {
+        Ok(self.inner.contains_key(
+            &self.inner.open_table(Some(T::NAME))?,
+            key.encode().as_ref(),
+        )?)
+    }
+
+    pub fn get_both<T>(&self, key: T::Key, value: T::Value) -> anyhow::Result<Option<T::Value>>
+    where
+        T: DupSort,
+    {
+        Ok(self
+            .inner
+            .get_both::<DecodableWrapper<_>>(
+                &self.inner.open_table(Some(T::NAME))?,
+                key.encode().as_ref(),
+                value.encode().as_ref(),
+            )?
+            .map(|v| v.0))
+    }
+
+    pub fn get_both_range<T>(
+        &self,
+        key: T::Key,
+        seek_value: T::SeekValue,
+    ) -> anyhow::Result<Option<T::Value>>
+    where
+        T: DupSort,
+    {
+        Ok(self
+            .inner
+            .get_both_range::<DecodableWrapper<_>>(
+                &self.inner.open_table(Some(T::NAME))?,
+                key.encode().as_ref(),
+                seek_value.encode().as_ref(),
+            )?
+            .map(|v| v.0))
+    }
 }
 
 impl<'db> Transaction<'db, RW> {
@@ -82,8 +124,8 @@ impl<'db> Transaction<'db, RW> {
     {
         Ok(self.inner.put(
             &self.inner.open_table(Some(T::NAME))?,
-            &key.encode(),
-            &value.encode(),
+            key.encode(),
+            value.encode(),
             WriteFlags::UPSERT,
         )?)
     }
@@ -0,0 +1,325 @@
+use super::{cursor::DecodableWrapper, traits::*};
+use crate::{
+    Cursor, DatabaseKind, Result, Table, TableFlags, Transaction, TransactionKind, WriteFlags, RW,
+};
+use std::{fmt, marker::PhantomData, ops::Deref};
+
+/// A handle to a table whose keys and values are encoded with [Encodable] and [Decodable].
+///
+/// Unlike tables declared with the [table!](crate::table) macro, a typed table is opened by name at
+/// runtime and works with any core [Transaction]. Values failing to decode are reported as
+/// [Error::DecodeError](crate::Error::DecodeError) carrying the underlying cause.
+///
+/// Dereferences to the underlying [Table], so the untyped byte API stays available as well.
+pub struct TypedTable<'txn, K, V> {
+    table: Table<'txn>,
+    _marker: PhantomData<fn() -> (K, V)>,
+}
+
+impl<'txn, K, V> TypedTable<'txn, K, V>
+where
+    K: TableObject,
+    V: TableObject,
+{
+    /// Wraps an already opened table.
+    pub fn new(table: Table<'txn>) -> Self {
+        Self {
+            table,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Opens an existing table. See [Transaction::open_table].
+    pub fn open<'db, TK: TransactionKind, E: DatabaseKind>(
+        txn: &'txn Transaction<'db, TK, E>,
+        name: Option<&str>,
+    ) -> Result<Self> {
+        txn.open_table(name).map(Self::new)
+    }
+
+    /// Opens or creates a table. See [Transaction::create_table].
+    pub fn create<'db, E: DatabaseKind>(
+        txn: &'txn Transaction<'db, RW, E>,
+        name: Option<&str>,
+        flags: TableFlags,
+    ) -> Result<Self> {
+        txn.create_table(name, flags).map(Self::new)
+    }
+
+    /// The untyped table handle.
+    pub fn raw(&self) -> &Table<'txn> {
+        &self.table
+    }
+
+    /// Gets the value stored under the given key.
+    ///
+    /// For [TableFlags::DUP_SORT] tables the first duplicate is returned.
+    pub fn get<'db, TK: TransactionKind, E: DatabaseKind>(
+        &self,
+        txn: &'txn Transaction<'db, TK, E>,
+        key: K,
+    ) -> Result<Option<V>> {
+        Ok(txn
+            .get::<DecodableWrapper<V>>(&self.table, key.encode().as_ref())?
+            .map(|v| v.0))
+    }
+
+    /// [TableFlags::DUP_SORT]-only: Returns the value if the table contains the given key/value
+    /// pair. See [Transaction::get_both].
+    pub fn get_both<'db, TK: TransactionKind, E: DatabaseKind>(
+        &self,
+        txn: &'txn Transaction<'db, TK, E>,
+        key: K,
+        value: V,
+    ) -> Result<Option<V>> {
+        Ok(txn
+            .get_both::<DecodableWrapper<V>>(
+                &self.table,
+                key.encode().as_ref(),
+                value.encode().as_ref(),
+            )?
+            .map(|v| v.0))
+    }
+
+    /// [TableFlags::DUP_SORT]-only: Returns the first duplicate of the given key which is greater
+    /// than or equal to `value`. See [Transaction::get_both_range].
+    pub fn get_both_range<'db, TK: TransactionKind, E: DatabaseKind>(
+        &self,
+        txn: &'txn Transaction<'db, TK, E>,
+        key: K,
+        value: V,
+    ) -> Result<Option<V>> {
+        Ok(txn
+            .get_both_range::<DecodableWrapper<V>>(
+                &self.table,
+                key.encode().as_ref(),
+                value.encode().as_ref(),
+            )?
+            .map(|v| v.0))
+    }
+
+    /// Returns `true` if the table contains the given key.
+    pub fn contains_key<'db, TK: TransactionKind, E: DatabaseKind>(
+        &self,
+        txn: &'txn Transaction<'db, TK, E>,
+        key: K,
+    ) -> Result<bool> {
+        txn.contains_key(&self.table, key.encode().as_ref())
+    }
+
+    /// Stores a value under the given key.
+    pub fn put<'db, E: DatabaseKind>(
+        &self,
+        txn: &'txn Transaction<'db, RW, E>,
+        key: K,
+        value: V,
+        flags: WriteFlags,
+    ) -> Result<()> {
+        txn.put(&self.table, key.encode(), value.encode(), flags)
+    }
+
+    /// Deletes the given key, or only the given key/value pair if `value` is specified.
+    ///
+    /// Returns `true` if anything was deleted.
+    pub fn del<'db, E: DatabaseKind>(
+        &self,
+        txn: &'txn Transaction<'db, RW, E>,
+        key: K,
+        value: Option<V>,
+    ) -> Result<bool> {
+        let value = value.map(Encodable::encode);
+        txn.del(
+            &self.table,
+            key.encode(),
+            value.as_ref().map(|value| value.as_ref()),
+        )
+    }
+
+    /// Iterates over all key/value pairs of the table, starting from the first one.
+    pub fn iter<'db, TK: TransactionKind, E: DatabaseKind>(
+        &self,
+        txn: &'txn Transaction<'db, TK, E>,
+    ) -> Result<impl Iterator<Item = Result<(K, V)>> + 'txn>
+    where
+        K: 'txn,
+        V: 'txn,
+    {
+        Ok(txn
+            .cursor(&self.table)?
+            .into_iter_start::<DecodableWrapper<K>, DecodableWrapper<V>>()
+            .map(|item| item.map(|(k, v)| (k.0, v.0))))
+    }
+
+    /// Opens a cursor yielding decoded keys and values.
+    pub fn cursor<'db, TK: TransactionKind, E: DatabaseKind>(
+        &self,
+        txn: &'txn Transaction<'db, TK, E>,
+    ) -> Result<TypedCursor<'txn, TK, K, V>> {
+        Ok(TypedCursor {
+            cursor: txn.cursor(&self.table)?,
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// A cursor over a [TypedTable], encoding and decoding keys and values on the fly.
+pub struct TypedCursor<'txn, TK, K, V>
+where
+    TK: TransactionKind,
+{
+    cursor: Cursor<'txn, TK>,
+    _marker: PhantomData<fn() -> (K, V)>,
+}
+
+fn decoded<K, V>(
+    item: Result<Option<(DecodableWrapper<K>, DecodableWrapper<V>)>>,
+) -> Result<Option<(K, V)>> {
+    item.map(|item| item.map(|(k, v)| (k.0, v.0)))
+}
+
+impl<'txn, TK, K, V> TypedCursor<'txn, TK, K, V>
+where
+    TK: TransactionKind,
+    K: TableObject,
+    V: TableObject,
+{
+    /// The untyped cursor.
+    pub fn raw(&mut self) -> &mut Cursor<'txn, TK> {
+        &mut self.cursor
+    }
+
+    /// Position at the first item.
+    pub fn first(&mut self) -> Result<Option<(K, V)>> {
+        decoded(self.cursor.first())
+    }
+
+    /// Position at the last item.
+    pub fn last(&mut self) -> Result<Option<(K, V)>> {
+        decoded(self.cursor.last())
+    }
+
+    /// Position at the next item.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Result<Option<(K, V)>> {
+        decoded(self.cursor.next())
+    }
+
+    /// Position at the previous item.
+    pub fn prev(&mut self) -> Result<Option<(K, V)>> {
+        decoded(self.cursor.prev())
+    }
+
+    /// Position at the item the cursor is currently at.
+    pub fn current(&mut self) -> Result<Option<(K, V)>> {
+        decoded(self.cursor.get_current())
+    }
+
+    /// Position at the given key.
+    pub fn seek_exact(&mut self, key: K) -> Result<Option<(K, V)>> {
+        decoded(self.cursor.set_key(key.encode().as_ref()))
+    }
+
+    /// Position at the first key greater than or equal to the given one.
+    pub fn seek(&mut self, key: K) -> Result<Option<(K, V)>> {
+        decoded(self.cursor.set_range(key.encode().as_ref()))
+    }
+
+    /// [TableFlags::DUP_SORT]-only: Position at the next duplicate of the current key.
+    pub fn next_dup(&mut self) -> Result<Option<(K, V)>> {
+        decoded(self.cursor.next_dup())
+    }
+
+    /// [TableFlags::DUP_SORT]-only: Position at the first duplicate of the next key.
+    pub fn next_nodup(&mut self) -> Result<Option<(K, V)>> {
+        decoded(self.cursor.next_nodup())
+    }
+
+    /// [TableFlags::DUP_SORT]-only: Position at the given key and at the first duplicate whose
+    /// encoded form is greater than or equal to the given value.
+    pub fn seek_dup(&mut self, key: K, value: V) -> Result<Option<V>> {
+        Ok(self
+            .cursor
+            .get_both_range::<DecodableWrapper<V>>(key.encode().as_ref(), value.encode().as_ref())?
+            .map(|v| v.0))
+    }
+
+    /// Iterate over the items, starting from the first one.
+    pub fn iter_start(&mut self) -> impl Iterator<Item = Result<(K, V)>> + use<'_, 'txn, TK, K, V>
+    where
+        K: 'txn,
+        V: 'txn,
+    {
+        self.cursor
+            .iter_start::<DecodableWrapper<K>, DecodableWrapper<V>>()
+            .map(|item| item.map(|(k, v)| (k.0, v.0)))
+    }
+
+    /// Iterate over the items, starting from the given key.
+    pub fn iter_from(
+        &mut self,
+        key: K,
+    ) -> impl Iterator<Item = Result<(K, V)>> + use<'_, 'txn, TK, K, V>
+    where
+        K: 'txn,
+        V: 'txn,
+    {
+        self.cursor
+            .iter_from::<DecodableWrapper<K>, DecodableWrapper<V>>(key.encode().as_ref())
+            .map(|item| item.map(|(k, v)| (k.0, v.0)))
+    }
+
+    /// [TableFlags::DUP_SORT]-only: Iterate over the duplicates of the given key.
+    pub fn iter_dup_of(
+        &mut self,
+        key: K,
+    ) -> impl Iterator<Item = Result<V>> + use<'_, 'txn, TK, K, V>
+    where
+        V: 'txn,
+    {
+        self.cursor
+            .iter_dup_of::<(), DecodableWrapper<V>>(key.encode().as_ref())
+            .map(|item| item.map(|(_, v)| v.0))
+    }
+}
+
+impl<'txn, K, V> TypedCursor<'txn, RW, K, V>
+where
+    K: TableObject,
+    V: TableObject,
+{
+    /// Stores a value under the given key, positioning the cursor at it.
+    pub fn put(&mut self, key: K, value: V, flags: WriteFlags) -> Result<()> {
+        self.cursor
+            .put(key.encode().as_ref(), value.encode().as_ref(), flags)
+    }
+
+    /// Deletes the current item, see [Cursor::del].
+    pub fn del(&mut self, flags: WriteFlags) -> Result<()> {
+        self.cursor.del(flags)
+    }
+}
+
+impl<TK, K, V> fmt::Debug for TypedCursor<'_, TK, K, V>
+where
+    TK: TransactionKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedCursor").finish()
+    }
+}
+
+impl<'txn, K, V> Deref for TypedTable<'txn, K, V> {
+    type Target = Table<'txn>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.table
+    }
+}
+
+impl<K, V> fmt::Debug for TypedTable<'_, K, V> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TypedTable")
+            .field("table", &self.table)
+            .finish()
+    }
+}
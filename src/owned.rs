@@ -0,0 +1,73 @@
+use crate::{
+    database::{Database, DatabaseKind},
+    error::Result,
+    transaction::{Transaction, RO},
+};
+use std::{fmt, ops::Deref, sync::Arc};
+
+/// A read-only transaction which keeps its [Database] alive, see [Database::begin_ro_txn_owned].
+///
+/// Unlike a [Transaction], it does not borrow the database, so it can be stored in the same
+/// struct as the database or kept after other handles to it are dropped. It dereferences to a
+/// [Transaction], whose tables and cursors borrow from the owned transaction instead.
+///
+/// Being [Send] and `'static`, it can also be moved into a spawned thread or task, or held across
+/// `.await` points.
+pub struct OwnedRoTransaction<E>
+where
+    E: DatabaseKind,
+{
+    // Declared before `db`, so the transaction ends before the database can be closed.
+    txn: Transaction<'static, RO, E>,
+    db: Arc<Database<E>>,
+}
+
+impl<E> Database<E>
+where
+    E: DatabaseKind,
+{
+    /// Create a read-only transaction which holds a reference to the database, instead of
+    /// borrowing it.
+    pub fn begin_ro_txn_owned(self: &Arc<Self>) -> Result<OwnedRoTransaction<E>> {
+        // The database lives on the heap until the last Arc is dropped, and the transaction keeps
+        // one. Only methods consuming the transaction hand out its database lifetime, and those
+        // cannot be called through the Deref impl.
+        let db = unsafe { &*Arc::as_ptr(self) };
+        Ok(OwnedRoTransaction {
+            txn: Transaction::new(db)?,
+            db: self.clone(),
+        })
+    }
+}
+
+impl<E> OwnedRoTransaction<E>
+where
+    E: DatabaseKind,
+{
+    /// The database the transaction reads from.
+    pub fn database(&self) -> &Arc<Database<E>> {
+        &self.db
+    }
+}
+
+impl<E> Deref for OwnedRoTransaction<E>
+where
+    E: DatabaseKind,
+{
+    type Target = Transaction<'static, RO, E>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.txn
+    }
+}
+
+impl<E> fmt::Debug for OwnedRoTransaction<E>
+where
+    E: DatabaseKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OwnedRoTransaction")
+            .field("id", &self.txn.id())
+            .finish()
+    }
+}
@@ -0,0 +1,223 @@
+use crate::{
+    database::DatabaseKind,
+    error::{mdbx_result, Error, Result},
+    transaction::{txn_execute, Transaction, TransactionKind},
+};
+use libc::{c_int, c_uint, c_void};
+use std::{
+    any::Any,
+    borrow::Cow,
+    panic::{self, AssertUnwindSafe},
+    slice,
+};
+
+/// Kind of a page visited by [Transaction::walk_pages].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PageKind {
+    /// A page which could not be read or is malformed, see [PageInfo::error].
+    Broken,
+    /// The meta pages, reported together.
+    Meta,
+    /// One or more consecutive pages holding a single large value.
+    Large,
+    /// An inner page of a B-tree.
+    Branch,
+    /// A leaf page of a B-tree.
+    Leaf,
+    /// A leaf page of a [TableFlags::DUP_FIXED](crate::TableFlags::DUP_FIXED) table, holding
+    /// duplicates without node headers.
+    DupFixedLeaf,
+    /// Duplicates of a key stored within the leaf page of the key.
+    SubLeaf,
+    /// Duplicates of a key stored within the leaf page of the key, without node headers.
+    SubDupFixedLeaf,
+    /// Malformed duplicates stored within the leaf page of a key.
+    SubBroken,
+}
+
+impl PageKind {
+    fn from_raw(kind: ffi::MDBX_page_type_t) -> Self {
+        match kind {
+            ffi::MDBX_page_meta => Self::Meta,
+            ffi::MDBX_page_large => Self::Large,
+            ffi::MDBX_page_branch => Self::Branch,
+            ffi::MDBX_page_leaf => Self::Leaf,
+            ffi::MDBX_page_dupfixed_leaf => Self::DupFixedLeaf,
+            ffi::MDBX_subpage_leaf => Self::SubLeaf,
+            ffi::MDBX_subpage_dupfixed_leaf => Self::SubDupFixedLeaf,
+            ffi::MDBX_subpage_broken => Self::SubBroken,
+            _ => Self::Broken,
+        }
+    }
+}
+
+/// The table a page visited by [Transaction::walk_pages] belongs to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PageOwner<'a> {
+    /// The meta pages at the start of the database.
+    Meta,
+    /// The GC table, which records the free pages.
+    Gc,
+    /// The main table, which also records the named tables.
+    Main,
+    /// A named table.
+    Table(Cow<'a, str>),
+}
+
+impl PageOwner<'_> {
+    /// Copy the table name, if needed, to keep the owner past the visit.
+    pub fn into_owned(self) -> PageOwner<'static> {
+        match self {
+            Self::Meta => PageOwner::Meta,
+            Self::Gc => PageOwner::Gc,
+            Self::Main => PageOwner::Main,
+            Self::Table(name) => PageOwner::Table(Cow::Owned(name.into_owned())),
+        }
+    }
+}
+
+/// A page, or run of pages, visited by [Transaction::walk_pages].
+#[derive(Debug)]
+pub struct PageInfo<'a> {
+    /// Number of the first page. For duplicates stored within a leaf page, the number of that
+    /// page.
+    pub pgno: u64,
+    /// Number of consecutive pages, or 0 for duplicates stored within a leaf page.
+    pub count: u32,
+    pub owner: PageOwner<'a>,
+    pub kind: PageKind,
+    /// Depth in the B-tree, starting from 0 at the root of the main and GC tables. Trees of the
+    /// named tables and of the duplicates of a key lie below the leaf pages holding them.
+    pub depth: u32,
+    /// Size in bytes, including the header and unused space.
+    pub size: usize,
+    /// Number of items, i.e. keys, duplicates or child pages.
+    pub entries: usize,
+    /// Bytes used by the items.
+    pub payload_bytes: usize,
+    /// Bytes used by the page header and item offsets.
+    pub header_bytes: usize,
+    /// Bytes left unused.
+    pub unused_bytes: usize,
+    /// Why the page is broken, if it is.
+    pub error: Option<Error>,
+}
+
+impl PageInfo<'_> {
+    /// Share of the page used by the items, between 0 and 1.
+    pub fn fill(&self) -> f64 {
+        if self.size == 0 {
+            return 0.0;
+        }
+        self.payload_bytes as f64 / self.size as f64
+    }
+}
+
+struct Walk<'f> {
+    visitor: &'f mut dyn FnMut(&PageInfo<'_>) -> Result<()>,
+    error: Option<Error>,
+    panic: Option<Box<dyn Any + Send>>,
+}
+
+impl<K, E> Transaction<'_, K, E>
+where
+    K: TransactionKind,
+    E: DatabaseKind,
+{
+    /// Visit every page in use in the snapshot of the transaction, e.g. to analyze space usage.
+    ///
+    /// The meta pages come first, followed by the GC table and the main table. Large values and
+    /// duplicates stored within a leaf page are reported before that page, while the trees of
+    /// named tables and of many duplicates follow it. Key order is not checked, see
+    /// [Database::check](crate::Database::check) for that.
+    pub fn walk_pages<F>(&self, mut visitor: F) -> Result<()>
+    where
+        F: FnMut(&PageInfo<'_>),
+    {
+        self.try_walk_pages(|page| {
+            visitor(page);
+            Ok(())
+        })
+    }
+
+    /// Like [Transaction::walk_pages], but stops at the first error returned by the visitor.
+    pub(crate) fn try_walk_pages<F>(&self, mut visitor: F) -> Result<()>
+    where
+        F: FnMut(&PageInfo<'_>) -> Result<()>,
+    {
+        let mut walk = Walk {
+            visitor: &mut visitor,
+            error: None,
+            panic: None,
+        };
+        let rc = txn_execute(&self.txn_mutex(), |txn| unsafe {
+            ffi::mdbx_env_pgwalk(
+                txn,
+                Some(visit_page),
+                &mut walk as *mut Walk as *mut c_void,
+                true,
+            )
+        });
+        if let Some(payload) = walk.panic {
+            panic::resume_unwind(payload);
+        }
+        if let Some(error) = walk.error {
+            return Err(error);
+        }
+        mdbx_result(rc)?;
+        Ok(())
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn visit_page(
+    pgno: u64,
+    count: c_uint,
+    ctx: *mut c_void,
+    depth: c_int,
+    name: *const ffi::MDBX_val,
+    size: usize,
+    kind: ffi::MDBX_page_type_t,
+    err: ffi::MDBX_error_t,
+    entries: usize,
+    payload_bytes: usize,
+    header_bytes: usize,
+    unused_bytes: usize,
+) -> c_int {
+    let walk = &mut *(ctx as *mut Walk);
+    let owner = match name as isize {
+        0 => PageOwner::Main,
+        -1 => PageOwner::Gc,
+        -2 => PageOwner::Meta,
+        _ => PageOwner::Table(String::from_utf8_lossy(slice::from_raw_parts(
+            (*name).iov_base as *const u8,
+            (*name).iov_len,
+        ))),
+    };
+    let page = PageInfo {
+        pgno,
+        count,
+        owner,
+        kind: PageKind::from_raw(kind),
+        depth: depth.max(0) as u32,
+        size,
+        entries,
+        payload_bytes,
+        header_bytes,
+        unused_bytes,
+        error: (err != ffi::MDBX_SUCCESS).then(|| Error::from_err_code(err)),
+    };
+
+    // Unwinding into MDBX is undefined behaviour, so stop the walk and resume the panic after it.
+    match panic::catch_unwind(AssertUnwindSafe(|| (walk.visitor)(&page))) {
+        Ok(Ok(())) => ffi::MDBX_SUCCESS,
+        Ok(Err(error)) => {
+            walk.error = Some(error);
+            ffi::MDBX_EINTR
+        }
+        Err(payload) => {
+            walk.panic = Some(payload);
+            ffi::MDBX_EINTR
+        }
+    }
+}
@@ -0,0 +1,146 @@
+use crate::{
+    cursor::Cursor,
+    database::DatabaseKind,
+    error::Result,
+    table::Table,
+    transaction::{Transaction, TransactionKind},
+};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    fmt,
+    mem::ManuallyDrop,
+    ops::{Deref, DerefMut},
+};
+
+/// Keeps the cursors of a transaction for reuse, see [Transaction::cursor_pool].
+///
+/// The pool is not [Sync], so handing out and returning cursors needs no locking. Idle cursors
+/// are closed when the pool is dropped, which happens before the transaction ends.
+pub struct CursorPool<'txn, K, E>
+where
+    K: TransactionKind,
+    E: DatabaseKind,
+{
+    txn: &'txn Transaction<'txn, K, E>,
+    idle: RefCell<HashMap<ffi::MDBX_dbi, Vec<Cursor<'txn, K>>>>,
+}
+
+impl<K, E> Transaction<'_, K, E>
+where
+    K: TransactionKind,
+    E: DatabaseKind,
+{
+    /// Creates a pool of cursors, for code paths which open a cursor per lookup.
+    ///
+    /// A cursor obtained from [CursorPool::cursor] goes back to the pool when dropped, instead of
+    /// being closed, and is handed out again for the same table.
+    pub fn cursor_pool<'txn>(&'txn self) -> CursorPool<'txn, K, E> {
+        CursorPool {
+            txn: self,
+            idle: RefCell::default(),
+        }
+    }
+}
+
+impl<'txn, K, E> CursorPool<'txn, K, E>
+where
+    K: TransactionKind,
+    E: DatabaseKind,
+{
+    /// Takes an idle cursor for the table out of the pool, or opens a new one if there is none.
+    /// The cursor is unpositioned either way.
+    pub fn cursor(&self, table: &Table<'txn>) -> Result<PooledCursor<'_, 'txn, K, E>> {
+        let dbi = table.dbi();
+        let idle = self.idle.borrow_mut().get_mut(&dbi).and_then(Vec::pop);
+        let cursor = match idle {
+            // Binding the cursor again resets its position.
+            Some(cursor) => cursor.unbind().bind(self.txn, table)?,
+            None => self.txn.cursor(table)?,
+        };
+
+        Ok(PooledCursor {
+            pool: self,
+            dbi,
+            cursor: ManuallyDrop::new(cursor),
+        })
+    }
+
+    /// The number of cursors waiting in the pool to be reused.
+    pub fn idle(&self) -> usize {
+        self.idle.borrow().values().map(Vec::len).sum()
+    }
+}
+
+impl<K, E> fmt::Debug for CursorPool<'_, K, E>
+where
+    K: TransactionKind,
+    E: DatabaseKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CursorPool")
+            .field("idle", &self.idle())
+            .finish()
+    }
+}
+
+/// A [Cursor] borrowed from a [CursorPool], which it returns to when dropped.
+pub struct PooledCursor<'pool, 'txn, K, E>
+where
+    K: TransactionKind,
+    E: DatabaseKind,
+{
+    pool: &'pool CursorPool<'txn, K, E>,
+    dbi: ffi::MDBX_dbi,
+    cursor: ManuallyDrop<Cursor<'txn, K>>,
+}
+
+impl<'txn, K, E> Deref for PooledCursor<'_, 'txn, K, E>
+where
+    K: TransactionKind,
+    E: DatabaseKind,
+{
+    type Target = Cursor<'txn, K>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.cursor
+    }
+}
+
+impl<K, E> DerefMut for PooledCursor<'_, '_, K, E>
+where
+    K: TransactionKind,
+    E: DatabaseKind,
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.cursor
+    }
+}
+
+impl<K, E> fmt::Debug for PooledCursor<'_, '_, K, E>
+where
+    K: TransactionKind,
+    E: DatabaseKind,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PooledCursor")
+            .field("dbi", &self.dbi)
+            .finish()
+    }
+}
+
+impl<K, E> Drop for PooledCursor<'_, '_, K, E>
+where
+    K: TransactionKind,
+    E: DatabaseKind,
+{
+    fn drop(&mut self) {
+        let cursor = unsafe { ManuallyDrop::take(&mut self.cursor) };
+        self.pool
+            .idle
+            .borrow_mut()
+            .entry(self.dbi)
+            .or_default()
+            .push(cursor);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::{
+    database::{Database, DatabaseKind},
+    error::{mdbx_result, Result},
+};
+use libc::{c_int, c_uint};
+use parking_lot::RwLock;
+use std::{
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    sync::Arc,
+};
+
+/// A read transaction which keeps a write transaction from reusing freed pages, as passed to the
+/// handler set with [Database::set_slow_reader_handler].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SlowReader {
+    /// Process running the read transaction.
+    pub pid: i32,
+    /// Id of the transaction whose snapshot is read.
+    pub txnid: u64,
+    /// Number of transactions committed since.
+    pub gap: u32,
+    /// Bytes which become reusable once the read transaction ends.
+    pub space: usize,
+    /// Number of times the handler was called before for the same write, starting from 0.
+    pub retry: u32,
+}
+
+/// What a slow reader handler did about the [SlowReader].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum SlowReaderAction {
+    /// Nothing, MDBX grows the database if possible and otherwise fails the write with
+    /// [Error::MapFull](crate::Error::MapFull).
+    GiveUp,
+    /// The read transaction ended, or the handler waited for a while. MDBX checks the readers
+    /// again and calls the handler once more if the reader is still there.
+    Retry,
+    /// The read transaction was aborted without ending it through MDBX, so its reader slot can be
+    /// released.
+    Aborted,
+    /// The process running the read transaction was killed, so its reader slot can be released.
+    Killed,
+}
+
+type Handler = dyn Fn(&SlowReader) -> SlowReaderAction + Send + Sync;
+
+/// Handlers by database, since MDBX only passes the database to the callback.
+static HANDLERS: RwLock<BTreeMap<usize, Arc<Handler>>> = RwLock::new(BTreeMap::new());
+
+impl<E> Database<E>
+where
+    E: DatabaseKind,
+{
+    /// Set a handler for read transactions which hold back the reuse of freed pages once the
+    /// database is full, replacing any previous one.
+    ///
+    /// The handler is called with the oldest such reader, by the thread writing to the database
+    /// while it has to wait. It can wait for the reader to finish, end it, or give up on it and
+    /// let the write grow the database or fail. It must not use the database itself, and its
+    /// panics are treated as [SlowReaderAction::GiveUp].
+    ///
+    /// # Safety
+    /// The handler must only return [SlowReaderAction::Aborted] or [SlowReaderAction::Killed]
+    /// for readers which are really gone. MDBX then reuses the pages of the reader, which values
+    /// borrowed from a still live read transaction, e.g. in this process, keep pointing into.
+    pub unsafe fn set_slow_reader_handler<F>(&self, handler: F) -> Result<()>
+    where
+        F: Fn(&SlowReader) -> SlowReaderAction + Send + Sync + 'static,
+    {
+        HANDLERS
+            .write()
+            .insert(self.ptr().0 as usize, Arc::new(handler));
+        mdbx_result(ffi::mdbx_env_set_hsr(
+            self.ptr().0,
+            Some(handle_slow_reader),
+        ))?;
+        Ok(())
+    }
+
+    /// Remove the handler set with [Database::set_slow_reader_handler].
+    pub fn clear_slow_reader_handler(&self) -> Result<()> {
+        mdbx_result(unsafe { ffi::mdbx_env_set_hsr(self.ptr().0, None) })?;
+        remove_handler(self.ptr().0);
+        Ok(())
+    }
+}
+
+pub(crate) fn remove_handler(env: *mut ffi::MDBX_env) {
+    HANDLERS.write().remove(&(env as usize));
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn handle_slow_reader(
+    env: *const ffi::MDBX_env,
+    _txn: *const ffi::MDBX_txn,
+    pid: ffi::mdbx_pid_t,
+    // Not recorded for databases opened with `MDBX_NOTLS`.
+    _tid: ffi::mdbx_tid_t,
+    laggard: u64,
+    gap: c_uint,
+    space: usize,
+    retry: c_int,
+) -> c_int {
+    // A negative retry only reports the end of the calls for a write.
+    let Ok(retry) = u32::try_from(retry) else {
+        return 0;
+    };
+    let Some(handler) = HANDLERS.read().get(&(env as usize)).cloned() else {
+        return -1;
+    };
+
+    let reader = SlowReader {
+        pid,
+        txnid: laggard,
+        gap,
+        space,
+        retry,
+    };
+    // Unwinding into MDBX is undefined behaviour.
+    match panic::catch_unwind(AssertUnwindSafe(|| handler(&reader))) {
+        Ok(SlowReaderAction::Retry) => 0,
+        Ok(SlowReaderAction::Aborted) => 1,
+        Ok(SlowReaderAction::Killed) => 2,
+        Ok(SlowReaderAction::GiveUp) | Err(_) => -1,
+    }
+}
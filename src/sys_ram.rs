@@ -0,0 +1,38 @@
+use crate::error::{mdbx_result, Result};
+
+/// Memory of the system, e.g. to size the [Geometry](crate::Geometry) of a database or to choose
+/// between [WriteMap](crate::WriteMap) and [NoWriteMap](crate::NoWriteMap) at runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SysRamInfo {
+    /// Size of a page of the operating system.
+    pub page_size: usize,
+    /// Number of pages of physical memory.
+    pub total_pages: usize,
+    /// Number of pages of physical memory currently available.
+    pub available_pages: usize,
+}
+
+impl SysRamInfo {
+    /// Query the memory of the system.
+    pub fn get() -> Result<Self> {
+        let (mut page_size, mut total_pages, mut available_pages) = (0, 0, 0);
+        mdbx_result(unsafe {
+            ffi::mdbx_get_sysraminfo(&mut page_size, &mut total_pages, &mut available_pages)
+        })?;
+        Ok(Self {
+            page_size: page_size as usize,
+            total_pages: total_pages as usize,
+            available_pages: available_pages as usize,
+        })
+    }
+
+    /// Size of the physical memory in bytes.
+    pub fn total_bytes(&self) -> usize {
+        self.page_size * self.total_pages
+    }
+
+    /// Size of the currently available physical memory in bytes.
+    pub fn available_bytes(&self) -> usize {
+        self.page_size * self.available_pages
+    }
+}
@@ -1,11 +1,14 @@
 use crate::{
+    comparator::ComparatorFn,
+    cursor::{ExactIter, IntoIter},
     database::DatabaseKind,
     error::{mdbx_result, Result},
-    transaction::{txn_execute, TransactionKind},
-    Transaction,
+    flags::WriteFlags,
+    transaction::{txn_execute, TransactionKind, RW},
+    Decodable, Transaction,
 };
 use libc::c_uint;
-use std::{ffi::CString, marker::PhantomData, ptr};
+use std::{borrow::Cow, ffi::CString, marker::PhantomData, ops::Deref, ptr};
 
 /// A handle to an individual table in a database.
 ///
@@ -21,6 +24,16 @@ impl<'txn> Table<'txn> {
         txn: &'txn Transaction<'db, K, E>,
         name: Option<&str>,
         flags: c_uint,
+    ) -> Result<Self> {
+        Self::new_with_comparators(txn, name, flags, None, None)
+    }
+
+    pub(crate) fn new_with_comparators<'db, K: TransactionKind, E: DatabaseKind>(
+        txn: &'txn Transaction<'db, K, E>,
+        name: Option<&str>,
+        flags: c_uint,
+        key_cmp: Option<ComparatorFn>,
+        dup_cmp: Option<ComparatorFn>,
     ) -> Result<Self> {
         let c_name = name.map(|n| CString::new(n).unwrap());
         let name_ptr = if let Some(c_name) = &c_name {
@@ -30,7 +43,7 @@ impl<'txn> Table<'txn> {
         };
         let mut dbi: ffi::MDBX_dbi = 0;
         mdbx_result(txn_execute(&txn.txn_mutex(), |txn| unsafe {
-            ffi::mdbx_dbi_open(txn, name_ptr, flags, &mut dbi)
+            ffi::mdbx_dbi_open_ex(txn, name_ptr, flags, &mut dbi, key_cmp, dup_cmp)
         }))?;
         Ok(Self::new_from_ptr(dbi))
     }
@@ -57,3 +70,135 @@ impl<'txn> Table<'txn> {
         self.dbi
     }
 }
+
+/// A handle to a table whose values are all exactly `N` bytes long.
+///
+/// Values are read back as `[u8; N]`, so no intermediate buffers are involved. Reading a value of
+/// any other length fails with [Error::WrongValueSize](crate::Error::WrongValueSize).
+///
+/// Dereferences to the underlying [Table], so it can be used with every API taking one.
+#[derive(Debug)]
+pub struct FixedValueTable<'txn, const N: usize> {
+    table: Table<'txn>,
+}
+
+impl<'txn, const N: usize> FixedValueTable<'txn, N> {
+    pub(crate) fn new(table: Table<'txn>) -> Self {
+        Self { table }
+    }
+
+    /// Gets the value stored under the given key.
+    ///
+    /// For [TableFlags::DUP_SORT](crate::TableFlags::DUP_SORT) tables the first duplicate is
+    /// returned.
+    pub fn get<'db, K: TransactionKind, E: DatabaseKind>(
+        &self,
+        txn: &'txn Transaction<'db, K, E>,
+        key: &[u8],
+    ) -> Result<Option<[u8; N]>> {
+        txn.get(&self.table, key)
+    }
+
+    /// Stores a value under the given key.
+    pub fn put<'db, E: DatabaseKind>(
+        &self,
+        txn: &'txn Transaction<'db, RW, E>,
+        key: impl AsRef<[u8]>,
+        value: &[u8; N],
+        flags: WriteFlags,
+    ) -> Result<()> {
+        txn.put(&self.table, key, value, flags)
+    }
+
+    /// Iterates over all key/value pairs of the table, starting from the first one.
+    pub fn iter<'db, K: TransactionKind, E: DatabaseKind>(
+        &self,
+        txn: &'txn Transaction<'db, K, E>,
+    ) -> Result<ExactIter<IntoIter<'txn, K, Cow<'txn, [u8]>, [u8; N]>>> {
+        Ok(txn.cursor(&self.table)?.into_iter_start())
+    }
+}
+
+impl<'txn, const N: usize> Deref for FixedValueTable<'txn, N> {
+    type Target = Table<'txn>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.table
+    }
+}
+
+/// A handle to a table created with [TableFlags::INTEGER_KEY](crate::TableFlags::INTEGER_KEY),
+/// keyed by `u64`.
+///
+/// MDBX requires integer keys to be native-endian and of the exact width, and sorts them
+/// numerically. This handle takes care of the encoding, so keys of the wrong width cannot be
+/// passed by accident.
+///
+/// Dereferences to the underlying [Table], so it can be used with every API taking one.
+#[derive(Debug)]
+pub struct IntegerKeyTable<'txn> {
+    table: Table<'txn>,
+}
+
+impl<'txn> IntegerKeyTable<'txn> {
+    pub(crate) fn new(table: Table<'txn>) -> Self {
+        Self { table }
+    }
+
+    /// Gets the value stored under the given key.
+    ///
+    /// For [TableFlags::DUP_SORT](crate::TableFlags::DUP_SORT) tables the first duplicate is
+    /// returned.
+    pub fn get<'db, Value: Decodable<'txn>, K: TransactionKind, E: DatabaseKind>(
+        &self,
+        txn: &'txn Transaction<'db, K, E>,
+        key: u64,
+    ) -> Result<Option<Value>> {
+        txn.get(&self.table, &key.to_ne_bytes())
+    }
+
+    /// Stores a value under the given key.
+    pub fn put<'db, E: DatabaseKind>(
+        &self,
+        txn: &'txn Transaction<'db, RW, E>,
+        key: u64,
+        value: impl AsRef<[u8]>,
+        flags: WriteFlags,
+    ) -> Result<()> {
+        txn.put(&self.table, key.to_ne_bytes(), value, flags)
+    }
+
+    /// Deletes the given key, or only the given key/value pair if `value` is specified.
+    ///
+    /// Returns `true` if anything was deleted.
+    pub fn del<'db, E: DatabaseKind>(
+        &self,
+        txn: &'txn Transaction<'db, RW, E>,
+        key: u64,
+        value: Option<&[u8]>,
+    ) -> Result<bool> {
+        txn.del(&self.table, key.to_ne_bytes(), value)
+    }
+
+    /// Iterates over all key/value pairs of the table in numeric key order.
+    pub fn iter<'db, Value, K: TransactionKind, E: DatabaseKind>(
+        &self,
+        txn: &'txn Transaction<'db, K, E>,
+    ) -> Result<impl Iterator<Item = Result<(u64, Value)>> + 'txn>
+    where
+        Value: Decodable<'txn> + 'txn,
+    {
+        Ok(txn
+            .cursor(&self.table)?
+            .into_iter_start::<[u8; 8], Value>()
+            .map(|item| item.map(|(key, value)| (u64::from_ne_bytes(key), value))))
+    }
+}
+
+impl<'txn> Deref for IntegerKeyTable<'txn> {
+    type Target = Table<'txn>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.table
+    }
+}
@@ -1,8 +1,10 @@
 use crate::{
+    comparator::ComparatorFn,
+    cursor::CursorPtr,
     database::{Database, DatabaseKind, NoWriteMap, TxnManagerMessage, TxnPtr},
     error::{mdbx_result, Result},
-    flags::{TableFlags, WriteFlags},
-    table::Table,
+    flags::{TableFlags, TransactionFlags, WriteFlags},
+    table::{FixedValueTable, IntegerKeyTable, Table},
     Cursor, Decodable, Error, Stat,
 };
 use ffi::{MDBX_txn_flags_t, MDBX_TXN_RDONLY, MDBX_TXN_READWRITE};
@@ -11,12 +13,17 @@ use libc::{c_uint, c_void};
 use parking_lot::Mutex;
 use sealed::sealed;
 use std::{
+    borrow::Cow,
+    cmp::Ordering,
+    collections::HashSet,
     fmt,
     fmt::Debug,
     marker::PhantomData,
     mem::size_of,
+    ops::{Bound, RangeBounds},
     ptr, result, slice,
     sync::{mpsc::sync_channel, Arc},
+    time::Duration,
 };
 
 #[sealed]
@@ -47,13 +54,21 @@ impl TransactionKind for RW {
 /// An MDBX transaction.
 ///
 /// All table operations require a transaction.
+///
+/// Transactions are [Send] and [Sync], and can be moved to or shared with other threads along
+/// with their tables and cursors. Databases are always opened with `MDBX_NOTLS`, so reader slots
+/// are tied to transactions rather than threads, and calls into a transaction are serialized by
+/// a lock.
 pub struct Transaction<'db, K, E>
 where
     K: TransactionKind,
     E: DatabaseKind,
 {
-    txn: Arc<Mutex<TxnPtr>>,
+    txn: Arc<Mutex<TxnState>>,
     primed_dbis: Mutex<IndexSet<ffi::MDBX_dbi>>,
+    /// Named tables created by this transaction, whose handles only outlive it if it commits.
+    created_dbis: Mutex<Vec<(String, ffi::MDBX_dbi)>>,
+    nested: bool,
     committed: bool,
     db: &'db Database<E>,
     _marker: PhantomData<fn(K)>,
@@ -80,8 +95,10 @@ where
 
     pub(crate) fn new_from_ptr(db: &'db Database<E>, txn: *mut ffi::MDBX_txn) -> Self {
         Self {
-            txn: Arc::new(Mutex::new(TxnPtr(txn))),
+            txn: Arc::new(Mutex::new(TxnState::new(txn))),
             primed_dbis: Mutex::new(IndexSet::new()),
+            created_dbis: Mutex::new(Vec::new()),
+            nested: false,
             committed: false,
             db,
             _marker: PhantomData,
@@ -92,12 +109,12 @@ where
     ///
     /// The caller **must** ensure that the pointer is not used after the
     /// lifetime of the transaction.
-    pub(crate) fn txn_mutex(&self) -> Arc<Mutex<TxnPtr>> {
+    pub(crate) fn txn_mutex(&self) -> Arc<Mutex<TxnState>> {
         self.txn.clone()
     }
 
     pub fn txn(&self) -> TxnPtr {
-        *self.txn.lock()
+        self.txn.lock().ptr
     }
 
     /// Returns a raw pointer to the MDBX database.
@@ -110,6 +127,27 @@ where
         txn_execute(&self.txn, |txn| unsafe { ffi::mdbx_txn_id(txn) })
     }
 
+    /// Returns the transaction's options and state flags.
+    pub fn flags(&self) -> TransactionFlags {
+        TransactionFlags::from_bits_retain(txn_execute(&self.txn, |txn| unsafe {
+            ffi::mdbx_txn_flags(txn)
+        }))
+    }
+
+    /// Retrieves information about the transaction.
+    ///
+    /// With `scan_rlt` set, the reader lock table is scanned to determine the lag of read-only
+    /// transactions and the space retained by them, which is more precise but slower.
+    pub fn info(&self, scan_rlt: bool) -> Result<TxnInfo> {
+        unsafe {
+            let mut info = TxnInfo(std::mem::zeroed());
+            mdbx_result(txn_execute(&self.txn, |txn| {
+                ffi::mdbx_txn_info(txn, &mut info.0, scan_rlt)
+            }))?;
+            Ok(info)
+        }
+    }
+
     /// Gets an item from a table.
     ///
     /// This function retrieves the data associated with the given key in the
@@ -140,6 +178,103 @@ where
         })
     }
 
+    /// Returns `true` if the table contains the given key.
+    ///
+    /// Like [Self::get], a missing key is not an error, while any other failure is propagated.
+    pub fn contains_key<'txn>(&'txn self, table: &Table<'txn>, key: &[u8]) -> Result<bool> {
+        Ok(self.get::<()>(table, key)?.is_some())
+    }
+
+    /// [TableFlags::DUP_SORT]-only: Returns the value if the table contains the given key/value
+    /// pair, or [None] otherwise.
+    pub fn get_both<'txn, Value>(
+        &'txn self,
+        table: &Table<'txn>,
+        key: &[u8],
+        value: &[u8],
+    ) -> Result<Option<Value>>
+    where
+        Value: Decodable<'txn>,
+    {
+        self.cursor(table)?.get_both(key, value)
+    }
+
+    /// [TableFlags::DUP_SORT]-only: Returns the first duplicate of the given key which is greater
+    /// than or equal to `value`, or [None] if there is none.
+    pub fn get_both_range<'txn, Value>(
+        &'txn self,
+        table: &Table<'txn>,
+        key: &[u8],
+        value: &[u8],
+    ) -> Result<Option<Value>>
+    where
+        Value: Decodable<'txn>,
+    {
+        self.cursor(table)?.get_both_range(key, value)
+    }
+
+    /// Returns the item with the smallest key, or [None] if the table is empty.
+    pub fn first<'txn, Key, Value>(&'txn self, table: &Table<'txn>) -> Result<Option<(Key, Value)>>
+    where
+        Key: Decodable<'txn>,
+        Value: Decodable<'txn>,
+    {
+        self.cursor(table)?.first()
+    }
+
+    /// Returns the item with the largest key, or [None] if the table is empty.
+    ///
+    /// For [TableFlags::DUP_SORT] tables this is the last duplicate of the largest key.
+    pub fn last<'txn, Key, Value>(&'txn self, table: &Table<'txn>) -> Result<Option<(Key, Value)>>
+    where
+        Key: Decodable<'txn>,
+        Value: Decodable<'txn>,
+    {
+        self.cursor(table)?.last()
+    }
+
+    /// [TableFlags::DUP_SORT]-only: Returns the first duplicate stored under the given key, or
+    /// [None] if the key is absent.
+    pub fn first_dup<'txn, Value>(
+        &'txn self,
+        table: &Table<'txn>,
+        key: &[u8],
+    ) -> Result<Option<Value>>
+    where
+        Value: Decodable<'txn>,
+    {
+        self.cursor(table)?.set(key)
+    }
+
+    /// [TableFlags::DUP_SORT]-only: Returns the last duplicate stored under the given key, or
+    /// [None] if the key is absent.
+    pub fn last_dup<'txn, Value>(
+        &'txn self,
+        table: &Table<'txn>,
+        key: &[u8],
+    ) -> Result<Option<Value>>
+    where
+        Value: Decodable<'txn>,
+    {
+        let mut cursor = self.cursor(table)?;
+        if cursor.set::<()>(key)?.is_none() {
+            return Ok(None);
+        }
+        cursor.last_dup()
+    }
+
+    /// Returns the number of values stored under the given key, or 0 if the key is absent.
+    ///
+    /// Tables without [TableFlags::DUP_SORT] hold at most one value per key, so the result is
+    /// either 0 or 1 for them.
+    pub fn dup_count<'txn>(&'txn self, table: &Table<'txn>, key: &[u8]) -> Result<usize> {
+        let mut cursor = self.cursor(table)?;
+        if cursor.set::<()>(key)?.is_none() {
+            return Ok(0);
+        }
+        cursor.count()
+    }
+
     /// Commits the transaction.
     ///
     /// Any pending operations will be saved.
@@ -147,16 +282,28 @@ where
         self.commit_and_rebind_open_dbs().map(|v| v.0)
     }
 
+    /// Commits the transaction, also returning how long each stage of the commit took.
+    pub fn commit_with_latency(self) -> Result<(bool, CommitLatency)> {
+        self.commit_inner().map(|(v, latency, _)| (v, latency))
+    }
+
     pub fn prime_for_permaopen(&self, table: Table<'_>) {
         self.primed_dbis.lock().insert(table.dbi());
     }
 
     /// Commits the transaction and returns table handles permanently open for the lifetime of `Database`.
-    pub fn commit_and_rebind_open_dbs(mut self) -> Result<(bool, Vec<Table<'db>>)> {
-        let txnlck = self.txn.lock();
-        let txn = txnlck.0;
+    pub fn commit_and_rebind_open_dbs(self) -> Result<(bool, Vec<Table<'db>>)> {
+        self.commit_inner().map(|(v, _, tables)| (v, tables))
+    }
+
+    fn commit_inner(mut self) -> Result<(bool, CommitLatency, Vec<Table<'db>>)> {
+        let mut txnlck = self.txn.lock();
+        txnlck.close_cursors();
+        let txn = txnlck.ptr.0;
         let result = if K::ONLY_CLEAN {
-            mdbx_result(unsafe { ffi::mdbx_txn_commit_ex(txn, ptr::null_mut()) })
+            let mut latency = CommitLatency::new();
+            mdbx_result(unsafe { ffi::mdbx_txn_commit_ex(txn, latency.mdb_commit_latency()) })
+                .map(|v| (v, latency))
         } else {
             let (sender, rx) = sync_channel(0);
             self.db
@@ -171,9 +318,14 @@ where
             rx.recv().unwrap()
         };
         self.committed = true;
-        result.map(|v| {
+        if result.is_ok() && !self.nested {
+            let mut dbis = self.db.dbis.lock();
+            dbis.extend(self.created_dbis.get_mut().drain(..));
+        }
+        result.map(|(v, latency)| {
             (
                 v,
+                latency,
                 self.primed_dbis
                     .lock()
                     .iter()
@@ -194,21 +346,197 @@ where
     /// The returned table handle may be shared among any transaction in the database.
     ///
     /// The table name may not contain the null character.
+    ///
+    /// Handles to named tables are cached by the database, so opening a table again in a later
+    /// transaction does not need to look it up by name.
     pub fn open_table<'txn>(&'txn self, name: Option<&str>) -> Result<Table<'txn>> {
-        Table::new(self, name, 0)
+        if let Some(name) = name {
+            let cached = self.db.dbis.lock().get(name).copied();
+            if let Some(dbi) = cached {
+                match self.dbi_flags(dbi) {
+                    Ok(_) => {
+                        let table = Table::new_from_ptr(dbi);
+                        // MDBX accepts handles opened by later transactions, so check that the
+                        // table exists in this snapshot. The handle stays valid for newer ones.
+                        return match self.table_stat(&table) {
+                            Ok(_) => Ok(table),
+                            Err(Error::BadDbi) => Err(Error::NotFound),
+                            Err(e) => Err(e),
+                        };
+                    }
+                    Err(Error::BadDbi) => self.db.forget_dbi(dbi),
+                    Err(e) => return Err(e),
+                }
+            }
+        }
+
+        let table = Table::new(self, name, 0)?;
+        self.remember_table(name, &table)?;
+        Ok(table)
     }
 
-    /// Gets the option flags for the given table in the transaction.
-    pub fn table_flags<'txn>(&'txn self, table: &Table<'txn>) -> Result<TableFlags> {
+    /// Adds a named table to the handle cache of the database. Handles to tables created by this
+    /// transaction are only added once it commits, as MDBX closes them otherwise.
+    fn remember_table(&self, name: Option<&str>, table: &Table<'_>) -> Result<()> {
+        let Some(name) = name else {
+            return Ok(());
+        };
+
+        let (_, state) = self.dbi_flags(table.dbi())?;
+        if state & ffi::MDBX_DBI_CREAT != 0 {
+            self.created_dbis
+                .lock()
+                .push((name.to_owned(), table.dbi()));
+        } else {
+            self.db.dbis.lock().insert(name.to_owned(), table.dbi());
+        }
+        Ok(())
+    }
+
+    /// The persistent flags and the `MDBX_dbi_state_t` of a table handle.
+    fn dbi_flags(&self, dbi: ffi::MDBX_dbi) -> Result<(c_uint, c_uint)> {
         let mut flags: c_uint = 0;
-        unsafe {
-            mdbx_result(txn_execute(&self.txn, |txn| {
-                ffi::mdbx_dbi_flags_ex(txn, table.dbi(), &mut flags, ptr::null_mut())
-            }))?;
+        let mut state: c_uint = 0;
+        mdbx_result(txn_execute(&self.txn, |txn| unsafe {
+            ffi::mdbx_dbi_flags_ex(txn, dbi, &mut flags, &mut state)
+        }))?;
+        Ok((flags, state))
+    }
+
+    /// Opens a handle to an MDBX table ordered by custom comparators, creating the table if
+    /// necessary.
+    ///
+    /// `key_cmp` orders the keys and `dup_cmp` the duplicates of [TableFlags::DUP_SORT] tables;
+    /// [None] keeps the default ordering implied by `flags`. See [Comparator] for a safe way to
+    /// define them.
+    ///
+    /// MDBX keeps using the comparators for as long as the table stays open in the database, so
+    /// they must not change while it is open. Opening a table again with different comparators
+    /// fails with [Error::Incompatible]. See [Self::create_table()] for other details.
+    ///
+    /// [Comparator]: crate::Comparator
+    pub fn create_table_with_comparators<'txn>(
+        &'txn self,
+        name: Option<&str>,
+        flags: TableFlags,
+        key_cmp: Option<ComparatorFn>,
+        dup_cmp: Option<ComparatorFn>,
+    ) -> Result<Table<'txn>> {
+        let id = (key_cmp.map(|f| f as usize), dup_cmp.map(|f| f as usize));
+        let mut comparators = self.db.comparators.lock();
+        let name_key = name.map(ToOwned::to_owned);
+        if comparators
+            .get(&name_key)
+            .is_some_and(|existing| *existing != id)
+        {
+            return Err(Error::Incompatible);
         }
+
+        let table = Table::new_with_comparators(
+            self,
+            name,
+            (flags | TableFlags::CREATE).bits(),
+            key_cmp,
+            dup_cmp,
+        )?;
+        comparators.insert(name_key, id);
+        Ok(table)
+    }
+
+    /// Returns the names of all named tables in the database, in key order.
+    ///
+    /// Named tables are recorded in the main table, next to any plain items stored there. Each
+    /// key is probed by opening it as a table, so handles to all named tables stay open afterwards,
+    /// which counts against [DatabaseOptions::max_tables](crate::DatabaseOptions::max_tables).
+    pub fn table_names(&self) -> Result<Vec<String>> {
+        let main_table = self.open_table(None)?;
+        let mut cursor = self.cursor(&main_table)?;
+        let mut names = Vec::new();
+        while let Some((name, ())) = cursor.next_nodup::<Cow<[u8]>, ()>()? {
+            let Ok(name) = std::str::from_utf8(&name) else {
+                continue;
+            };
+            if name.contains('\0') {
+                continue;
+            }
+            match Table::new(self, Some(name), TableFlags::ACCEDE.bits()) {
+                Ok(_) => names.push(name.to_owned()),
+                Err(Error::Incompatible) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(names)
+    }
+
+    /// Opens a handle to an MDBX table storing values of exactly `N` bytes.
+    ///
+    /// See [Self::open_table()] for details.
+    pub fn open_fixed_value_table<'txn, const N: usize>(
+        &'txn self,
+        name: Option<&str>,
+    ) -> Result<FixedValueTable<'txn, N>> {
+        self.open_table(name).map(FixedValueTable::new)
+    }
+
+    /// Opens a handle to an MDBX table keyed by native-endian `u64` integers.
+    ///
+    /// Fails with [Error::Incompatible] if the table was not created with
+    /// [TableFlags::INTEGER_KEY]. See [Self::open_table()] for details.
+    pub fn open_integer_table<'txn>(
+        &'txn self,
+        name: Option<&str>,
+    ) -> Result<IntegerKeyTable<'txn>> {
+        let table = self.open_table(name)?;
+        if !self.table_flags(&table)?.contains(TableFlags::INTEGER_KEY) {
+            return Err(Error::Incompatible);
+        }
+        Ok(IntegerKeyTable::new(table))
+    }
+
+    /// Gets the option flags for the given table in the transaction.
+    pub fn table_flags<'txn>(&'txn self, table: &Table<'txn>) -> Result<TableFlags> {
+        let (flags, _) = self.dbi_flags(table.dbi())?;
         Ok(TableFlags::from_bits_truncate(flags))
     }
 
+    /// Maximal key size in bytes for the given table, according to its flags.
+    pub fn table_max_key_size<'txn>(&'txn self, table: &Table<'txn>) -> Result<usize> {
+        self.db.max_key_size(self.table_flags(table)?)
+    }
+
+    /// Maximal value size in bytes for the given table, according to its flags.
+    pub fn table_max_value_size<'txn>(&'txn self, table: &Table<'txn>) -> Result<usize> {
+        self.db.max_value_size(self.table_flags(table)?)
+    }
+
+    /// Current value of the table's persistent sequence.
+    pub fn current_sequence<'txn>(&'txn self, table: &Table<'txn>) -> Result<u64> {
+        let mut value = 0;
+        mdbx_result(txn_execute(&self.txn, |txn| unsafe {
+            ffi::mdbx_dbi_sequence(txn, table.dbi(), &mut value, 0)
+        }))?;
+        Ok(value)
+    }
+
+    /// Reads the canary markers of the transaction's snapshot.
+    pub fn canary(&self) -> Result<Canary> {
+        let mut canary = ffi::MDBX_canary {
+            x: 0,
+            y: 0,
+            z: 0,
+            v: 0,
+        };
+        mdbx_result(txn_execute(&self.txn, |txn| unsafe {
+            ffi::mdbx_canary_get(txn, &mut canary)
+        }))?;
+        Ok(Canary {
+            x: canary.x,
+            y: canary.y,
+            z: canary.z,
+            v: canary.v,
+        })
+    }
+
     /// Retrieves table statistics.
     pub fn table_stat<'txn>(&'txn self, table: &Table<'txn>) -> Result<Stat> {
         unsafe {
@@ -220,15 +548,179 @@ where
         }
     }
 
+    /// [TableFlags::DUP_SORT]-only: Retrieves the depths of the trees holding the duplicates of
+    /// each key, as a bitmask.
+    ///
+    /// Bit 0 is set if some key has a single value, bit 1 if the duplicates of some key fit in a
+    /// single page, and bit `n` if the duplicates of some key form a nested B-tree of depth `n`.
+    /// This walks every key, so it is linear in the number of keys. The pages of the nested trees
+    /// are included in [Self::table_stat].
+    ///
+    /// Fails with [Error::Incompatible] for other tables.
+    pub fn dupsort_depthmask<'txn>(&'txn self, table: &Table<'txn>) -> Result<u32> {
+        let mut mask = 0;
+        let not_dup_sort = mdbx_result(txn_execute(&self.txn, |txn| unsafe {
+            ffi::mdbx_dbi_dupsort_depthmask(txn, table.dbi(), &mut mask)
+        }))?;
+        if not_dup_sort {
+            return Err(Error::Incompatible);
+        }
+        Ok(mask)
+    }
+
+    /// Returns the number of items in the table.
+    ///
+    /// For [TableFlags::DUP_SORT] tables every duplicate is counted, i.e. this is the number of
+    /// key/value pairs rather than the number of distinct keys. See [Self::key_count].
+    pub fn len<'txn>(&'txn self, table: &Table<'txn>) -> Result<usize> {
+        Ok(self.table_stat(table)?.entries())
+    }
+
+    /// Returns `true` if the table contains no items.
+    ///
+    /// This only probes for the first item and is cheaper than [Self::len].
+    pub fn is_empty<'txn>(&'txn self, table: &Table<'txn>) -> Result<bool> {
+        Ok(self.cursor(table)?.first::<(), ()>()?.is_none())
+    }
+
+    /// Returns the number of distinct keys in the table.
+    ///
+    /// Same as [Self::len] for tables without [TableFlags::DUP_SORT]. For
+    /// [TableFlags::DUP_SORT] tables this walks every key, so it is linear in the number of keys.
+    pub fn key_count<'txn>(&'txn self, table: &Table<'txn>) -> Result<usize> {
+        if !self.table_flags(table)?.contains(TableFlags::DUP_SORT) {
+            return self.len(table);
+        }
+
+        let mut cursor = self.cursor(table)?;
+        let mut count = 0;
+        let mut item = cursor.first::<(), ()>()?;
+        while item.is_some() {
+            count += 1;
+            item = cursor.next_nodup()?;
+        }
+        Ok(count)
+    }
+
+    /// Estimates the number of items between `start` and `end` without iterating them.
+    ///
+    /// Each bound is a key with optional data to seek among sorted duplicates (only for
+    /// [TableFlags::DUP_SORT] tables). `None` stands for the first or last item respectively. The
+    /// result is negative if `start` sorts after `end`.
+    ///
+    /// The estimate is derived from the height and fill of the B-tree, so its accuracy depends on
+    /// how balanced the tree is. Pages may be anywhere between a quarter and fully filled, so in
+    /// the worst case the result may be off by a factor of 4 for each tree level other than the
+    /// first and the last, though in practice the error rarely exceeds a few percent.
+    pub fn estimate_range<'txn>(
+        &'txn self,
+        table: &Table<'txn>,
+        start: Option<(&[u8], Option<&[u8]>)>,
+        end: Option<(&[u8], Option<&[u8]>)>,
+    ) -> Result<isize> {
+        fn to_val(slice: Option<&[u8]>) -> Option<ffi::MDBX_val> {
+            slice.map(|slice| ffi::MDBX_val {
+                iov_len: slice.len(),
+                iov_base: slice.as_ptr() as *mut c_void,
+            })
+        }
+        fn as_ptr(val: &Option<ffi::MDBX_val>) -> *const ffi::MDBX_val {
+            val.as_ref().map_or(ptr::null(), |val| val as *const _)
+        }
+
+        let begin_key = to_val(start.map(|(key, _)| key));
+        let begin_data = to_val(start.and_then(|(_, data)| data));
+        let end_key = to_val(end.map(|(key, _)| key));
+        let end_data = to_val(end.and_then(|(_, data)| data));
+
+        let mut distance = 0;
+        mdbx_result(txn_execute(&self.txn, |txn| unsafe {
+            ffi::mdbx_estimate_range(
+                txn,
+                table.dbi(),
+                as_ptr(&begin_key),
+                as_ptr(&begin_data),
+                as_ptr(&end_key),
+                as_ptr(&end_data),
+                &mut distance,
+            )
+        }))?;
+        Ok(distance)
+    }
+
     /// Open a new cursor on the given table.
     pub fn cursor<'txn>(&'txn self, table: &Table<'txn>) -> Result<Cursor<'txn, K>> {
         Cursor::new(self, table)
     }
+
+    pub(crate) fn compare_keys(&self, table: &Table<'_>, a: &[u8], b: &[u8]) -> Ordering {
+        let a = ffi::MDBX_val {
+            iov_len: a.len(),
+            iov_base: a.as_ptr() as *mut c_void,
+        };
+        let b = ffi::MDBX_val {
+            iov_len: b.len(),
+            iov_base: b.as_ptr() as *mut c_void,
+        };
+        txn_execute(&self.txn, |txn| unsafe {
+            ffi::mdbx_cmp(txn, table.dbi(), &a, &b)
+        })
+        .cmp(&0)
+    }
+
+    /// Like [Transaction::compare_keys], but orders duplicates of a key.
+    pub(crate) fn compare_values(&self, table: &Table<'_>, a: &[u8], b: &[u8]) -> Ordering {
+        let a = ffi::MDBX_val {
+            iov_len: a.len(),
+            iov_base: a.as_ptr() as *mut c_void,
+        };
+        let b = ffi::MDBX_val {
+            iov_len: b.len(),
+            iov_base: b.as_ptr() as *mut c_void,
+        };
+        txn_execute(&self.txn, |txn| unsafe {
+            ffi::mdbx_dcmp(txn, table.dbi(), &a, &b)
+        })
+        .cmp(&0)
+    }
+}
+
+/// An MDBX transaction, shared by the [Transaction] and the cursors opened in it.
+pub(crate) struct TxnState {
+    pub(crate) ptr: TxnPtr,
+    /// Cursors opened in the transaction which are still open. Those left when the transaction
+    /// ends are closed with it, after which dropping their [Cursor] does nothing.
+    cursors: HashSet<CursorPtr>,
+}
+
+impl TxnState {
+    fn new(txn: *mut ffi::MDBX_txn) -> Self {
+        Self {
+            ptr: TxnPtr(txn),
+            cursors: HashSet::new(),
+        }
+    }
+
+    pub(crate) fn register(&mut self, cursor: CursorPtr) {
+        self.cursors.insert(cursor);
+    }
+
+    /// Stops tracking the cursor, returning whether it was still open.
+    pub(crate) fn deregister(&mut self, cursor: CursorPtr) -> bool {
+        self.cursors.remove(&cursor)
+    }
+
+    /// Closes the cursors still open, which must happen before the transaction ends.
+    fn close_cursors(&mut self) {
+        for cursor in self.cursors.drain() {
+            unsafe { ffi::mdbx_cursor_close(cursor.0) }
+        }
+    }
 }
 
-pub(crate) fn txn_execute<F: FnOnce(*mut ffi::MDBX_txn) -> T, T>(txn: &Mutex<TxnPtr>, f: F) -> T {
+pub(crate) fn txn_execute<F: FnOnce(*mut ffi::MDBX_txn) -> T, T>(txn: &Mutex<TxnState>, f: F) -> T {
     let lck = txn.lock();
-    (f)(lck.0)
+    (f)(lck.ptr.0)
 }
 
 impl<'db, E> Transaction<'db, RW, E>
@@ -240,7 +732,9 @@ where
         name: Option<&str>,
         flags: TableFlags,
     ) -> Result<Table<'txn>> {
-        Table::new(self, name, flags.bits())
+        let table = Table::new(self, name, flags.bits())?;
+        self.remember_table(name, &table)?;
+        Ok(table)
     }
 
     /// Opens a handle to an MDBX table, creating the table if necessary.
@@ -263,6 +757,38 @@ where
         self.open_table_with_flags(name, flags | TableFlags::CREATE)
     }
 
+    /// Opens a handle to an MDBX table storing values of exactly `N` bytes, creating the table if
+    /// necessary.
+    ///
+    /// If `flags` contains [TableFlags::DUP_SORT], [TableFlags::DUP_FIXED] is added as well, since
+    /// all duplicates are known to have the same size.
+    ///
+    /// See [Self::create_table()] for details.
+    pub fn create_fixed_value_table<'txn, const N: usize>(
+        &'txn self,
+        name: Option<&str>,
+        mut flags: TableFlags,
+    ) -> Result<FixedValueTable<'txn, N>> {
+        if flags.contains(TableFlags::DUP_SORT) {
+            flags |= TableFlags::DUP_FIXED;
+        }
+        self.create_table(name, flags).map(FixedValueTable::new)
+    }
+
+    /// Opens a handle to an MDBX table keyed by native-endian `u64` integers, creating the table
+    /// if necessary.
+    ///
+    /// [TableFlags::INTEGER_KEY] is always added to `flags`. See [Self::create_table()] for
+    /// details.
+    pub fn create_integer_table<'txn>(
+        &'txn self,
+        name: Option<&str>,
+        flags: TableFlags,
+    ) -> Result<IntegerKeyTable<'txn>> {
+        self.create_table(name, flags | TableFlags::INTEGER_KEY)
+            .map(IntegerKeyTable::new)
+    }
+
     /// Stores an item into a table.
     ///
     /// This function stores key/data pairs in the table. The default
@@ -293,6 +819,213 @@ where
         Ok(())
     }
 
+    /// Stores an item into a table unless `key` is already present, in which case the existing
+    /// value is returned and the table is left unchanged. Returns [None] if the item was stored.
+    ///
+    /// In [TableFlags::DUP_SORT] tables, nothing is stored if the key has any duplicate, and the
+    /// first one is returned. To add a duplicate unless the same key/value pair exists, use
+    /// [Transaction::put] with [WriteFlags::NO_DUP_DATA], which fails with [Error::KeyExist].
+    pub fn put_no_overwrite<'txn, Value>(
+        &'txn self,
+        table: &Table<'txn>,
+        key: impl AsRef<[u8]>,
+        data: impl AsRef<[u8]>,
+    ) -> Result<Option<Value>>
+    where
+        Value: Decodable<'txn>,
+    {
+        let key = key.as_ref();
+        let data = data.as_ref();
+        let key_val: ffi::MDBX_val = ffi::MDBX_val {
+            iov_len: key.len(),
+            iov_base: key.as_ptr() as *mut c_void,
+        };
+        let mut data_val: ffi::MDBX_val = ffi::MDBX_val {
+            iov_len: data.len(),
+            iov_base: data.as_ptr() as *mut c_void,
+        };
+        // On a collision, MDBX points the data at the existing value.
+        txn_execute(&self.txn, |txn| unsafe {
+            match ffi::mdbx_put(
+                txn,
+                table.dbi(),
+                &key_val,
+                &mut data_val,
+                ffi::MDBX_NOOVERWRITE,
+            ) {
+                ffi::MDBX_SUCCESS => Ok(None),
+                ffi::MDBX_KEYEXIST => Value::decode_val::<RW>(txn, &data_val).map(Some),
+                err_code => Err(Error::from_err_code(err_code)),
+            }
+        })
+    }
+
+    /// Stores an item into a table like [Transaction::put], returning the value previously stored
+    /// under `key`, or [None] if there was none.
+    ///
+    /// The previous value is always copied out, since its page may be overwritten by the update.
+    ///
+    /// In [TableFlags::DUP_SORT] tables, the new value is added as another duplicate and the first
+    /// previous duplicate is returned. With [WriteFlags::CURRENT] the existing value is replaced
+    /// instead, which fails with [Error::Multival] if the key has several duplicates; use
+    /// [Transaction::replace_dup] to replace a specific one. The
+    /// `WriteFlags::CURRENT | WriteFlags::NO_OVERWRITE` combination is reserved for that method.
+    pub fn replace<'txn, Value>(
+        &'txn self,
+        table: &Table<'txn>,
+        key: impl AsRef<[u8]>,
+        data: impl AsRef<[u8]>,
+        flags: WriteFlags,
+    ) -> Result<Option<Value>>
+    where
+        Value: Decodable<'txn>,
+    {
+        if flags.contains(WriteFlags::CURRENT | WriteFlags::NO_OVERWRITE) {
+            return Err(Error::InvalidValue);
+        }
+
+        let key = key.as_ref();
+        let data = data.as_ref();
+        let key_val: ffi::MDBX_val = ffi::MDBX_val {
+            iov_len: key.len(),
+            iov_base: key.as_ptr() as *mut c_void,
+        };
+        let mut data_val: ffi::MDBX_val = ffi::MDBX_val {
+            iov_len: data.len(),
+            iov_base: data.as_ptr() as *mut c_void,
+        };
+
+        let mut buf = vec![0; 64];
+        txn_execute(&self.txn, |txn| unsafe {
+            let old_val = loop {
+                let mut old_val = ffi::MDBX_val {
+                    iov_len: buf.len(),
+                    iov_base: buf.as_mut_ptr() as *mut c_void,
+                };
+                // MDBX reports the required size if the buffer is too small.
+                if mdbx_result(ffi::mdbx_replace(
+                    txn,
+                    table.dbi(),
+                    &key_val,
+                    &mut data_val,
+                    &mut old_val,
+                    flags.bits(),
+                ))? {
+                    buf.resize(old_val.iov_len, 0);
+                    continue;
+                }
+                break old_val;
+            };
+
+            if old_val.iov_base.is_null() {
+                return Ok(None);
+            }
+
+            Value::decode(slice::from_raw_parts(
+                old_val.iov_base as *const u8,
+                old_val.iov_len,
+            ))
+            .map(Some)
+        })
+    }
+
+    /// Replaces the duplicate `old_data` of `key` in a [TableFlags::DUP_SORT] table with
+    /// `new_data`.
+    ///
+    /// Returns [Error::NotFound] if the key/data pair does not exist.
+    pub fn replace_dup<'txn>(
+        &'txn self,
+        table: &Table<'txn>,
+        key: impl AsRef<[u8]>,
+        old_data: impl AsRef<[u8]>,
+        new_data: impl AsRef<[u8]>,
+    ) -> Result<()> {
+        let key = key.as_ref();
+        let old_data = old_data.as_ref();
+        let new_data = new_data.as_ref();
+        let key_val: ffi::MDBX_val = ffi::MDBX_val {
+            iov_len: key.len(),
+            iov_base: key.as_ptr() as *mut c_void,
+        };
+        let mut old_val: ffi::MDBX_val = ffi::MDBX_val {
+            iov_len: old_data.len(),
+            iov_base: old_data.as_ptr() as *mut c_void,
+        };
+        let mut new_val: ffi::MDBX_val = ffi::MDBX_val {
+            iov_len: new_data.len(),
+            iov_base: new_data.as_ptr() as *mut c_void,
+        };
+        mdbx_result(txn_execute(&self.txn, |txn| unsafe {
+            ffi::mdbx_replace(
+                txn,
+                table.dbi(),
+                &key_val,
+                &mut new_val,
+                &mut old_val,
+                ffi::MDBX_CURRENT | ffi::MDBX_NOOVERWRITE,
+            )
+        }))?;
+
+        Ok(())
+    }
+
+    /// Sets the canary markers, which are persisted with the commit of this transaction.
+    ///
+    /// `canary.v` is ignored, as it is maintained by MDBX.
+    pub fn put_canary(&self, canary: &Canary) -> Result<()> {
+        let canary = ffi::MDBX_canary {
+            x: canary.x,
+            y: canary.y,
+            z: canary.z,
+            v: 0,
+        };
+        mdbx_result(txn_execute(&self.txn, |txn| unsafe {
+            ffi::mdbx_canary_put(txn, &canary)
+        }))?;
+        Ok(())
+    }
+
+    /// Increments the table's persistent sequence by `increment`, returning its previous value.
+    ///
+    /// Returns [Error::SequenceOverflow] (leaving the sequence unchanged) if the sequence would
+    /// overflow.
+    pub fn sequence<'txn>(&'txn self, table: &Table<'txn>, increment: u64) -> Result<u64> {
+        let mut value = 0;
+        if mdbx_result(txn_execute(&self.txn, |txn| unsafe {
+            ffi::mdbx_dbi_sequence(txn, table.dbi(), &mut value, increment)
+        }))? {
+            return Err(Error::SequenceOverflow);
+        }
+        Ok(value)
+    }
+
+    /// Appends a key/data pair to the end of a table, which is much faster than [Transaction::put]
+    /// for bulk loading already sorted data.
+    ///
+    /// Returns [Error::KeyMismatch] if `key` does not sort after the last key in the table.
+    pub fn append<'txn>(
+        &'txn self,
+        table: &Table<'txn>,
+        key: impl AsRef<[u8]>,
+        data: impl AsRef<[u8]>,
+    ) -> Result<()> {
+        self.put(table, key, data, WriteFlags::APPEND)
+    }
+
+    /// Appends a data item to the end of the duplicates of `key` in a [TableFlags::DUP_SORT]
+    /// table.
+    ///
+    /// Returns [Error::KeyMismatch] if the key/data pair does not sort after the last one in the
+    /// table.
+    pub fn append_dup<'txn>(
+        &'txn self,
+        table: &Table<'txn>,
+        key: impl AsRef<[u8]>,
+        data: impl AsRef<[u8]>,
+    ) -> Result<()> {
+        self.put(table, key, data, WriteFlags::APPEND_DUP)
+    }
+
     /// Returns a buffer which can be used to write a value into the item at the
     /// given key and with the given length. The buffer must be completely
     /// filled by the caller.
@@ -369,6 +1102,116 @@ where
         })
     }
 
+    /// Deletes all items whose keys fall within `range`, returning the number of deleted
+    /// key/value pairs. In [TableFlags::DUP_SORT] tables, all duplicates of the matching keys are
+    /// deleted and counted individually.
+    ///
+    /// Keys are compared using the table's own ordering.
+    pub fn del_range<'txn, Key>(
+        &'txn self,
+        table: &Table<'txn>,
+        range: impl RangeBounds<Key>,
+    ) -> Result<usize>
+    where
+        Key: AsRef<[u8]>,
+    {
+        self.del_matching(table, range, |_, _| true, |_, _| ())
+    }
+
+    /// Keeps only the items for which `f` returns `true`, returning the number of deleted
+    /// key/value pairs.
+    ///
+    /// In [TableFlags::DUP_SORT] tables `f` is called for every duplicate.
+    pub fn retain<'txn>(
+        &'txn self,
+        table: &Table<'txn>,
+        mut f: impl FnMut(&[u8], &[u8]) -> bool,
+    ) -> Result<usize> {
+        self.del_matching::<&[u8]>(table, .., |key, value| !f(key, value), |_, _| ())
+    }
+
+    /// Deletes the items within `range` for which `f` returns `true`, returning the deleted
+    /// key/value pairs in table order.
+    ///
+    /// In [TableFlags::DUP_SORT] tables `f` is called for every duplicate. Keys are compared
+    /// using the table's own ordering.
+    pub fn drain<'txn, Key>(
+        &'txn self,
+        table: &Table<'txn>,
+        range: impl RangeBounds<Key>,
+        f: impl FnMut(&[u8], &[u8]) -> bool,
+    ) -> Result<Vec<(Vec<u8>, Vec<u8>)>>
+    where
+        Key: AsRef<[u8]>,
+    {
+        let mut drained = Vec::new();
+        self.del_matching(table, range, f, |key, value| {
+            drained.push((key.to_vec(), value.to_vec()))
+        })?;
+        Ok(drained)
+    }
+
+    /// Walks `range` with a single cursor, deleting every item `f` matches and passing it to
+    /// `on_deleted`.
+    fn del_matching<'txn, Key>(
+        &'txn self,
+        table: &Table<'txn>,
+        range: impl RangeBounds<Key>,
+        mut f: impl FnMut(&[u8], &[u8]) -> bool,
+        mut on_deleted: impl FnMut(&[u8], &[u8]),
+    ) -> Result<usize>
+    where
+        Key: AsRef<[u8]>,
+    {
+        let mut cursor = self.cursor(table)?;
+        let mut item = match range.start_bound() {
+            Bound::Included(start) => cursor.set_range::<Cow<[u8]>, Cow<[u8]>>(start.as_ref())?,
+            Bound::Excluded(start) => {
+                match cursor.set_range::<Cow<[u8]>, Cow<[u8]>>(start.as_ref())? {
+                    Some((key, _))
+                        if self.compare_keys(table, &key, start.as_ref()) == Ordering::Equal =>
+                    {
+                        cursor.next_nodup()?
+                    }
+                    other => other,
+                }
+            }
+            Bound::Unbounded => cursor.first()?,
+        };
+
+        let mut deleted = 0;
+        while let Some((key, value)) = item {
+            let past_end = match range.end_bound() {
+                Bound::Included(end) => {
+                    self.compare_keys(table, &key, end.as_ref()) == Ordering::Greater
+                }
+                Bound::Excluded(end) => {
+                    self.compare_keys(table, &key, end.as_ref()) != Ordering::Less
+                }
+                Bound::Unbounded => false,
+            };
+            if past_end {
+                break;
+            }
+
+            if f(&key, &value) {
+                on_deleted(&key, &value);
+                cursor.del(WriteFlags::empty())?;
+                deleted += 1;
+                // Deleting moves the cursor to the following item, except after the last
+                // duplicate of a key, where it has to be advanced to the next key explicitly.
+                item = match cursor.get_current()? {
+                    Some(item) => Some(item),
+                    None => cursor.next()?,
+                };
+            } else {
+                item = cursor.next()?;
+            }
+        }
+
+        Ok(deleted)
+    }
+
     /// Empties the given table. All items will be removed.
     pub fn clear_table<'txn>(&'txn self, table: &Table<'txn>) -> Result<()> {
         mdbx_result(txn_execute(&self.txn, |txn| unsafe {
@@ -378,6 +1221,41 @@ where
         Ok(())
     }
 
+    /// Drops the named table from the database and releases its handle.
+    ///
+    /// Taking `&mut self` guarantees that no [Table] or [Cursor] obtained from this transaction is
+    /// still alive:
+    ///
+    /// ```compile_fail
+    /// # use libmdbx::{Database, NoWriteMap, TableFlags};
+    /// # let dir = tempfile::tempdir().unwrap();
+    /// # let db = Database::<NoWriteMap>::open_with_options(&dir, libmdbx::DatabaseOptions {
+    /// #     max_tables: Some(1),
+    /// #     ..Default::default()
+    /// # }).unwrap();
+    /// let mut txn = db.begin_rw_txn().unwrap();
+    /// let table = txn.create_table(Some("table"), TableFlags::empty()).unwrap();
+    /// let cursor = txn.cursor(&table).unwrap();
+    /// unsafe { txn.drop_table_by_name("table") }.unwrap();
+    /// drop(cursor);
+    /// ```
+    ///
+    /// Fails with [Error::TableInUse] if the table was primed with
+    /// [Transaction::prime_for_permaopen].
+    ///
+    /// # Safety
+    /// Caller must close ALL other [Table] and [Cursor] instances pointing to the same dbi BEFORE calling this function,
+    /// including those of other transactions and those returned by
+    /// [Transaction::commit_and_rebind_open_dbs].
+    pub unsafe fn drop_table_by_name(&mut self, name: &str) -> Result<()> {
+        let table = self.open_table(Some(name))?;
+        if self.primed_dbis.lock().contains(&table.dbi()) {
+            return Err(Error::TableInUse);
+        }
+
+        self.drop_table(table)
+    }
+
     /// Drops the table from the database.
     ///
     /// # Safety
@@ -386,6 +1264,10 @@ where
         mdbx_result(txn_execute(&self.txn, |txn| {
             ffi::mdbx_drop(txn, table.dbi(), true)
         }))?;
+        self.db.forget_dbi(table.dbi());
+        self.created_dbis
+            .lock()
+            .retain(|(_, dbi)| *dbi != table.dbi());
 
         Ok(())
     }
@@ -400,10 +1282,23 @@ where
     /// # Safety
     /// Caller must close ALL other [Table] and [Cursor] instances pointing to the same dbi BEFORE calling this function.
     pub unsafe fn close_table(&self, table: Table<'_>) -> Result<()> {
+        self.db.forget_dbi(table.dbi());
         mdbx_result(ffi::mdbx_dbi_close(self.db.ptr().0, table.dbi()))?;
 
         Ok(())
     }
+
+    /// Number of write transactions committed since this transaction's snapshot was taken.
+    ///
+    /// This does not scan the reader table, so it is cheap enough to call in a loop.
+    pub fn lag(&self) -> Result<u64> {
+        self.info(false).map(|info| info.reader_lag())
+    }
+
+    /// Whether the snapshot is more than `max_lag` commits behind the most recent one.
+    pub fn is_stale(&self, max_lag: u64) -> Result<bool> {
+        Ok(self.lag()? > max_lag)
+    }
 }
 
 impl<'db> Transaction<'db, RW, NoWriteMap> {
@@ -422,13 +1317,157 @@ impl<'db> Transaction<'db, RW, NoWriteMap> {
                 })
                 .unwrap();
 
-            rx.recv()
-                .unwrap()
-                .map(|ptr| Transaction::new_from_ptr(self.db, ptr.0))
+            rx.recv().unwrap().map(|ptr| {
+                let mut txn = Transaction::new_from_ptr(self.db, ptr.0);
+                txn.nested = true;
+                txn
+            })
         })
     }
 }
 
+/// Durations of the stages of a transaction commit.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct CommitLatency(ffi::MDBX_commit_latency);
+
+impl CommitLatency {
+    pub(crate) fn new() -> Self {
+        unsafe { Self(std::mem::zeroed()) }
+    }
+
+    pub(crate) fn mdb_commit_latency(&mut self) -> *mut ffi::MDBX_commit_latency {
+        &mut self.0
+    }
+
+    /// Duration of preparation (committing child transactions, updating table records).
+    #[inline]
+    pub fn preparation(&self) -> Duration {
+        duration_from_16dot16(self.0.preparation)
+    }
+
+    /// Duration of the GC update, by wall clock.
+    #[inline]
+    pub fn gc_wallclock(&self) -> Duration {
+        duration_from_16dot16(self.0.gc_wallclock)
+    }
+
+    /// User-mode CPU time spent on the GC update.
+    #[inline]
+    pub fn gc_cputime(&self) -> Duration {
+        duration_from_16dot16(self.0.gc_cputime)
+    }
+
+    /// Duration of the internal audit, if enabled.
+    #[inline]
+    pub fn audit(&self) -> Duration {
+        duration_from_16dot16(self.0.audit)
+    }
+
+    /// Duration of writing dirty pages to the filesystem.
+    #[inline]
+    pub fn write(&self) -> Duration {
+        duration_from_16dot16(self.0.write)
+    }
+
+    /// Duration of syncing written data to the disk.
+    #[inline]
+    pub fn sync(&self) -> Duration {
+        duration_from_16dot16(self.0.sync)
+    }
+
+    /// Duration of transaction ending (releasing resources).
+    #[inline]
+    pub fn ending(&self) -> Duration {
+        duration_from_16dot16(self.0.ending)
+    }
+
+    /// Total duration of the commit.
+    #[inline]
+    pub fn whole(&self) -> Duration {
+        duration_from_16dot16(self.0.whole)
+    }
+}
+
+/// Application-defined markers stamped into every commit.
+///
+/// The markers are committed atomically with the data, so they can record e.g. replication
+/// watermarks of the data in the same snapshot. Each read-only transaction sees the markers of
+/// its own snapshot.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Canary {
+    pub x: u64,
+    pub y: u64,
+    pub z: u64,
+    /// Set by MDBX to the id of the transaction which last changed the markers.
+    pub v: u64,
+}
+
+/// Transaction information.
+#[derive(Clone, Copy, Debug)]
+#[repr(transparent)]
+pub struct TxnInfo(ffi::MDBX_txn_info);
+
+impl TxnInfo {
+    /// The id of the transaction. For read-only transactions, this is the snapshot being read.
+    #[inline]
+    pub fn id(&self) -> u64 {
+        self.0.txn_id
+    }
+
+    /// For read-only transactions, the number of write transactions committed since the snapshot
+    /// was taken. For read-write transactions (only with `scan_rlt`), the lag of the oldest
+    /// reader.
+    #[inline]
+    pub fn reader_lag(&self) -> u64 {
+        self.0.txn_reader_lag
+    }
+
+    /// Space in bytes used by this transaction, up to the last used page.
+    #[inline]
+    pub fn space_used(&self) -> u64 {
+        self.0.txn_space_used
+    }
+
+    /// Current size of the database file in bytes.
+    #[inline]
+    pub fn space_limit_soft(&self) -> u64 {
+        self.0.txn_space_limit_soft
+    }
+
+    /// Upper bound of the database file size in bytes.
+    #[inline]
+    pub fn space_limit_hard(&self) -> u64 {
+        self.0.txn_space_limit_hard
+    }
+
+    /// For read-only transactions, the size of pages retired by writers since the snapshot was
+    /// taken. For read-write transactions, the size of pages retired so far by copy-on-write.
+    #[inline]
+    pub fn space_retired(&self) -> u64 {
+        self.0.txn_space_retired
+    }
+
+    /// For read-only transactions, the space writers may consume before this reader is treated
+    /// as a straggler. For read-write transactions, the space left before [Error::TxnFull].
+    #[inline]
+    pub fn space_leftover(&self) -> u64 {
+        self.0.txn_space_leftover
+    }
+
+    /// For read-only transactions (only with `scan_rlt`), the space reclaimed once this
+    /// transaction finishes. For read-write transactions, the size of dirty pages.
+    #[inline]
+    pub fn space_dirty(&self) -> u64 {
+        self.0.txn_space_dirty
+    }
+}
+
+/// Converts MDBX's 16.16 fixed-point seconds to a [Duration].
+pub(crate) fn duration_from_16dot16(value: u32) -> Duration {
+    Duration::from_nanos((u64::from(value) * 1_000_000_000) >> 16)
+}
+
 impl<'db, K, E> fmt::Debug for Transaction<'db, K, E>
 where
     K: TransactionKind,
@@ -445,26 +1484,27 @@ where
     E: DatabaseKind,
 {
     fn drop(&mut self) {
-        txn_execute(&self.txn, |txn| {
-            if !self.committed {
-                if K::ONLY_CLEAN {
-                    unsafe {
-                        ffi::mdbx_txn_abort(txn);
-                    }
-                } else {
-                    let (sender, rx) = sync_channel(0);
-                    self.db
-                        .txn_manager
-                        .as_ref()
-                        .unwrap()
-                        .send(TxnManagerMessage::Abort {
-                            tx: TxnPtr(txn),
-                            sender,
-                        })
-                        .unwrap();
-                    rx.recv().unwrap().unwrap();
+        let mut txnlck = self.txn.lock();
+        if !self.committed {
+            txnlck.close_cursors();
+            let txn = txnlck.ptr.0;
+            if K::ONLY_CLEAN {
+                unsafe {
+                    ffi::mdbx_txn_abort(txn);
                 }
+            } else {
+                let (sender, rx) = sync_channel(0);
+                self.db
+                    .txn_manager
+                    .as_ref()
+                    .unwrap()
+                    .send(TxnManagerMessage::Abort {
+                        tx: TxnPtr(txn),
+                        sender,
+                    })
+                    .unwrap();
+                rx.recv().unwrap().unwrap();
             }
-        })
+        }
     }
 }
@@ -1,5 +1,5 @@
 use libmdbx::*;
-use std::borrow::Cow;
+use std::{borrow::Cow, ops::Bound};
 use tempfile::tempdir;
 
 type Database = libmdbx::Database<NoWriteMap>;
@@ -338,6 +338,157 @@ fn test_iter_dup() {
     assert_eq!(0, cursor.iter_dup_of::<(), ()>(b"foo").count());
 }
 
+#[test]
+fn test_iter_dup_of_missing_key() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let dup_table = txn.create_table(Some("dup"), TableFlags::DUP_SORT).unwrap();
+    let plain_table = txn
+        .create_table(Some("plain"), TableFlags::empty())
+        .unwrap();
+    for key in [b"a", b"c", b"e"] {
+        for value in [b"1", b"2", b"3"] {
+            txn.put(&dup_table, key, value, WriteFlags::empty())
+                .unwrap();
+        }
+        txn.put(&plain_table, key, b"1", WriteFlags::empty())
+            .unwrap();
+    }
+
+    for table in [&dup_table, &plain_table] {
+        let mut cursor = txn.cursor(table).unwrap();
+        for missing in [b"0", b"b", b"d", b"z"] {
+            cursor.set::<()>(b"c").unwrap();
+            let iter = cursor.iter_dup_of::<(), ()>(missing);
+            assert_eq!(iter.size_hint(), (0, Some(0)));
+            assert_eq!(iter.count(), 0);
+
+            cursor.set::<()>(b"c").unwrap();
+            let iter = cursor.clone().into_iter_dup_of::<(), ()>(missing);
+            assert_eq!(iter.count(), 0);
+        }
+    }
+}
+
+#[test]
+fn test_iter_keys_values() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.create_table(None, TableFlags::DUP_SORT).unwrap();
+    for key in [b"a", b"b", b"c", b"d"] {
+        for value in [b"1", b"2", b"3"] {
+            txn.put(&table, key, value, WriteFlags::empty()).unwrap();
+        }
+    }
+
+    let mut cursor = txn.cursor(&table).unwrap();
+    let items = cursor
+        .iter_start::<[u8; 1], [u8; 1]>()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+
+    let keys = cursor
+        .iter_keys_start::<[u8; 1]>()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(keys, items.iter().map(|(k, _)| *k).collect::<Vec<_>>());
+    let values = cursor
+        .iter_values_start::<[u8; 1]>()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(values, items.iter().map(|(_, v)| *v).collect::<Vec<_>>());
+
+    let keys = cursor
+        .iter_keys_from::<[u8; 1]>(b"c")
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(keys, vec![*b"c", *b"c", *b"c", *b"d", *b"d", *b"d"]);
+    let values = cursor
+        .iter_values_from::<[u8; 1]>(b"d")
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(values, vec![*b"1", *b"2", *b"3"]);
+
+    let unique = cursor
+        .iter_unique_keys_start::<[u8; 1]>()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(unique, vec![*b"a", *b"b", *b"c", *b"d"]);
+    let unique = cursor
+        .iter_unique_keys_from::<[u8; 1]>(b"bb")
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(unique, vec![*b"c", *b"d"]);
+
+    cursor.set::<()>(b"b").unwrap();
+    let unique = cursor
+        .iter_unique_keys::<[u8; 1]>()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(unique, vec![*b"c", *b"d"]);
+    cursor.set::<()>(b"b").unwrap();
+    let keys = cursor
+        .iter_keys::<[u8; 1]>()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(keys.len(), 8);
+    cursor.set::<()>(b"c").unwrap();
+    let values = cursor
+        .iter_values::<[u8; 1]>()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(values, vec![*b"2", *b"3", *b"1", *b"2", *b"3"]);
+}
+
+#[test]
+fn test_set_key_range() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    for (k, v) in [(b"key1", b"val1"), (b"key3", b"val3")] {
+        txn.put(&table, k, v, WriteFlags::empty()).unwrap();
+    }
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    let mut cursor = txn.cursor(&table).unwrap();
+
+    assert_eq!(cursor.set_key(b"key1").unwrap(), Some((*b"key1", *b"val1")));
+    assert_eq!(cursor.set_key::<(), ()>(b"key2").unwrap(), None);
+    assert_eq!(
+        cursor.set_range(b"key1").unwrap(),
+        Some((*b"key1", *b"val1"))
+    );
+    assert_eq!(
+        cursor.set_range(b"key2").unwrap(),
+        Some((*b"key3", *b"val3"))
+    );
+    assert_eq!(cursor.set_range::<(), ()>(b"key4").unwrap(), None);
+
+    // Keys borrowed from the table itself are returned as well.
+    let (key, _) = cursor.last::<Cow<[u8]>, ()>().unwrap().unwrap();
+    assert_eq!(cursor.set_key(&key).unwrap(), Some((*b"key3", *b"val3")));
+    assert_eq!(cursor.set_range(&key).unwrap(), Some((*b"key3", *b"val3")));
+    assert_eq!(
+        cursor.set_lowerbound(&key, None).unwrap(),
+        Some((false, *b"key3", *b"val3"))
+    );
+}
+
 #[test]
 fn test_iter_del_get() {
     let dir = tempdir().unwrap();
@@ -433,3 +584,1178 @@ fn test_put_del() {
         )
     );
 }
+
+#[test]
+fn test_reserve() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.create_table(None, TableFlags::empty()).unwrap();
+    let dup_table = txn.create_table(Some("dup"), TableFlags::DUP_SORT).unwrap();
+    {
+        let mut cursor = txn.cursor(&table).unwrap();
+        let buf = cursor.reserve(b"key1", 4, WriteFlags::empty()).unwrap();
+        buf.copy_from_slice(b"val1");
+        assert_eq!(cursor.get_current().unwrap(), Some((*b"key1", *b"val1")));
+
+        let mut dup_cursor = txn.cursor(&dup_table).unwrap();
+        assert!(matches!(
+            dup_cursor.reserve(b"key1", 4, WriteFlags::empty()),
+            Err(Error::Incompatible)
+        ));
+    }
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    assert_eq!(txn.get::<[u8; 4]>(&table, b"key1").unwrap(), Some(*b"val1"));
+}
+
+#[test]
+fn test_put_multiple() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let values = (0..10_000u32)
+        .flat_map(u32::to_be_bytes)
+        .collect::<Vec<_>>();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_table(Some("fixed"), TableFlags::DUP_SORT | TableFlags::DUP_FIXED)
+        .unwrap();
+    let plain_table = txn
+        .create_table(Some("plain"), TableFlags::empty())
+        .unwrap();
+    {
+        let mut cursor = txn.cursor(&table).unwrap();
+        assert!(matches!(
+            cursor.put_multiple(b"key", &values[..6], 4, WriteFlags::empty()),
+            Err(Error::UnalignedValues {
+                value_size: 4,
+                len: 6
+            })
+        ));
+        assert_eq!(
+            cursor
+                .put_multiple(b"key", &values, 4, WriteFlags::empty())
+                .unwrap(),
+            10_000
+        );
+
+        let mut plain_cursor = txn.cursor(&plain_table).unwrap();
+        assert!(matches!(
+            plain_cursor.put_multiple(b"key", &values, 4, WriteFlags::empty()),
+            Err(Error::Incompatible)
+        ));
+    }
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(Some("fixed")).unwrap();
+    let mut cursor = txn.cursor(&table).unwrap();
+    cursor.set::<()>(b"key").unwrap();
+    assert_eq!(cursor.count().unwrap(), 10_000);
+    let stored = cursor
+        .iter_dup_of::<(), [u8; 4]>(b"key")
+        .map(|item| u32::from_be_bytes(item.unwrap().1))
+        .collect::<Vec<_>>();
+    assert_eq!(stored, (0..10_000).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_iter_dup_fixed_of() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let values = (0..100_000u64)
+        .flat_map(u64::to_be_bytes)
+        .collect::<Vec<_>>();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_table(None, TableFlags::DUP_SORT | TableFlags::DUP_FIXED)
+        .unwrap();
+    {
+        let mut cursor = txn.cursor(&table).unwrap();
+        cursor
+            .put(b"a", &7u64.to_be_bytes(), WriteFlags::empty())
+            .unwrap();
+        assert_eq!(
+            cursor
+                .put_multiple(b"b", &values, 8, WriteFlags::empty())
+                .unwrap(),
+            100_000
+        );
+        cursor
+            .put(b"c", &9u64.to_be_bytes(), WriteFlags::empty())
+            .unwrap();
+    }
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    let mut cursor = txn.cursor(&table).unwrap();
+
+    let fixed = cursor
+        .iter_dup_fixed_of::<[u8; 8]>(b"b")
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    let one_by_one = cursor
+        .iter_dup_of::<(), [u8; 8]>(b"b")
+        .map(|item| item.map(|(_, value)| value))
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(fixed.len(), 100_000);
+    assert_eq!(fixed, one_by_one);
+    assert_eq!(fixed.concat(), values);
+
+    let single = cursor
+        .iter_dup_fixed_of::<[u8; 8]>(b"c")
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(single, vec![9u64.to_be_bytes()]);
+
+    assert_eq!(cursor.iter_dup_fixed_of::<[u8; 8]>(b"absent").count(), 0);
+}
+
+#[test]
+fn test_try_clone_rw() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    for i in 0..10u8 {
+        txn.put(&table, [i], [i], WriteFlags::empty()).unwrap();
+    }
+
+    let mut writer = txn.cursor(&table).unwrap();
+    writer.set::<()>(&[3]).unwrap();
+    let mut fork = writer.try_clone().unwrap();
+
+    // Moving the writer leaves the fork in place.
+    assert_eq!(writer.next().unwrap(), Some(([4], [4])));
+    assert_eq!(fork.get_current().unwrap(), Some(([3], [3])));
+
+    // Deletions through the writer are visible to the fork.
+    writer.del(WriteFlags::empty()).unwrap();
+    assert_eq!(writer.get_current().unwrap(), Some(([5], [5])));
+    assert_eq!(
+        fork.iter::<[u8; 1], [u8; 1]>()
+            .map(|item| item.unwrap().0[0])
+            .collect::<Vec<_>>(),
+        vec![5, 6, 7, 8, 9]
+    );
+
+    // Deleting the item the fork is positioned at moves it onto the next one.
+    fork.set::<()>(&[7]).unwrap();
+    writer.set::<()>(&[7]).unwrap();
+    writer.del(WriteFlags::empty()).unwrap();
+    assert_eq!(fork.get_current().unwrap(), Some(([8], [8])));
+    assert_eq!(fork.next().unwrap(), Some(([9], [9])));
+}
+
+#[test]
+fn test_unbound_cursor() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(1),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let mut unbound = UnboundCursor::new().unwrap();
+    for i in 0..3u8 {
+        let txn = db.begin_rw_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        txn.put(&table, [i], [i], WriteFlags::empty()).unwrap();
+        txn.commit().unwrap();
+
+        // The bound cursor borrows the transaction, so it has to be unbound before the
+        // transaction can end.
+        let txn = db.begin_ro_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        let mut cursor = unbound.bind(&txn, &table).unwrap();
+        assert_eq!(cursor.get_current::<(), ()>().unwrap(), None);
+        assert_eq!(
+            cursor
+                .iter_keys_start::<[u8; 1]>()
+                .map(|key| key.unwrap()[0])
+                .collect::<Vec<_>>(),
+            (0..=i).collect::<Vec<_>>()
+        );
+        unbound = cursor.unbind();
+    }
+
+    // Cursors opened by a transaction can be reused as well, also for another table.
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    let other = txn
+        .create_table(Some("other"), TableFlags::empty())
+        .unwrap();
+    txn.put(&other, b"key", b"val", WriteFlags::empty())
+        .unwrap();
+    let cursor = txn.cursor(&table).unwrap();
+    let mut cursor = cursor.unbind().bind(&txn, &other).unwrap();
+    assert_eq!(cursor.first().unwrap(), Some((*b"key", *b"val")));
+    let unbound = cursor.unbind();
+    txn.commit().unwrap();
+    drop(unbound);
+}
+
+#[test]
+fn test_iter_cancelled() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    for i in 0..100u32 {
+        txn.put(&table, i.to_be_bytes(), [], WriteFlags::empty())
+            .unwrap();
+    }
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    let token = CancellationToken::new();
+    let mut iter = token.wrap(txn.cursor(&table).unwrap().into_iter_start::<[u8; 4], ()>());
+
+    for i in 0..10u32 {
+        assert_eq!(iter.next().unwrap().unwrap(), (i.to_be_bytes(), ()));
+    }
+
+    token.clone().cancel();
+    assert!(token.is_cancelled());
+    assert!(matches!(
+        iter.next(),
+        Some(Err(Error::Cancelled { progress: 10 }))
+    ));
+    assert!(iter.next().is_none());
+}
+
+#[test]
+fn test_append() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.create_table(None, TableFlags::DUP_SORT).unwrap();
+    let mut cursor = txn.cursor(&table).unwrap();
+
+    cursor.append(b"key1", b"val1").unwrap();
+    cursor.append_dup(b"key1", b"val2").unwrap();
+    cursor.append(b"key2", b"val1").unwrap();
+    assert!(matches!(
+        cursor.append(b"key0", b"val1"),
+        Err(Error::KeyMismatch)
+    ));
+    assert!(matches!(
+        cursor.append_dup(b"key2", b"val0"),
+        Err(Error::KeyMismatch)
+    ));
+
+    let items = cursor
+        .iter_start::<[u8; 4], [u8; 4]>()
+        .collect::<Result<Vec<_>>>()
+        .unwrap();
+    assert_eq!(
+        items,
+        vec![
+            (*b"key1", *b"val1"),
+            (*b"key1", *b"val2"),
+            (*b"key2", *b"val1")
+        ]
+    );
+}
+
+#[test]
+fn test_count() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.create_table(Some("dup"), TableFlags::DUP_SORT).unwrap();
+    for (key, val) in [
+        (b"key1", b"val1"),
+        (b"key1", b"val2"),
+        (b"key1", b"val3"),
+        (b"key2", b"val1"),
+    ] {
+        txn.put(&table, key, val, WriteFlags::empty()).unwrap();
+    }
+    let plain_table = txn
+        .create_table(Some("plain"), TableFlags::empty())
+        .unwrap();
+    txn.put(&plain_table, b"key1", b"val1", WriteFlags::empty())
+        .unwrap();
+
+    let mut cursor = txn.cursor(&table).unwrap();
+    assert!(matches!(cursor.count(), Err(Error::NoData)));
+    cursor.first::<(), ()>().unwrap();
+    assert_eq!(cursor.count().unwrap(), 3);
+    cursor.next::<(), ()>().unwrap();
+    assert_eq!(cursor.count().unwrap(), 3);
+    cursor.next_nodup::<(), ()>().unwrap();
+    assert_eq!(cursor.count().unwrap(), 1);
+
+    let mut plain_cursor = txn.cursor(&plain_table).unwrap();
+    plain_cursor.first::<(), ()>().unwrap();
+    assert_eq!(plain_cursor.count().unwrap(), 1);
+    drop((cursor, plain_cursor));
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(Some("dup")).unwrap();
+    let mut cursor = txn.cursor(&table).unwrap();
+    assert!(matches!(cursor.count(), Err(Error::NoData)));
+    cursor.set::<()>(b"key1").unwrap();
+    assert_eq!(cursor.count().unwrap(), 3);
+}
+
+#[test]
+fn test_iter_rev() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let items = vec![
+        (*b"key1", *b"val1"),
+        (*b"key2", *b"val2"),
+        (*b"key3", *b"val3"),
+        (*b"key5", *b"val5"),
+    ];
+    let rev_items = items.iter().rev().copied().collect::<Vec<_>>();
+
+    {
+        let txn = db.begin_rw_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        for (key, data) in &items {
+            txn.put(&table, key, data, WriteFlags::empty()).unwrap();
+        }
+        assert!(!txn.commit().unwrap());
+    }
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    let mut cursor = txn.cursor(&table).unwrap();
+
+    assert_eq!(
+        rev_items,
+        cursor.iter_prev().collect::<Result<Vec<_>>>().unwrap()
+    );
+    assert_eq!(
+        rev_items,
+        cursor.iter_end().collect::<Result<Vec<_>>>().unwrap()
+    );
+
+    cursor.set::<()>(b"key3").unwrap();
+    assert_eq!(
+        rev_items.clone().into_iter().skip(2).collect::<Vec<_>>(),
+        cursor.iter_prev().collect::<Result<Vec<_>>>().unwrap()
+    );
+
+    assert_eq!(
+        rev_items.clone().into_iter().skip(2).collect::<Vec<_>>(),
+        cursor
+            .iter_from_rev(b"key2")
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    );
+    assert_eq!(
+        rev_items.clone().into_iter().skip(1).collect::<Vec<_>>(),
+        cursor
+            .iter_from_rev(b"key4")
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    );
+    assert_eq!(
+        rev_items,
+        cursor
+            .iter_from_rev(b"key6")
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    );
+    assert_eq!(
+        Vec::<((), ())>::new(),
+        cursor
+            .iter_from_rev(b"key0")
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    );
+}
+
+#[test]
+fn test_iter_rev_empty_database() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    let mut cursor = txn.cursor(&table).unwrap();
+
+    assert!(cursor.iter_prev::<(), ()>().next().is_none());
+    assert!(cursor.iter_end::<(), ()>().next().is_none());
+    assert!(cursor.iter_from_rev::<(), ()>(b"foo").next().is_none());
+}
+
+#[test]
+fn test_iter_rev_dup() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.create_table(None, TableFlags::DUP_SORT).unwrap();
+    for key in [b"key1", b"key2", b"key3"] {
+        for val in [b"val1", b"val2"] {
+            txn.put(&table, key, val, WriteFlags::empty()).unwrap();
+        }
+    }
+
+    let mut cursor = txn.cursor(&table).unwrap();
+    assert_eq!(
+        cursor
+            .iter_end::<[u8; 4], [u8; 4]>()
+            .collect::<Result<Vec<_>>>()
+            .unwrap(),
+        vec![
+            (*b"key3", *b"val2"),
+            (*b"key3", *b"val1"),
+            (*b"key2", *b"val2"),
+            (*b"key2", *b"val1"),
+            (*b"key1", *b"val2"),
+            (*b"key1", *b"val1"),
+        ]
+    );
+    assert_eq!(
+        cursor
+            .iter_from_rev::<[u8; 4], [u8; 4]>(b"key2")
+            .collect::<Result<Vec<_>>>()
+            .unwrap(),
+        vec![
+            (*b"key2", *b"val2"),
+            (*b"key2", *b"val1"),
+            (*b"key1", *b"val2"),
+            (*b"key1", *b"val1"),
+        ]
+    );
+}
+
+#[test]
+fn test_iter_double_ended() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let items = vec![
+        (*b"key1", *b"val1"),
+        (*b"key2", *b"val2"),
+        (*b"key3", *b"val3"),
+        (*b"key5", *b"val5"),
+    ];
+    let rev_items = items.iter().rev().copied().collect::<Vec<_>>();
+
+    {
+        let txn = db.begin_rw_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        for (key, data) in &items {
+            txn.put(&table, key, data, WriteFlags::empty()).unwrap();
+        }
+        txn.commit().unwrap();
+    }
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    let mut cursor = txn.cursor(&table).unwrap();
+
+    assert_eq!(
+        rev_items,
+        cursor
+            .iter_start()
+            .rev()
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    );
+    assert_eq!(
+        items,
+        cursor.iter_end().rev().collect::<Result<Vec<_>>>().unwrap()
+    );
+    assert_eq!(
+        rev_items[..2],
+        cursor
+            .iter_from(b"key2")
+            .rev()
+            .take(2)
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    );
+    assert_eq!(
+        rev_items[..3],
+        cursor
+            .iter_from(b"key2")
+            .rev()
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    );
+    assert_eq!(
+        Vec::<((), ())>::new(),
+        cursor
+            .iter_from(b"key6")
+            .rev()
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    );
+    assert_eq!(
+        rev_items,
+        txn.cursor(&table)
+            .unwrap()
+            .into_iter_start()
+            .rev()
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    );
+
+    // Interleaved calls never yield an item twice, whichever end reaches the middle first.
+    let mut iter = cursor.iter_start::<[u8; 4], [u8; 4]>();
+    assert_eq!(iter.next().unwrap().unwrap(), items[0]);
+    assert_eq!(iter.next_back().unwrap().unwrap(), items[3]);
+    assert_eq!(iter.next().unwrap().unwrap(), items[1]);
+    assert_eq!(iter.next_back().unwrap().unwrap(), items[2]);
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+
+    let mut iter = cursor.iter_start::<[u8; 4], [u8; 4]>();
+    assert_eq!(iter.next_back().unwrap().unwrap(), items[3]);
+    assert_eq!(iter.next_back().unwrap().unwrap(), items[2]);
+    assert_eq!(iter.next_back().unwrap().unwrap(), items[1]);
+    assert_eq!(iter.next().unwrap().unwrap(), items[0]);
+    assert!(iter.next_back().is_none());
+    assert!(iter.next().is_none());
+
+    let mut iter = cursor.iter_from::<[u8; 4], [u8; 4]>(b"key2");
+    assert_eq!(iter.next_back().unwrap().unwrap(), items[3]);
+    assert_eq!(iter.next().unwrap().unwrap(), items[1]);
+    assert_eq!(iter.next_back().unwrap().unwrap(), items[2]);
+    assert!(iter.next().is_none());
+    assert!(iter.next_back().is_none());
+}
+
+#[test]
+fn test_iter_double_ended_dup() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.create_table(None, TableFlags::DUP_SORT).unwrap();
+    let items = [b"key1", b"key2", b"key3"]
+        .into_iter()
+        .flat_map(|key| [(*key, *b"val1"), (*key, *b"val2"), (*key, *b"val3")])
+        .collect::<Vec<_>>();
+    for (key, val) in &items {
+        txn.put(&table, key, val, WriteFlags::empty()).unwrap();
+    }
+
+    let mut cursor = txn.cursor(&table).unwrap();
+    assert_eq!(
+        items.iter().rev().copied().collect::<Vec<_>>(),
+        cursor
+            .iter_start()
+            .rev()
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    );
+    assert_eq!(
+        items[3..6]
+            .iter()
+            .rev()
+            .map(|(_, v)| ((), *v))
+            .collect::<Vec<_>>(),
+        cursor
+            .iter_dup_of(b"key2")
+            .rev()
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    );
+    assert!(cursor.iter_dup_of::<(), ()>(b"key9").next_back().is_none());
+
+    let mut iter = cursor.iter_dup_of::<[u8; 4], [u8; 4]>(b"key3");
+    assert_eq!(iter.next().unwrap().unwrap(), items[6]);
+    assert_eq!(iter.next_back().unwrap().unwrap(), items[8]);
+    assert_eq!(iter.next().unwrap().unwrap(), items[7]);
+    assert!(iter.next_back().is_none());
+    assert!(iter.next().is_none());
+
+    let mut iter = cursor.iter_start::<[u8; 4], [u8; 4]>();
+    let mut seen = Vec::new();
+    for i in 0.. {
+        let item = if i % 3 == 0 {
+            iter.next_back()
+        } else {
+            iter.next()
+        };
+        match item {
+            Some(item) => seen.push(item.unwrap()),
+            None => break,
+        }
+    }
+    seen.sort();
+    assert_eq!(items, seen);
+}
+
+#[test]
+fn test_iter_size_hint() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.create_table(None, TableFlags::DUP_SORT).unwrap();
+    for i in 0..100_000u32 {
+        txn.put(
+            &table,
+            (i / 10).to_be_bytes(),
+            i.to_be_bytes(),
+            WriteFlags::empty(),
+        )
+        .unwrap();
+    }
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    let mut cursor = txn.cursor(&table).unwrap();
+
+    let mut iter = cursor.iter_start::<(), ()>();
+    assert_eq!(iter.size_hint(), (100_000, Some(100_000)));
+    iter.next().unwrap().unwrap();
+    iter.next_back().unwrap().unwrap();
+    assert_eq!(iter.size_hint(), (99_998, Some(99_998)));
+    assert_eq!(iter.count(), 99_998);
+
+    let iter = txn.cursor(&table).unwrap().into_iter_start::<(), ()>();
+    assert_eq!(iter.size_hint(), (100_000, Some(100_000)));
+    assert_eq!(iter.collect::<Result<Vec<_>>>().unwrap().len(), 100_000);
+
+    assert_eq!(
+        cursor.iter_end::<(), ()>().size_hint(),
+        (100_000, Some(100_000))
+    );
+
+    let mut iter = cursor.iter_dup_of::<(), ()>(&42u32.to_be_bytes());
+    assert_eq!(iter.size_hint(), (10, Some(10)));
+    iter.next().unwrap().unwrap();
+    assert_eq!(iter.size_hint(), (9, Some(9)));
+    assert_eq!(iter.count(), 9);
+
+    // Ranges of keys are only bounded by the number of items in the table.
+    let mut iter = cursor.iter_from::<(), ()>(&5_000u32.to_be_bytes());
+    assert_eq!(iter.size_hint(), (0, Some(100_000)));
+    iter.next().unwrap().unwrap();
+    assert_eq!(iter.size_hint(), (0, Some(99_999)));
+    assert_eq!(iter.by_ref().count(), 49_999);
+    assert_eq!(iter.size_hint(), (0, Some(0)));
+
+    let (start, end) = (100u32.to_be_bytes(), 300u32.to_be_bytes());
+    for (range, count) in [
+        ((Bound::Included(start), Bound::Excluded(end)), 2_000),
+        ((Bound::Unbounded, Bound::Excluded(end)), 3_000),
+        ((Bound::Included(start), Bound::Unbounded), 99_000),
+    ] {
+        let iter = cursor.iter_range::<(), (), _>(range);
+        assert_eq!(iter.size_hint(), (0, Some(100_000)));
+        assert_eq!(iter.count(), count);
+    }
+
+    let iter = cursor.iter_dup_range::<(), (), _>(&42u32.to_be_bytes(), 422u32.to_be_bytes()..);
+    assert_eq!(iter.size_hint(), (0, Some(10)));
+    assert_eq!(iter.count(), 8);
+
+    // Only the ends of exact iterations in read-only transactions are known.
+    assert_eq!(cursor.iter_start::<(), ()>().len(), 100_000);
+    assert_eq!(cursor.iter_dup_of::<(), ()>(&42u32.to_be_bytes()).len(), 10);
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    let mut cursor = txn.cursor(&table).unwrap();
+    assert_eq!(cursor.iter_start::<(), ()>().size_hint(), (0, None));
+    assert_eq!(
+        cursor
+            .iter_range::<(), (), _>(1_000u32.to_be_bytes()..)
+            .size_hint(),
+        (0, None)
+    );
+}
+
+#[test]
+fn test_iter_range() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let items = vec![
+        (*b"key1", *b"val1"),
+        (*b"key2", *b"val2"),
+        (*b"key3", *b"val3"),
+        (*b"key5", *b"val5"),
+    ];
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    for (key, data) in &items {
+        txn.put(&table, key, data, WriteFlags::empty()).unwrap();
+    }
+
+    let mut cursor = txn.cursor(&table).unwrap();
+    let mut range = |range: (Bound<&[u8]>, Bound<&[u8]>)| {
+        cursor
+            .iter_range::<[u8; 4], [u8; 4], [u8]>(range)
+            .collect::<Result<Vec<_>>>()
+            .unwrap()
+    };
+
+    use Bound::*;
+    assert_eq!(range((Unbounded, Unbounded)), items);
+    assert_eq!(range((Included(b"key2"), Excluded(b"key5"))), items[1..3]);
+    assert_eq!(range((Included(b"key2"), Included(b"key5"))), items[1..4]);
+    assert_eq!(range((Excluded(b"key2"), Included(b"key5"))), items[2..4]);
+    assert_eq!(range((Excluded(b"key2"), Excluded(b"key5"))), items[2..3]);
+    assert_eq!(range((Excluded(b"key4"), Unbounded)), items[3..]);
+    assert_eq!(range((Included(b"key0"), Included(b"key4"))), items[..3]);
+    assert_eq!(range((Unbounded, Included(b"key2"))), items[..2]);
+    assert_eq!(range((Unbounded, Excluded(b"key2"))), items[..1]);
+    assert!(range((Included(b"key4"), Excluded(b"key5"))).is_empty());
+    assert!(range((Excluded(b"key5"), Unbounded)).is_empty());
+    assert!(range((Included(b"key6"), Unbounded)).is_empty());
+    assert!(range((Included(b"key3"), Excluded(b"key3"))).is_empty());
+
+    assert_eq!(
+        cursor
+            .iter_range::<[u8; 4], [u8; 4], _>(*b"key2"..=*b"key3")
+            .collect::<Result<Vec<_>>>()
+            .unwrap(),
+        items[1..3]
+    );
+    assert_eq!(
+        cursor
+            .iter_range::<[u8; 4], [u8; 4], [u8; 4]>(..)
+            .collect::<Result<Vec<_>>>()
+            .unwrap(),
+        items
+    );
+}
+
+#[test]
+fn test_iter_range_dup_integer() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.create_table(Some("dup"), TableFlags::DUP_SORT).unwrap();
+    for key in [b"key1", b"key2", b"key3"] {
+        for val in [b"val1", b"val2"] {
+            txn.put(&table, key, val, WriteFlags::empty()).unwrap();
+        }
+    }
+    let mut cursor = txn.cursor(&table).unwrap();
+    assert_eq!(
+        cursor
+            .iter_range::<[u8; 4], [u8; 4], _>(*b"key2"..*b"key4")
+            .collect::<Result<Vec<_>>>()
+            .unwrap(),
+        vec![
+            (*b"key2", *b"val1"),
+            (*b"key2", *b"val2"),
+            (*b"key3", *b"val1"),
+            (*b"key3", *b"val2"),
+        ]
+    );
+    assert_eq!(
+        cursor
+            .iter_range::<[u8; 4], [u8; 4], _>((
+                Bound::Excluded(*b"key1"),
+                Bound::Excluded(*b"key3")
+            ))
+            .collect::<Result<Vec<_>>>()
+            .unwrap(),
+        vec![(*b"key2", *b"val1"), (*b"key2", *b"val2")]
+    );
+
+    // Integer keys are compared numerically rather than bytewise.
+    let table = txn
+        .create_integer_table(Some("int"), TableFlags::empty())
+        .unwrap();
+    for key in [1u64, 255, 256, 1000] {
+        table.put(&txn, key, [], WriteFlags::empty()).unwrap();
+    }
+    assert_eq!(
+        txn.cursor(&table)
+            .unwrap()
+            .iter_range::<[u8; 8], (), _>(2u64.to_ne_bytes()..=256u64.to_ne_bytes())
+            .map(|r| u64::from_ne_bytes(r.unwrap().0))
+            .collect::<Vec<_>>(),
+        vec![255, 256]
+    );
+}
+
+#[test]
+fn test_iter_dup_range() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.create_table(None, TableFlags::DUP_SORT).unwrap();
+    for key in [b"key1", b"key2", b"key3"] {
+        for val in [b"val1", b"val2", b"val3", b"val4"] {
+            txn.put(&table, key, val, WriteFlags::empty()).unwrap();
+        }
+    }
+    let mut cursor = txn.cursor(&table).unwrap();
+    let mut dup_range = |key: &[u8], range: (Bound<&[u8]>, Bound<&[u8]>)| {
+        cursor
+            .iter_dup_range::<[u8; 4], [u8; 4], [u8]>(key, range)
+            .map(|item| {
+                let (k, v) = item.unwrap();
+                assert_eq!(k, *b"key2");
+                v
+            })
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        dup_range(
+            b"key2",
+            (Bound::Included(b"val2"), Bound::Included(b"val3"))
+        ),
+        vec![*b"val2", *b"val3"]
+    );
+    assert_eq!(
+        dup_range(
+            b"key2",
+            (Bound::Excluded(b"val2"), Bound::Excluded(b"val4"))
+        ),
+        vec![*b"val3"]
+    );
+    assert_eq!(
+        dup_range(b"key2", (Bound::Excluded(b"val20"), Bound::Unbounded)),
+        vec![*b"val3", *b"val4"]
+    );
+    assert_eq!(
+        dup_range(b"key2", (Bound::Unbounded, Bound::Excluded(b"val2"))),
+        vec![*b"val1"]
+    );
+    assert_eq!(
+        dup_range(b"key2", (Bound::Included(b"val5"), Bound::Unbounded)),
+        Vec::<[u8; 4]>::new()
+    );
+    assert_eq!(
+        dup_range(b"key0", (Bound::Unbounded, Bound::Unbounded)),
+        Vec::<[u8; 4]>::new()
+    );
+    assert_eq!(
+        dup_range(b"key22", (Bound::Included(b"val1"), Bound::Unbounded)),
+        Vec::<[u8; 4]>::new()
+    );
+}
+
+#[test]
+fn test_get_not_found() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_table(None, TableFlags::DUP_SORT | TableFlags::DUP_FIXED)
+        .unwrap();
+
+    // Every positioning method reports a missing item as `None` rather than an error.
+    let mut cursor = txn.cursor(&table).unwrap();
+    assert_eq!(cursor.first::<(), ()>().unwrap(), None);
+    assert_eq!(cursor.last::<(), ()>().unwrap(), None);
+    assert_eq!(cursor.next::<(), ()>().unwrap(), None);
+    assert_eq!(cursor.prev::<(), ()>().unwrap(), None);
+    assert_eq!(cursor.next_nodup::<(), ()>().unwrap(), None);
+    assert_eq!(cursor.prev_nodup::<(), ()>().unwrap(), None);
+    assert_eq!(cursor.set::<()>(b"key1").unwrap(), None);
+    assert_eq!(cursor.set_key::<(), ()>(b"key1").unwrap(), None);
+    assert_eq!(cursor.set_range::<(), ()>(b"key1").unwrap(), None);
+    assert_eq!(
+        cursor.set_lowerbound::<(), ()>(b"key1", None).unwrap(),
+        None
+    );
+    assert_eq!(cursor.get_both::<()>(b"key1", b"val1").unwrap(), None);
+    assert_eq!(cursor.get_both_range::<()>(b"key1", b"val1").unwrap(), None);
+
+    for (k, v) in [
+        (b"key1", b"val1"),
+        (b"key1", b"val3"),
+        (b"key3", b"val1"),
+        (b"key3", b"val3"),
+    ] {
+        txn.put(&table, k, v, WriteFlags::empty()).unwrap();
+    }
+
+    let mut cursor = txn.cursor(&table).unwrap();
+    assert_eq!(
+        cursor.set_lowerbound(b"key1", Some(b"val1")).unwrap(),
+        Some((false, *b"key1", *b"val1"))
+    );
+    assert_eq!(
+        cursor.set_lowerbound(b"key1", Some(b"val2")).unwrap(),
+        Some((true, *b"key1", *b"val3"))
+    );
+    assert_eq!(
+        cursor.set_lowerbound(b"key2", None).unwrap(),
+        Some((true, *b"key3", *b"val1"))
+    );
+    assert_eq!(
+        cursor.set_lowerbound::<(), ()>(b"key4", None).unwrap(),
+        None
+    );
+
+    assert_eq!(cursor.set::<()>(b"key2").unwrap(), None);
+    assert_eq!(cursor.get_both::<()>(b"key1", b"val2").unwrap(), None);
+    assert_eq!(cursor.get_both_range::<()>(b"key3", b"val4").unwrap(), None);
+    assert_eq!(cursor.last::<(), ()>().unwrap(), Some(((), ())));
+    assert_eq!(cursor.next::<(), ()>().unwrap(), None);
+    assert_eq!(cursor.next_dup::<(), ()>().unwrap(), None);
+    assert_eq!(cursor.next_nodup::<(), ()>().unwrap(), None);
+    assert_eq!(cursor.first::<(), ()>().unwrap(), Some(((), ())));
+    assert_eq!(cursor.prev::<(), ()>().unwrap(), None);
+    assert_eq!(cursor.prev_dup::<(), ()>().unwrap(), None);
+    assert_eq!(cursor.prev_nodup::<(), ()>().unwrap(), None);
+
+    // All duplicates fit on a single page, so there is no previous page to return.
+    assert_eq!(cursor.last::<(), ()>().unwrap(), Some(((), ())));
+    assert_eq!(cursor.prev_multiple::<(), ()>().unwrap(), None);
+}
+
+#[test]
+fn test_cursor_pool() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_table(Some("items"), TableFlags::empty())
+        .unwrap();
+    let other = txn
+        .create_table(Some("other"), TableFlags::empty())
+        .unwrap();
+    let mut writer = txn.cursor(&table).unwrap();
+    let pool = txn.cursor_pool();
+
+    // Lookups through pooled cursors see the writes made through another cursor.
+    for i in 0..100u32 {
+        writer
+            .put(
+                &i.to_be_bytes(),
+                &(i * 2).to_be_bytes(),
+                WriteFlags::empty(),
+            )
+            .unwrap();
+        if i > 0 {
+            writer.del(WriteFlags::empty()).unwrap();
+            writer
+                .put(
+                    &i.to_be_bytes(),
+                    &(i * 3).to_be_bytes(),
+                    WriteFlags::empty(),
+                )
+                .unwrap();
+        }
+        let mut cursor = pool.cursor(&table).unwrap();
+        // Reused cursors are unpositioned.
+        assert_eq!(cursor.get_current::<(), ()>().unwrap(), None);
+        let value = cursor.set::<[u8; 4]>(&i.to_be_bytes()).unwrap();
+        let expected = if i > 0 { i * 3 } else { 0 };
+        assert_eq!(value, Some(expected.to_be_bytes()));
+        assert_eq!(
+            cursor.last::<[u8; 4], ()>().unwrap(),
+            Some((i.to_be_bytes(), ()))
+        );
+        drop(cursor);
+        assert_eq!(pool.idle(), 1);
+    }
+
+    // Cursors are pooled per table.
+    let first = pool.cursor(&table).unwrap();
+    let second = pool.cursor(&table).unwrap();
+    let mut cursor = pool.cursor(&other).unwrap();
+    assert_eq!(pool.idle(), 0);
+    assert_eq!(cursor.first::<(), ()>().unwrap(), None);
+    drop((first, second, cursor));
+    assert_eq!(pool.idle(), 3);
+    let cursor = pool.cursor(&other).unwrap();
+    assert_eq!(pool.idle(), 2);
+    drop(cursor);
+
+    drop(pool);
+    drop(writer);
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(Some("items")).unwrap();
+    let pool = txn.cursor_pool();
+    for i in 0..100u32 {
+        let mut cursor = pool.cursor(&table).unwrap();
+        assert!(cursor.set::<()>(&i.to_be_bytes()).unwrap().is_some());
+    }
+    assert_eq!(pool.idle(), 1);
+}
+
+#[test]
+fn test_cursors_closed_with_txn() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.create_table(None, TableFlags::DUP_SORT).unwrap();
+    for (key, value) in [(b"a", b"1"), (b"a", b"2"), (b"b", b"1")] {
+        txn.put(&table, key, value, WriteFlags::empty()).unwrap();
+    }
+    // Leaked cursors, including the copies made by IterDup, are closed on commit.
+    let mut cursor = txn.cursor(&table).unwrap();
+    let mut dups = cursor.iter_dup_start::<(), ()>();
+    std::mem::forget(dups.next().unwrap());
+    std::mem::forget(dups.next().unwrap());
+    std::mem::forget(cursor);
+    std::mem::forget(txn.cursor(&table).unwrap().try_clone().unwrap());
+    txn.commit().unwrap();
+
+    // And on abort.
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    let mut cursor = txn.cursor(&table).unwrap();
+    cursor.first::<(), ()>().unwrap();
+    std::mem::forget(cursor);
+    drop(txn);
+
+    for _ in 0..2 {
+        let txn = db.begin_ro_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        let mut cursor = txn.cursor(&table).unwrap();
+        assert_eq!(cursor.iter_dup_start::<(), ()>().count(), 2);
+        std::mem::forget(cursor);
+        drop(txn);
+    }
+
+    // Cursors which are dropped or unbound in time are not closed twice.
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    drop(txn.cursor(&table).unwrap());
+    let unbound = txn.cursor(&table).unwrap().unbind();
+    let cursor = txn.cursor(&table).unwrap();
+    std::mem::forget(cursor);
+    drop(txn);
+    drop(unbound);
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    assert_eq!(txn.len(&table).unwrap(), 3);
+}
+
+#[test]
+fn test_iter_err_once() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            mode: Mode::ReadWrite(ReadWriteOptions {
+                min_size: Some(64 << 10),
+                max_size: Some(1 << 20),
+                growth_step: Some(64 << 10),
+                ..Default::default()
+            }),
+            max_tables: Some(1),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_table(Some("dup"), TableFlags::DUP_SORT | TableFlags::DUP_FIXED)
+        .unwrap();
+    for (key, value) in [(b"key1", b"val1"), (b"key1", b"val2"), (b"key2", b"val1")] {
+        txn.put(&table, key, value, WriteFlags::empty()).unwrap();
+    }
+
+    // Exhausted iterators stay exhausted, even when items are added after the end.
+    let mut cursor = txn.cursor(&table).unwrap();
+    let mut writer = txn.cursor(&table).unwrap();
+    let mut iter = cursor.iter_start::<Vec<u8>, Vec<u8>>();
+    assert_eq!(iter.by_ref().count(), 3);
+    writer.put(b"key3", b"val1", WriteFlags::empty()).unwrap();
+    assert!(iter.next().is_none());
+    let mut iter = cursor.iter_dup_start::<Vec<u8>, Vec<u8>>();
+    assert_eq!(iter.by_ref().count(), 3);
+    writer.put(b"key4", b"val1", WriteFlags::empty()).unwrap();
+    assert!(iter.next().is_none());
+
+    // A value larger than the map fails with MapFull and leaves the transaction unusable, so
+    // every read through the cursors fails from now on.
+    let mut cursors = (0..5)
+        .map(|_| txn.cursor(&table).unwrap())
+        .collect::<Vec<_>>();
+    let main = txn.open_table(None).unwrap();
+    assert!(matches!(
+        txn.put(&main, b"key5", vec![0; 2 << 20], WriteFlags::empty()),
+        Err(Error::MapFull)
+    ));
+
+    let items = cursors[0].iter_from::<(), ()>(b"key1").collect::<Vec<_>>();
+    assert!(matches!(items[..], [Err(Error::BadTxn)]), "{items:?}");
+    let items = cursors[1]
+        .iter_range::<(), (), _>(&b"key1"[..]..)
+        .collect::<Vec<_>>();
+    assert!(matches!(items[..], [Err(Error::BadTxn)]), "{items:?}");
+    let items = cursors[2]
+        .iter_dup_from::<(), ()>(b"key1")
+        .flatten()
+        .collect::<Vec<_>>();
+    assert!(matches!(items[..], [Err(Error::BadTxn)]), "{items:?}");
+    let items = cursors[3]
+        .iter_dup_fixed_of::<()>(b"key1")
+        .collect::<Vec<_>>();
+    assert!(matches!(items[..], [Err(Error::BadTxn)]), "{items:?}");
+    let items = cursors
+        .pop()
+        .unwrap()
+        .into_iter_from::<(), ()>(b"key1")
+        .collect::<Vec<_>>();
+    assert!(matches!(items[..], [Err(Error::BadTxn)]), "{items:?}");
+}
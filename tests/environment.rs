@@ -31,6 +31,30 @@ fn test_open() {
     .is_ok());
 }
 
+#[cfg(unix)]
+#[test]
+fn test_open_with_permissions() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = tempdir().unwrap();
+    let _db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            permissions: Some(0o600),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    for file in ["mdbx.dat", "mdbx.lck"] {
+        let mode = std::fs::metadata(dir.path().join(file))
+            .unwrap()
+            .permissions()
+            .mode();
+        assert_eq!(mode & 0o777, 0o600, "{file}");
+    }
+}
+
 #[test]
 fn test_begin_txn() {
     let dir = tempdir().unwrap();
@@ -59,6 +83,94 @@ fn test_begin_txn() {
     }
 }
 
+#[test]
+fn test_open_read_only() {
+    let dir = tempdir().unwrap();
+
+    // read-only mode never creates files
+    assert!(Database::open_read_only(&dir).is_err());
+    assert!(!dir.path().join("mdbx.dat").exists());
+
+    {
+        let db = Database::open(&dir).unwrap();
+        assert!(!db.is_read_only());
+        let txn = db.begin_rw_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        txn.put(&table, b"key", b"val", WriteFlags::empty())
+            .unwrap();
+        txn.commit().unwrap();
+    }
+
+    let db = Database::open_read_only(&dir).unwrap();
+    assert!(db.is_read_only());
+    assert!(matches!(db.begin_rw_txn(), Err(Error::Access)));
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    assert_eq!(txn.get(&table, b"key").unwrap(), Some(*b"val"));
+}
+
+#[test]
+fn test_read_only_kind() {
+    type ReadOnlyDatabase = libmdbx::Database<ReadOnly>;
+
+    let dir = tempdir().unwrap();
+    assert!(ReadOnlyDatabase::open(&dir).is_err());
+    assert!(!dir.path().join("mdbx.dat").exists());
+
+    let db = Database::open(&dir).unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    txn.put(&table, b"key", b"val", WriteFlags::empty())
+        .unwrap();
+    txn.commit().unwrap();
+    drop(db);
+
+    // Options asking for a read-write database are overridden.
+    let db = ReadOnlyDatabase::open_with_options(&dir, DatabaseOptions::default()).unwrap();
+    assert!(db.is_read_only());
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    assert_eq!(txn.get(&table, b"key").unwrap(), Some(*b"val"));
+}
+
+#[test]
+fn test_begin_rw_txn_with_timeout() {
+    use std::time::{Duration, Instant};
+
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            assert!(matches!(db.try_begin_rw_txn(), Err(Error::Busy)));
+
+            let start = Instant::now();
+            assert!(matches!(
+                db.begin_rw_txn_with_timeout(Duration::from_millis(200)),
+                Err(Error::Timeout)
+            ));
+            let elapsed = start.elapsed();
+            assert!(elapsed >= Duration::from_millis(200));
+            assert!(elapsed < Duration::from_secs(2));
+        })
+        .join()
+        .unwrap();
+    });
+    txn.commit().unwrap();
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            db.begin_rw_txn_with_timeout(Duration::from_millis(200))
+                .unwrap()
+                .commit()
+                .unwrap();
+            db.try_begin_rw_txn().unwrap().commit().unwrap();
+        });
+    });
+}
+
 #[test]
 fn test_open_table() {
     let dir = tempdir().unwrap();
@@ -131,6 +243,400 @@ fn test_sync() {
     }
 }
 
+#[test]
+fn test_sync_poll() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            mode: Mode::ReadWrite(ReadWriteOptions {
+                sync_mode: SyncMode::SafeNoSync,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    txn.put(&table, b"key", b"value", WriteFlags::empty())
+        .unwrap();
+    txn.commit().unwrap();
+    assert!(db.info().unwrap().unsync_volume() > 0);
+
+    // Polling does not wait for a running read-write transaction.
+    let txn = db.begin_rw_txn().unwrap();
+    assert!(matches!(db.sync_poll(), Err(Error::Busy)));
+    drop(txn);
+
+    assert!(!db.sync(true).unwrap());
+    assert_eq!(db.info().unwrap().unsync_volume(), 0);
+    assert!(db.sync(true).unwrap());
+    assert!(db.sync_poll().unwrap());
+}
+
+#[test]
+fn test_close() {
+    let dir = tempdir().unwrap();
+
+    let db = Database::open(&dir).unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    txn.put(&table, b"key", b"val", WriteFlags::empty())
+        .unwrap();
+    txn.commit().unwrap();
+    db.close(false).unwrap();
+
+    let db = Database::open(&dir).unwrap();
+    {
+        let txn = db.begin_ro_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        assert_eq!(txn.get(&table, b"key").unwrap(), Some(*b"val"));
+    }
+    db.close(true).unwrap();
+}
+
+#[test]
+fn test_user_context() {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+    assert!(db.get_user_context::<AtomicUsize>().is_none());
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    db.set_user_context(counter.clone()).unwrap();
+    assert_eq!(Arc::strong_count(&counter), 2);
+    assert!(db.get_user_context::<String>().is_none());
+
+    std::thread::scope(|s| {
+        s.spawn(|| {
+            db.get_user_context::<AtomicUsize>()
+                .unwrap()
+                .fetch_add(1, Ordering::SeqCst);
+        });
+    });
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+
+    // contexts of different types coexist
+    db.set_user_context(Arc::new(String::from("ctx"))).unwrap();
+    assert_eq!(Arc::strong_count(&counter), 2);
+    assert_eq!(*db.get_user_context::<String>().unwrap(), "ctx");
+    assert_eq!(
+        db.get_user_context::<AtomicUsize>()
+            .unwrap()
+            .load(Ordering::SeqCst),
+        1
+    );
+
+    // replacing a context releases the previous one of the same type
+    db.set_user_context(Arc::new(AtomicUsize::new(2))).unwrap();
+    assert_eq!(Arc::strong_count(&counter), 1);
+
+    let ctx = db.take_user_context::<String>().unwrap();
+    assert!(db.get_user_context::<String>().is_none());
+    assert!(db.take_user_context::<String>().is_none());
+    assert_eq!(Arc::strong_count(&ctx), 1);
+    assert_eq!(
+        db.get_user_context::<AtomicUsize>()
+            .unwrap()
+            .load(Ordering::SeqCst),
+        2
+    );
+
+    db.set_user_context(ctx.clone()).unwrap();
+    drop(db);
+    assert_eq!(Arc::strong_count(&ctx), 1);
+}
+
+#[test]
+fn test_dirty_page_options() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            txn_dp_limit: Some(2048),
+            txn_dp_initial: Some(4096),
+            spill_max_denominator: Some(0),
+            spill_min_denominator: Some(16),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    // The explicit limit wins over the larger initial size.
+    assert_eq!(db.get_option(MdbxOption::TxnDpLimit).unwrap(), 2048);
+    assert_eq!(db.get_option(MdbxOption::TxnDpInitial).unwrap(), 2048);
+    assert_eq!(db.get_option(MdbxOption::SpillMaxDenominator).unwrap(), 0);
+    assert_eq!(db.get_option(MdbxOption::SpillMinDenominator).unwrap(), 16);
+
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            txn_dp_initial: Some(100_000),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(db.get_option(MdbxOption::TxnDpInitial).unwrap(), 100_000);
+    assert_eq!(db.get_option(MdbxOption::TxnDpLimit).unwrap(), 100_000);
+}
+
+#[test]
+fn test_merge_options() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            merge_threshold_percent: Some(50.0),
+            rp_augment_limit: Some(1000),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(
+        db.get_option(MdbxOption::MergeThreshold16Dot16Percent)
+            .unwrap(),
+        32768
+    );
+    assert_eq!(db.get_option(MdbxOption::RpAugmentLimit).unwrap(), 1000);
+    drop(db);
+
+    for percent in [10.0, 60.0] {
+        assert!(matches!(
+            Database::open_with_options(
+                &dir,
+                DatabaseOptions {
+                    merge_threshold_percent: Some(percent),
+                    ..Default::default()
+                },
+            ),
+            Err(Error::InvalidValue)
+        ));
+    }
+}
+
+#[test]
+fn test_options() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_readers: Some(16),
+            extra_options: vec![(MdbxOption::TxnDpLimit, 128)],
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    // MDBX rounds the reader table up to fill the lock file pages
+    assert!(db.get_option(MdbxOption::MaxReaders).unwrap() >= 16);
+    assert_eq!(db.get_option(MdbxOption::TxnDpLimit).unwrap(), 128);
+
+    // With so few dirty pages allowed, a large transaction spills some to disk.
+    let write = |count: u32| {
+        let txn = db.begin_rw_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        for i in 0..count {
+            txn.put(&table, i.to_be_bytes(), [0; 64], WriteFlags::empty())
+                .unwrap();
+        }
+        txn.commit().unwrap();
+        db.info().unwrap().page_ops().spill
+    };
+    let spilled = write(20_000);
+    assert!(spilled > 0);
+
+    db.set_option(MdbxOption::TxnDpLimit, 65536).unwrap();
+    assert_eq!(db.get_option(MdbxOption::TxnDpLimit).unwrap(), 65536);
+    db.set_option(MdbxOption::SyncBytes, 1 << 20).unwrap();
+    assert_eq!(db.get_option(MdbxOption::SyncBytes).unwrap(), 1 << 20);
+    db.set_option(MdbxOption::SyncPeriod, 65536).unwrap();
+    assert_eq!(db.get_option(MdbxOption::SyncPeriod).unwrap(), 65536);
+    db.set_option(MdbxOption::RpAugmentLimit, 1000).unwrap();
+    assert_eq!(db.get_option(MdbxOption::RpAugmentLimit).unwrap(), 1000);
+
+    // With the limit raised, the same transaction fits in memory.
+    assert_eq!(write(20_000), spilled);
+    assert_eq!(db.stat().unwrap().entries(), 20_000);
+
+    assert!(matches!(
+        db.set_option(MdbxOption::MaxTables, 10),
+        Err(Error::Permission)
+    ));
+    assert!(matches!(
+        db.set_option(MdbxOption::TxnDpLimit, 0),
+        Err(Error::InvalidValue)
+    ));
+}
+
+#[test]
+fn test_check_readers() {
+    use std::{
+        env,
+        process::{Command, Stdio},
+    };
+
+    // In the child process, leave a read transaction behind by exiting without dropping it.
+    const READER_DIR: &str = "LIBMDBX_TEST_STALE_READER_DIR";
+    if let Some(dir) = env::var_os(READER_DIR) {
+        let db = Database::open(dir).unwrap();
+        let txn = db.begin_ro_txn().unwrap();
+        assert!(txn.id() > 0);
+        std::process::exit(0);
+    }
+
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+    assert_eq!(db.check_readers().unwrap(), 0);
+
+    let status = Command::new(env::current_exe().unwrap())
+        .args(["--exact", "test_check_readers"])
+        .env(READER_DIR, dir.path())
+        .stdout(Stdio::null())
+        .status()
+        .unwrap();
+    assert!(status.success());
+    assert_eq!(db.check_readers().unwrap(), 1);
+    assert_eq!(db.check_readers().unwrap(), 0);
+}
+
+#[test]
+fn test_slow_reader_handler() {
+    use std::{
+        sync::{mpsc, Arc, Mutex},
+        thread,
+    };
+
+    let dir = tempdir().unwrap();
+    let db = Arc::new(
+        Database::open_with_options(
+            &dir,
+            DatabaseOptions {
+                mode: Mode::ReadWrite(ReadWriteOptions {
+                    min_size: Some(1 << 20),
+                    max_size: Some(1 << 20),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        )
+        .unwrap(),
+    );
+    // Rewrites the same items, so the database only stays within its size by reusing pages.
+    let write = |db: &Database| -> Result<()> {
+        for n in 0..50 {
+            let txn = db.begin_rw_txn()?;
+            let table = txn.open_table(None)?;
+            for i in 0..100u32 {
+                txn.put(&table, i.to_be_bytes(), [n; 1024], WriteFlags::empty())?;
+            }
+            txn.commit()?;
+        }
+        Ok(())
+    };
+    write(&db).unwrap();
+
+    // The handler ends a reader running on another thread.
+    let (finish_tx, finish_rx) = mpsc::channel::<()>();
+    let (done_tx, done_rx) = mpsc::channel();
+    let reader = thread::spawn({
+        let db = db.clone();
+        move || {
+            let txn = db.begin_ro_txn().unwrap();
+            done_tx.send(txn.id()).unwrap();
+            finish_rx.recv().unwrap();
+            drop(txn);
+            done_tx.send(0).unwrap();
+        }
+    });
+    let reader_txnid = done_rx.recv().unwrap();
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let handler = {
+        let calls = calls.clone();
+        let done_rx = Mutex::new(done_rx);
+        move |reader: &SlowReader| {
+            calls.lock().unwrap().push(*reader);
+            finish_tx.send(()).unwrap();
+            done_rx.lock().unwrap().recv().unwrap();
+            SlowReaderAction::Retry
+        }
+    };
+    // Neither handler releases the slot of a reader.
+    unsafe { db.set_slow_reader_handler(handler) }.unwrap();
+    write(&db).unwrap();
+    reader.join().unwrap();
+    let calls = calls.lock().unwrap().clone();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].txnid, reader_txnid);
+    assert_eq!(calls[0].pid, std::process::id() as i32);
+    assert_eq!(calls[0].retry, 0);
+    assert!(calls[0].gap > 0);
+
+    // Giving up on the reader fails the write once the database is full.
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let handler = {
+        let calls = calls.clone();
+        move |reader: &SlowReader| {
+            calls.lock().unwrap().push(*reader);
+            SlowReaderAction::GiveUp
+        }
+    };
+    unsafe { db.set_slow_reader_handler(handler) }.unwrap();
+    let txn = db.begin_ro_txn().unwrap();
+    assert!(write(&db).unwrap_err().is_map_full());
+    assert!(!calls.lock().unwrap().is_empty());
+    assert!(calls
+        .lock()
+        .unwrap()
+        .iter()
+        .all(|reader| reader.txnid == txn.id()));
+    drop(txn);
+
+    db.clear_slow_reader_handler().unwrap();
+    write(&db).unwrap();
+}
+
+#[test]
+fn test_remove_files() {
+    let dir = tempdir().unwrap();
+
+    drop(Database::open(&dir).unwrap());
+    assert!(dir.path().join("mdbx.dat").exists());
+
+    assert!(Database::remove_files(&dir, DeleteMode::EnsureUnused).unwrap());
+    assert!(!dir.path().join("mdbx.dat").exists());
+    assert!(!dir.path().join("mdbx.lck").exists());
+
+    assert!(!Database::remove_files(&dir, DeleteMode::JustDelete).unwrap());
+}
+
+#[test]
+fn test_remove_files_in_use() {
+    let dir = tempdir().unwrap();
+
+    let db = Database::open(&dir).unwrap();
+    assert!(Database::remove_files(&dir, DeleteMode::EnsureUnused).is_err());
+    assert!(dir.path().join("mdbx.dat").exists());
+    drop(db);
+}
+
+#[test]
+fn test_remove_files_wait_for_unused() {
+    let dir = tempdir().unwrap();
+
+    let db = Database::open(&dir).unwrap();
+    let closer = std::thread::spawn(move || {
+        std::thread::sleep(std::time::Duration::from_millis(100));
+        drop(db);
+    });
+    assert!(Database::remove_files(&dir, DeleteMode::WaitForUnused).unwrap());
+    assert!(!dir.path().join("mdbx.dat").exists());
+    closer.join().unwrap();
+}
+
 #[test]
 fn test_stat() {
     let dir = tempdir().unwrap();
@@ -168,43 +674,1232 @@ fn test_stat() {
 }
 
 #[test]
-fn test_info() {
+fn test_all_stats() {
     let dir = tempdir().unwrap();
-    let db = Database::open(&dir).unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(4),
+            ..Default::default()
+        },
+    )
+    .unwrap();
 
-    let info = db.info().unwrap();
-    // assert_eq!(info.geometry().min(), map_size as u64);
-    // assert_eq!(info.last_pgno(), 1);
-    // assert_eq!(info.last_txnid(), 0);
-    assert_eq!(info.num_readers(), 0);
+    let txn = db.begin_rw_txn().unwrap();
+    let small = txn
+        .create_table(Some("small"), TableFlags::empty())
+        .unwrap();
+    for i in 0..10u32 {
+        txn.put(&small, i.to_be_bytes(), b"value", WriteFlags::empty())
+            .unwrap();
+    }
+    let big = txn.create_table(Some("big"), TableFlags::empty()).unwrap();
+    for i in 0..5u32 {
+        txn.put(&big, i.to_be_bytes(), [0; 10000], WriteFlags::empty())
+            .unwrap();
+    }
+    let dups = txn
+        .create_table(Some("dups"), TableFlags::DUP_SORT)
+        .unwrap();
+    for i in 0..1000u32 {
+        txn.put(
+            &dups,
+            (i % 10).to_be_bytes(),
+            i.to_be_bytes(),
+            WriteFlags::empty(),
+        )
+        .unwrap();
+    }
+    txn.commit().unwrap();
+    // Leave some pages to the GC.
+    let txn = db.begin_rw_txn().unwrap();
+    let big = txn.open_table(Some("big")).unwrap();
+    txn.del(&big, 0u32.to_be_bytes(), None).unwrap();
+    txn.commit().unwrap();
+
+    let stats = db.all_stats().unwrap();
+    let names = stats
+        .tables
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(names, ["big", "dups", "small"]);
+    assert_eq!(stats.main.entries(), 3);
+    assert!(stats.gc.entries() > 0);
+    assert_eq!(stats.table("dups").unwrap().entries(), 1000);
+    assert!(stats.table("missing").is_none());
+
+    let all = [&stats.main, &stats.gc]
+        .into_iter()
+        .chain(stats.tables.iter().map(|(_, stat)| stat))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        stats.pages(),
+        all.iter()
+            .map(|stat| stat.branch_pages() + stat.leaf_pages() + stat.overflow_pages())
+            .sum::<usize>()
+    );
+    assert_eq!(
+        stats.branch_pages(),
+        all.iter().map(|stat| stat.branch_pages()).sum::<usize>()
+    );
+    assert_eq!(
+        stats.leaf_pages(),
+        all.iter().map(|stat| stat.leaf_pages()).sum::<usize>()
+    );
+    assert_eq!(
+        stats.overflow_pages(),
+        all.iter().map(|stat| stat.overflow_pages()).sum::<usize>()
+    );
+    assert!(stats.overflow_pages() >= 4 * 3);
+    assert_eq!(stats.entries(), 3 + 10 + 4 + 1000);
+    assert_eq!(
+        stats.depth(),
+        all.iter().map(|stat| stat.depth()).max().unwrap()
+    );
+    assert!(stats.pages() <= stats.info.last_pgno() + 1);
+
+    let txn = db.begin_ro_txn().unwrap();
+    for (name, stat) in &stats.tables {
+        let table = txn.open_table(Some(name)).unwrap();
+        assert_eq!(txn.table_stat(&table).unwrap().entries(), stat.entries());
+    }
 }
 
 #[test]
-fn test_freelist() {
+fn test_stat_display() {
     let dir = tempdir().unwrap();
     let db = Database::open(&dir).unwrap();
+    let tx = db.begin_rw_txn().unwrap();
+    tx.put(
+        &tx.open_table(None).unwrap(),
+        b"key",
+        b"val",
+        WriteFlags::empty(),
+    )
+    .unwrap();
+    tx.commit().unwrap();
 
-    let mut freelist = db.freelist().unwrap();
-    assert_eq!(freelist, 0);
+    let stat = db.stat().unwrap();
+    let copy = stat;
+    assert_eq!(copy.entries(), stat.entries());
+    assert_eq!(
+        stat.to_string(),
+        format!(
+            "depth 1, 0 branch / 1 leaf / 0 overflow pages of {} bytes, 1 entries",
+            stat.page_size()
+        )
+    );
+    assert!(format!("{stat:?}").contains("entries: 1"));
 
-    // Write a few small values.
-    for i in 0..64_u64 {
-        let value = i.to_le_bytes();
-        let tx = db.begin_rw_txn().unwrap();
+    let info = db.info().unwrap();
+    assert!(info.to_string().starts_with("map size "));
+    assert!(format!("{info:?}").contains("last_txnid"));
+    assert_eq!(info.page_size(), stat.page_size());
+    assert!(info.page_ops().newly > 0);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_stat_serialize() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+    let tx = db.begin_rw_txn().unwrap();
+    let table = tx.open_table(None).unwrap();
+    for i in 0..10_u64 {
         tx.put(
-            &tx.open_table(None).unwrap(),
-            value,
-            value,
-            WriteFlags::default(),
+            &table,
+            i.to_be_bytes(),
+            i.to_be_bytes(),
+            WriteFlags::empty(),
         )
         .unwrap();
-        tx.commit().unwrap();
     }
-    let tx = db.begin_rw_txn().unwrap();
-    tx.clear_table(&tx.open_table(None).unwrap()).unwrap();
     tx.commit().unwrap();
 
-    // Freelist should not be empty after clear_table.
-    freelist = db.freelist().unwrap();
-    assert!(freelist > 0);
+    let stat = serde_json::to_value(db.stat().unwrap()).unwrap();
+    assert!(stat["page_size"].as_u64().unwrap().is_power_of_two());
+    assert_eq!(stat["depth"], 1);
+    assert_eq!(stat["branch_pages"], 0);
+    assert_eq!(stat["leaf_pages"], 1);
+    assert_eq!(stat["overflow_pages"], 0);
+    assert_eq!(stat["entries"], 10);
+    assert!(stat["mod_txnid"].as_u64().unwrap() > 0);
+
+    let stats = serde_json::to_value(db.all_stats().unwrap()).unwrap();
+    assert_eq!(stats["main"], stat);
+    assert!(stats["tables"].as_object().unwrap().is_empty());
+    let info = &stats["info"];
+    assert!(info["map_size"].as_u64().unwrap() >= info["geometry"]["current"].as_u64().unwrap());
+    assert!(info["last_txnid"].as_u64().unwrap() > 0);
+    assert!(info["since_sync_secs"].is_f64());
+    assert!(info["page_ops"]["newly"].as_u64().unwrap() > 0);
+}
+
+/// Environment variables of a child process started by [open_in_child].
+const CHILD_OPEN_DIR: &str = "LIBMDBX_TEST_CHILD_OPEN_DIR";
+const CHILD_OPEN_FLAG: &str = "LIBMDBX_TEST_CHILD_OPEN_FLAG";
+
+/// Open the database at `dir` in another process, as MDBX refuses to open a database twice in
+/// the same process, with the given `exclusive` or `accede` flag.
+fn open_in_child(test: &str, dir: &std::path::Path, flag: &str) -> Result<()> {
+    use std::process::{Command, Stdio};
+
+    let output = Command::new(std::env::current_exe().unwrap())
+        .args(["--exact", test, "--nocapture"])
+        .env(CHILD_OPEN_DIR, dir)
+        .env(CHILD_OPEN_FLAG, flag)
+        .stdout(Stdio::null())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    match String::from_utf8(output.stderr)
+        .unwrap()
+        .trim()
+        .parse()
+        .unwrap()
+    {
+        0 => Ok(()),
+        code => Err(Error::from_err_code(code)),
+    }
+}
+
+/// In the child process started by [open_in_child], open the database and exit with the result.
+fn child_open() {
+    let Some(dir) = std::env::var_os(CHILD_OPEN_DIR) else {
+        return;
+    };
+    let flag = std::env::var(CHILD_OPEN_FLAG).unwrap();
+    let options = DatabaseOptions {
+        exclusive: flag == "exclusive",
+        accede: flag == "accede",
+        ..Default::default()
+    };
+    // Exit codes are truncated to a byte, so report the error code on stderr.
+    match Database::open_with_options(dir, options) {
+        // Hold the writer lock until the parent closes stdin.
+        Ok(db) if flag == "write" => {
+            let _txn = db.begin_rw_txn().unwrap();
+            println!("ready");
+            std::io::Read::read_to_end(&mut std::io::stdin(), &mut Vec::new()).unwrap();
+        }
+        Ok(_) => eprintln!("0"),
+        Err(e) => eprintln!("{}", e.code()),
+    }
+    std::process::exit(0);
+}
+
+#[test]
+fn test_exclusive() {
+    child_open();
+    let dir = tempdir().unwrap();
+
+    let db = Database::open(&dir).unwrap();
+    assert!(matches!(
+        open_in_child("test_exclusive", dir.path(), "exclusive"),
+        Err(Error::Busy)
+    ));
+    drop(db);
+
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            exclusive: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert!(matches!(
+        open_in_child("test_exclusive", dir.path(), ""),
+        Err(Error::Busy)
+    ));
+    // Nor can a second handle in the same process open it.
+    assert!(matches!(Database::open(&dir), Err(Error::Busy)));
+    drop(db);
+
+    open_in_child("test_exclusive", dir.path(), "exclusive").unwrap();
+}
+
+#[test]
+fn test_accede() {
+    child_open();
+    let dir = tempdir().unwrap();
+
+    let _db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            mode: Mode::ReadWrite(ReadWriteOptions {
+                sync_mode: SyncMode::SafeNoSync,
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert!(matches!(
+        open_in_child("test_accede", dir.path(), ""),
+        Err(Error::Incompatible)
+    ));
+    open_in_child("test_accede", dir.path(), "accede").unwrap();
+}
+
+#[test]
+fn test_begin_rw_txn_locked_by_other_process() {
+    use std::{
+        io::{BufRead, BufReader},
+        process::{Command, Stdio},
+        time::{Duration, Instant},
+    };
+
+    child_open();
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let mut child = Command::new(std::env::current_exe().unwrap())
+        .args([
+            "--exact",
+            "test_begin_rw_txn_locked_by_other_process",
+            "--nocapture",
+        ])
+        .env(CHILD_OPEN_DIR, dir.path())
+        .env(CHILD_OPEN_FLAG, "write")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut stdout = BufReader::new(child.stdout.take().unwrap()).lines();
+    // The line starts with the name of the test, as printed by the test harness.
+    assert!(stdout.any(|line| line.unwrap().ends_with("ready")));
+
+    assert!(matches!(db.try_begin_rw_txn(), Err(Error::Busy)));
+    let start = Instant::now();
+    assert!(matches!(
+        db.begin_rw_txn_with_timeout(Duration::from_millis(200)),
+        Err(Error::Timeout)
+    ));
+    let elapsed = start.elapsed();
+    assert!(elapsed >= Duration::from_millis(200));
+    assert!(elapsed < Duration::from_secs(2));
+
+    drop(child.stdin.take());
+    child.wait().unwrap();
+    db.try_begin_rw_txn().unwrap().commit().unwrap();
+}
+
+#[test]
+fn test_limits() {
+    let default = limits::default_page_size();
+    assert!(default.is_power_of_two());
+    assert_eq!(
+        limits::max_key_size(0, TableFlags::empty()).unwrap(),
+        limits::max_key_size(default, TableFlags::empty()).unwrap()
+    );
+
+    let mut previous = None;
+    let mut page_size = limits::MIN_PAGE_SIZE;
+    while page_size <= limits::MAX_PAGE_SIZE {
+        let key = limits::max_key_size(page_size, TableFlags::empty()).unwrap();
+        let dup_value = limits::max_value_size(page_size, TableFlags::DUP_SORT).unwrap();
+        let value = limits::max_value_size(page_size, TableFlags::empty()).unwrap();
+        assert!(dup_value < value);
+        assert!(limits::max_inline_value_size(page_size, TableFlags::empty()).unwrap() < value);
+        assert!(limits::max_inline_pair_size(page_size, TableFlags::empty()).unwrap() > key);
+        assert!(limits::min_db_size(page_size).unwrap() < limits::max_db_size(page_size).unwrap());
+        assert!(
+            limits::max_txn_size(page_size).unwrap() <= limits::max_db_size(page_size).unwrap()
+        );
+        // Larger pages hold larger keys.
+        if let Some(previous) = previous {
+            assert!(key > previous);
+        }
+        previous = Some(key);
+        page_size *= 2;
+    }
+
+    for page_size in [limits::MIN_PAGE_SIZE / 2, 3000, limits::MAX_PAGE_SIZE * 2] {
+        assert!(limits::max_key_size(page_size, TableFlags::empty()).is_err());
+        assert!(limits::max_db_size(page_size).is_err());
+    }
+
+    // A database must not be larger than the limit for its page size.
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+    let info = db.info().unwrap();
+    assert!(
+        info.geometry().max() as usize <= limits::max_db_size(info.page_size() as usize).unwrap()
+    );
+}
+
+#[test]
+fn test_sys_ram_info() {
+    let ram = SysRamInfo::get().unwrap();
+    assert!(ram.page_size.is_power_of_two());
+    assert!(ram.available_pages > 0);
+    assert!(ram.available_pages <= ram.total_pages);
+    assert_eq!(ram.total_bytes(), ram.page_size * ram.total_pages);
+    assert!(ram.available_bytes() <= ram.total_bytes());
+}
+
+#[test]
+fn test_info() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let info = db.info().unwrap();
+    // assert_eq!(info.geometry().min(), map_size as u64);
+    // assert_eq!(info.last_pgno(), 1);
+    // assert_eq!(info.last_txnid(), 0);
+    assert_eq!(info.num_readers(), 0);
+}
+
+#[test]
+fn test_info_reader_lag() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let reader = db.begin_ro_txn().unwrap();
+    for i in 0..4_u64 {
+        let txn = db.begin_rw_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        txn.put(&table, i.to_le_bytes(), b"val", WriteFlags::empty())
+            .unwrap();
+        txn.commit().unwrap();
+    }
+
+    // The reader lags behind the writes.
+    let info = db.info().unwrap();
+    assert_eq!(info.latter_reader_txnid(), reader.id());
+    assert_eq!(info.self_latter_reader_txnid(), reader.id());
+    assert_eq!(info.last_txnid() as u64, reader.id() + 4);
+    assert!(info.meta_txnids().contains(&(info.last_txnid() as u64)));
+    assert!(info.page_ops().newly > 0);
+    assert_eq!(info.boot_id(), db.info().unwrap().boot_id());
+    drop(reader);
+
+    let info = db.info().unwrap();
+    assert_eq!(info.latter_reader_txnid(), info.last_txnid() as u64);
+}
+
+#[test]
+fn test_freelist() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let mut freelist = db.freelist().unwrap();
+    assert_eq!(freelist, 0);
+
+    // Write a few small values.
+    for i in 0..64_u64 {
+        let value = i.to_le_bytes();
+        let tx = db.begin_rw_txn().unwrap();
+        tx.put(
+            &tx.open_table(None).unwrap(),
+            value,
+            value,
+            WriteFlags::default(),
+        )
+        .unwrap();
+        tx.commit().unwrap();
+    }
+    let tx = db.begin_rw_txn().unwrap();
+    tx.clear_table(&tx.open_table(None).unwrap()).unwrap();
+    tx.commit().unwrap();
+
+    // Freelist should not be empty after clear_table.
+    freelist = db.freelist().unwrap();
+    assert!(freelist > 0);
+}
+
+#[test]
+fn test_table_names() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(4),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(db.table_names().unwrap(), Vec::<String>::new());
+
+    let txn = db.begin_rw_txn().unwrap();
+    for name in ["table2", "table1", "table3"] {
+        txn.create_table(Some(name), TableFlags::empty()).unwrap();
+    }
+    let main_table = txn.open_table(None).unwrap();
+    for key in [&b"plain1"[..], b"zzz", &[0xff, 0xfe]] {
+        txn.put(&main_table, key, b"val", WriteFlags::empty())
+            .unwrap();
+    }
+    assert_eq!(
+        txn.table_names().unwrap(),
+        vec!["table1", "table2", "table3"]
+    );
+    txn.commit().unwrap();
+
+    assert_eq!(
+        db.table_names().unwrap(),
+        vec!["table1", "table2", "table3"]
+    );
+}
+
+#[test]
+fn test_geometry() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            mode: Mode::ReadWrite(ReadWriteOptions {
+                min_size: Some(4 << 20),
+                max_size: Some(64 << 20),
+                growth_step: Some(4 << 20),
+                // Rounded up to the allocation granularity.
+                shrink_threshold: Some((8 << 20) - 1),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let geometry = db.geometry().unwrap();
+    assert_eq!(geometry.min(), 4 << 20);
+    assert_eq!(geometry.max(), 64 << 20);
+    assert_eq!(geometry.growth_step(), 4 << 20);
+    assert_eq!(geometry.shrink_threshold(), 8 << 20);
+    let initial = geometry.current();
+    assert_eq!(initial, 4 << 20);
+
+    // Writing past the initial size grows the database by the growth step.
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    for i in 0..600u32 {
+        txn.put(&table, i.to_be_bytes(), [0; 4096], WriteFlags::empty())
+            .unwrap();
+    }
+    txn.commit().unwrap();
+    assert_eq!(db.geometry().unwrap().current(), initial + (4 << 20));
+}
+
+#[test]
+fn test_automatic_growth() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            mode: Mode::ReadWrite(ReadWriteOptions {
+                min_size: Some(64 << 10),
+                max_size: Some(4 << 20),
+                growth_step: Some(64 << 10),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let initial = db.info().unwrap().geometry().current();
+    assert!(initial < 1 << 20, "{initial}");
+
+    // Writing past the current size grows the database without surfacing an error.
+    let value = [0u8; 1024];
+    for i in 0..1024u32 {
+        let txn = db.begin_rw_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        txn.put(&table, i.to_be_bytes(), value, WriteFlags::empty())
+            .unwrap();
+        txn.commit().unwrap();
+    }
+    let geometry = db.info().unwrap().geometry();
+    assert!(geometry.current() > initial);
+    assert!(geometry.current() <= geometry.max());
+
+    // Only the upper bound is a hard limit.
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    let err = (1024u32..)
+        .find_map(|i| {
+            txn.put(&table, i.to_be_bytes(), value, WriteFlags::empty())
+                .err()
+        })
+        .unwrap();
+    assert!(err.is_map_full());
+}
+
+#[test]
+fn test_set_geometry() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            mode: Mode::ReadWrite(ReadWriteOptions {
+                min_size: Some(64 << 10),
+                max_size: Some(4 << 20),
+                growth_step: Some(64 << 10),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let initial = db.geometry().unwrap();
+    db.set_geometry(Geometry {
+        max_size: Some(1 << 20),
+        ..Default::default()
+    })
+    .unwrap();
+    let geometry = db.geometry().unwrap();
+    assert_eq!(geometry.max(), 1 << 20);
+    assert_eq!(geometry.min(), initial.min());
+    assert_eq!(geometry.growth_step(), initial.growth_step());
+    assert_eq!(geometry.shrink_threshold(), initial.shrink_threshold());
+
+    // Counts the values which fit before the database is full.
+    let value = [0u8; 1024];
+    let fill = || {
+        let txn = db.begin_rw_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        (0u32..)
+            .find(|i| {
+                txn.put(&table, i.to_be_bytes(), value, WriteFlags::empty())
+                    .is_err_and(|err| err.is_map_full())
+            })
+            .unwrap()
+    };
+    let count = fill();
+
+    // Raising the upper bound makes room without reopening the database.
+    db.set_geometry(Geometry {
+        max_size: Some(4 << 20),
+        growth_step: Some(256 << 10),
+        ..Default::default()
+    })
+    .unwrap();
+    let geometry = db.geometry().unwrap();
+    assert_eq!(geometry.max(), 4 << 20);
+    assert_eq!(geometry.growth_step(), 256 << 10);
+    assert!(fill() > count);
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    for i in 0..count {
+        txn.put(&table, i.to_be_bytes(), value, WriteFlags::empty())
+            .unwrap();
+    }
+    txn.commit().unwrap();
+
+    // The upper bound cannot drop below the space in use.
+    assert!(matches!(
+        db.set_geometry(Geometry {
+            max_size: Some(256 << 10),
+            ..Default::default()
+        }),
+        Err(Error::MapFull)
+    ));
+    assert_eq!(db.geometry().unwrap().max(), 4 << 20);
+}
+
+#[test]
+fn test_check() {
+    use std::fs;
+
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(1),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    for i in 0..1000u32 {
+        txn.put(&table, i.to_be_bytes(), [0; 64], WriteFlags::empty())
+            .unwrap();
+    }
+    let dups = txn
+        .create_table(Some("dups"), TableFlags::DUP_SORT)
+        .unwrap();
+    for i in 0..100u32 {
+        txn.put(&dups, b"key", i.to_be_bytes(), WriteFlags::empty())
+            .unwrap();
+    }
+    txn.commit().unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    txn.del(&txn.open_table(None).unwrap(), 0u32.to_be_bytes(), None)
+        .unwrap();
+    txn.commit().unwrap();
+
+    for level in [CheckLevel::Pages, CheckLevel::Full] {
+        let report = db.check(level, None).unwrap();
+        assert!(report.is_ok(), "{}", report.problem.unwrap());
+        assert_eq!(report.unreachable_pages, 0);
+        assert!(report.gc_pages > 0);
+        assert_eq!(
+            report.used_pages + report.gc_pages,
+            report.pages,
+            "{report:?}"
+        );
+        let entries = report
+            .tables
+            .iter()
+            .map(|table| (table.name.as_deref(), table.entries))
+            .collect::<Vec<_>>();
+        // The main table also records the named table.
+        assert_eq!(entries, [(None, 1000), (Some("dups"), 100)]);
+        assert!(report.tables[0].pages > 1);
+    }
+
+    let token = CancellationToken::new();
+    token.cancel();
+    let report = db.check(CheckLevel::Full, Some(&token)).unwrap();
+    assert!(matches!(
+        report.problem,
+        Some(Inconsistency::Failed(Error::Cancelled { progress: 0 }))
+    ));
+    assert!(report.tables.is_empty());
+
+    let check_copy = |data: &[u8]| {
+        let copy = tempdir().unwrap();
+        fs::write(copy.path().join("mdbx.dat"), data).unwrap();
+        let db = Database::open_with_options(
+            &copy,
+            DatabaseOptions {
+                max_tables: Some(1),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        [CheckLevel::Pages, CheckLevel::Full].map(|level| db.check(level, None).unwrap().problem)
+    };
+
+    // Flip a bit of the page number which each page records in its header, at offset 16.
+    let stat = db.stat().unwrap();
+    let page_size = stat.page_size() as usize;
+    let report = db.check(CheckLevel::Pages, None).unwrap();
+    let data = fs::read(dir.path().join("mdbx.dat")).unwrap();
+    drop(db);
+    let mut broken = data.clone();
+    let pgno = report.pages as usize - 1;
+    broken[pgno * page_size + 16] ^= 0x40;
+    for problem in check_copy(&broken) {
+        match problem {
+            Some(Inconsistency::BrokenPage { pgno: broken, .. }) => {
+                assert_eq!(broken as usize, pgno)
+            }
+            problem => panic!("unexpected problem {problem:?}"),
+        }
+    }
+
+    // Find a GC record past the meta pages, i.e. a page count followed by that many page numbers
+    // in descending order, and replace its last page with a meta page.
+    let words = data
+        .chunks_exact(4)
+        .map(|word| u32::from_ne_bytes(word.try_into().unwrap()) as u64)
+        .collect::<Vec<_>>();
+    let record = (3 * page_size / 4..words.len())
+        .find(|&i| {
+            let count = words[i] as usize;
+            (1..=report.gc_pages as usize).contains(&count)
+                && words.get(i + 1..=i + count).is_some_and(|pgnos| {
+                    pgnos.windows(2).all(|pair| pair[0] > pair[1])
+                        && pgnos.iter().all(|&pgno| (3..report.pages).contains(&pgno))
+                })
+        })
+        .unwrap();
+    let mut broken = data.clone();
+    let last = (record + words[record] as usize) * 4;
+    broken[last..last + 4].copy_from_slice(&2u32.to_ne_bytes());
+    for problem in check_copy(&broken) {
+        assert!(
+            matches!(problem, Some(Inconsistency::FreePageInUse { pgno: 2 })),
+            "{problem:?}"
+        );
+    }
+}
+
+#[test]
+fn test_copy_to_path() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            mode: Mode::ReadWrite(ReadWriteOptions {
+                min_size: Some(4 << 20),
+                max_size: Some(4 << 20),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    for i in 0..1000u32 {
+        txn.put(&table, i.to_be_bytes(), [0; 256], WriteFlags::empty())
+            .unwrap();
+    }
+    txn.commit().unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    txn.del_range(&table, 100u32.to_be_bytes()..).unwrap();
+    txn.commit().unwrap();
+
+    let copy = |flags| {
+        let copy_dir = tempdir().unwrap();
+        let path = copy_dir.path().join("mdbx.dat");
+        db.copy_to_path(&path, flags).unwrap();
+        // A copy is only made to a new file.
+        assert!(matches!(
+            db.copy_to_path(&path, flags),
+            Err(Error::Other(_))
+        ));
+
+        // Opened read-only, so the stored geometry is kept.
+        let copy = Database::open_read_only(&copy_dir).unwrap();
+        let txn = copy.begin_ro_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        assert_eq!(txn.len(&table).unwrap(), 100);
+        (copy.geometry().unwrap(), copy.info().unwrap().last_pgno())
+    };
+
+    let (geometry, last_pgno) = copy(CopyFlags::empty());
+    assert_eq!(geometry.min(), 4 << 20);
+    assert_eq!(geometry.max(), 4 << 20);
+    assert_eq!(last_pgno, db.info().unwrap().last_pgno());
+
+    let (geometry, compact_last_pgno) = copy(CopyFlags::COMPACT);
+    assert_eq!(geometry.min(), 4 << 20);
+    assert!(compact_last_pgno < last_pgno);
+
+    let (geometry, _) = copy(CopyFlags::FORCE_DYNAMIC_SIZE);
+    assert!(geometry.min() < geometry.max());
+}
+
+#[test]
+fn test_copy_to_file() {
+    use std::{
+        fs,
+        io::{self, Read, Seek},
+        thread,
+    };
+
+    fn check_copy(data: &[u8]) {
+        let dir = tempdir().unwrap();
+        fs::write(dir.path().join("mdbx.dat"), data).unwrap();
+        let db = Database::open(&dir).unwrap();
+        let txn = db.begin_ro_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        assert_eq!(txn.len(&table).unwrap(), 100);
+        for i in 0..100u32 {
+            assert_eq!(
+                txn.get::<Vec<u8>>(&table, &i.to_be_bytes()).unwrap(),
+                Some(i.to_le_bytes().to_vec())
+            );
+        }
+    }
+
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    for i in 0..1000u32 {
+        txn.put(
+            &table,
+            i.to_be_bytes(),
+            i.to_le_bytes(),
+            WriteFlags::empty(),
+        )
+        .unwrap();
+    }
+    txn.commit().unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    txn.del_range(&table, 100u32.to_be_bytes()..).unwrap();
+    txn.commit().unwrap();
+
+    for compact in [false, true] {
+        let mut file = tempfile::tempfile().unwrap();
+        db.copy_to_file(&file, compact).unwrap();
+        let mut data = Vec::new();
+        file.rewind().unwrap();
+        file.read_to_end(&mut data).unwrap();
+        check_copy(&data);
+    }
+
+    // Non-seekable targets are written to sequentially, but cannot be compacted into.
+    let (mut reader, writer) = io::pipe().unwrap();
+    let reader = thread::spawn(move || {
+        let mut data = Vec::new();
+        reader.read_to_end(&mut data).unwrap();
+        data
+    });
+    assert!(matches!(
+        db.copy_to_fd(&writer, CopyFlags::COMPACT),
+        Err(Error::Io(_))
+    ));
+    db.copy_to_fd(&writer, CopyFlags::empty()).unwrap();
+    drop(writer);
+    check_copy(&reader.join().unwrap());
+}
+
+#[test]
+fn test_sync_mode() {
+    fn check<E: DatabaseKind>(sync_mode: SyncMode, expected: ffi::MDBX_env_flags_t) {
+        let dir = tempdir().unwrap();
+        let db = libmdbx::Database::<E>::open_with_options(
+            &dir,
+            DatabaseOptions {
+                mode: Mode::ReadWrite(ReadWriteOptions {
+                    sync_mode,
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(db.sync_mode().unwrap(), sync_mode);
+
+        let mut flags = 0;
+        assert_eq!(
+            unsafe { ffi::mdbx_env_get_flags(db.ptr().0, &mut flags) },
+            ffi::MDBX_SUCCESS
+        );
+        let sync_flags = ffi::MDBX_NOMETASYNC | ffi::MDBX_UTTERLY_NOSYNC;
+        assert_eq!(flags & sync_flags, expected, "{sync_mode:?}");
+        assert_eq!(flags & ffi::MDBX_WRITEMAP, E::EXTRA_FLAGS);
+    }
+
+    // MDBX implies NOMETASYNC for the modes which do not sync at all.
+    for (sync_mode, expected) in [
+        (SyncMode::Durable, ffi::MDBX_SYNC_DURABLE),
+        (SyncMode::NoMetaSync, ffi::MDBX_NOMETASYNC),
+        (
+            SyncMode::SafeNoSync,
+            ffi::MDBX_SAFE_NOSYNC | ffi::MDBX_NOMETASYNC,
+        ),
+        (
+            SyncMode::UtterlyNoSync,
+            ffi::MDBX_UTTERLY_NOSYNC | ffi::MDBX_NOMETASYNC,
+        ),
+    ] {
+        check::<NoWriteMap>(sync_mode, expected);
+        check::<WriteMap>(sync_mode, expected);
+    }
+
+    // The mode can be switched while the database is open.
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+    for (i, sync_mode) in [
+        SyncMode::UtterlyNoSync,
+        SyncMode::NoMetaSync,
+        SyncMode::SafeNoSync,
+        SyncMode::Durable,
+    ]
+    .into_iter()
+    .enumerate()
+    {
+        db.set_sync_mode(sync_mode).unwrap();
+        assert_eq!(db.sync_mode().unwrap(), sync_mode);
+
+        let txn = db.begin_rw_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        txn.put(&table, [i as u8], b"value", WriteFlags::empty())
+            .unwrap();
+        txn.commit().unwrap();
+        let unsynced = db.info().unwrap().unsync_volume() > 0;
+        assert_eq!(unsynced, sync_mode != SyncMode::Durable, "{sync_mode:?}");
+        db.sync(true).unwrap();
+    }
+    drop(db);
+
+    let db = Database::open_read_only(&dir).unwrap();
+    assert_eq!(db.sync_mode().unwrap(), SyncMode::Durable);
+    assert!(db.set_sync_mode(SyncMode::SafeNoSync).is_err());
+}
+
+#[test]
+fn test_fd() {
+    use std::{fs::File, os::unix::fs::MetadataExt};
+
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+    let file = File::from(db.fd().unwrap().try_clone_to_owned().unwrap());
+    let metadata = file.metadata().unwrap();
+    let expected = std::fs::metadata(dir.path().join("mdbx.dat")).unwrap();
+    assert_eq!(
+        (metadata.dev(), metadata.ino()),
+        (expected.dev(), expected.ino())
+    );
+
+    // The duplicate stays usable after the database is closed.
+    drop(db);
+    assert_eq!(file.metadata().unwrap().ino(), expected.ino());
+}
+
+#[test]
+fn test_set_flags() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            coalesce: true,
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    assert_eq!(db.flags().unwrap(), RuntimeFlags::COALESCE);
+
+    db.set_flags(RuntimeFlags::NO_MEMINIT, true).unwrap();
+    assert_eq!(db.flags().unwrap(), RuntimeFlags::all());
+    db.set_flags(RuntimeFlags::COALESCE, false).unwrap();
+    assert_eq!(db.flags().unwrap(), RuntimeFlags::NO_MEMINIT);
+    // The sync mode is left alone.
+    db.set_sync_mode(SyncMode::SafeNoSync).unwrap();
+    db.set_flags(RuntimeFlags::NO_MEMINIT, false).unwrap();
+    assert_eq!(db.flags().unwrap(), RuntimeFlags::empty());
+    assert_eq!(db.sync_mode().unwrap(), SyncMode::SafeNoSync);
+
+    let txn = db.begin_rw_txn().unwrap();
+    txn.put(
+        &txn.open_table(None).unwrap(),
+        b"key",
+        b"val",
+        WriteFlags::empty(),
+    )
+    .unwrap();
+    txn.commit().unwrap();
+    drop(db);
+
+    let db = Database::open_read_only(&dir).unwrap();
+    assert!(matches!(
+        db.set_flags(RuntimeFlags::COALESCE, true),
+        Err(Error::Access)
+    ));
+}
+
+#[test]
+fn test_warmup() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            mode: Mode::ReadWrite(ReadWriteOptions {
+                max_size: Some(64 << 20),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    for i in 0..1024u32 {
+        txn.put(&table, i.to_be_bytes(), [0xaa; 4096], WriteFlags::empty())
+            .unwrap();
+    }
+    txn.commit().unwrap();
+    assert!(db.info().unwrap().last_pgno() * 4096 > 4 << 20);
+
+    // Warming up does not interfere with readers.
+    let txn = db.begin_ro_txn().unwrap();
+    assert!(db.warmup(WarmupFlags::empty(), None).unwrap());
+    assert!(db.warmup(WarmupFlags::FORCE, None).unwrap());
+    assert!(db
+        .warmup(WarmupFlags::FORCE | WarmupFlags::OOM_SAFE, None)
+        .unwrap());
+    let table = txn.open_table(None).unwrap();
+    assert_eq!(txn.table_stat(&table).unwrap().entries(), 1024);
+    drop(txn);
+
+    let start = std::time::Instant::now();
+    db.warmup(WarmupFlags::FORCE, Some(std::time::Duration::ZERO))
+        .unwrap();
+    assert!(start.elapsed() < std::time::Duration::from_secs(1));
+
+    // Locking pages fails if the resource limits of the process are too low for the database.
+    match db.warmup(WarmupFlags::LOCK, None) {
+        Ok(completed) => assert!(completed),
+        Err(e) => assert!(matches!(e, Error::Permission | Error::Other(_)), "{e:?}"),
+    }
+    assert!(db.warmup(WarmupFlags::RELEASE, None).unwrap());
+}
+
+#[test]
+fn test_setup_debug() {
+    use std::sync::Mutex;
+
+    static RECORDS: Mutex<Vec<(LogLevel, String)>> = Mutex::new(Vec::new());
+
+    fn logger(record: &LogRecord) {
+        RECORDS
+            .lock()
+            .unwrap()
+            .push((record.level, record.message.to_owned()));
+    }
+
+    fn panicking_logger(_: &LogRecord) {
+        panic!("logger failure");
+    }
+
+    let dir = tempdir().unwrap();
+    std::fs::write(dir.path().join("mdbx.dat"), [0xab; 65536]).unwrap();
+
+    setup_debug(LogLevel::Trace, DebugFlags::empty(), Some(logger));
+    assert!(Database::open(&dir).is_err());
+    assert!(RECORDS
+        .lock()
+        .unwrap()
+        .iter()
+        .any(|(level, message)| *level == LogLevel::Error
+            && message.contains("invalid magic/version abababababab")
+            && !message.ends_with('\n')));
+
+    // Panics in the logger do not reach MDBX.
+    setup_debug(LogLevel::Trace, DebugFlags::empty(), Some(panicking_logger));
+    assert!(Database::open(&dir).is_err());
+
+    setup_debug(LogLevel::Fatal, DebugFlags::empty(), None);
+}
+
+#[cfg(feature = "async")]
+mod r#async {
+    use super::*;
+    use std::{
+        future::Future,
+        panic::{self, AssertUnwindSafe},
+        pin::pin,
+        sync::Arc,
+        task::{Context, Poll, Wake, Waker},
+        thread::{self, Thread},
+    };
+
+    struct Unpark(Thread);
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let waker = Waker::from(Arc::new(Unpark(thread::current())));
+        let mut cx = Context::from_waker(&waker);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    fn len(txn: &Transaction<'_, impl TransactionKind, NoWriteMap>) -> usize {
+        txn.len(&txn.open_table(None).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_async_database() {
+        let dir = tempdir().unwrap();
+        let db = Arc::new(AsyncDatabase::new(Database::open(&dir).unwrap()));
+
+        // Writes run in submission order, each seeing the ones before it.
+        let writes = (0..20u32)
+            .map(|i| {
+                db.write(move |txn| {
+                    let seen = len(txn);
+                    let table = txn.open_table(None)?;
+                    txn.put(&table, i.to_be_bytes(), b"value", WriteFlags::empty())?;
+                    Ok(seen)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        // Readers running concurrently always see a committed prefix of the writes.
+        let readers = (0..4)
+            .map(|_| {
+                let db = db.clone();
+                thread::spawn(move || {
+                    let mut last = 0;
+                    for _ in 0..10 {
+                        let seen = block_on(db.read(len)).unwrap();
+                        assert!(seen >= last);
+                        last = seen;
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for (i, write) in writes.into_iter().enumerate() {
+            assert_eq!(block_on(write).unwrap(), i);
+        }
+        for reader in readers {
+            reader.join().unwrap();
+        }
+        assert_eq!(block_on(db.read(len)).unwrap(), 20);
+
+        // A failing closure aborts its transaction and hands the error back.
+        let err = block_on(db.write(|txn| {
+            let table = txn.open_table(None)?;
+            txn.put(&table, b"partial", b"value", WriteFlags::empty())?;
+            txn.put(
+                &table,
+                0u32.to_be_bytes(),
+                b"value",
+                WriteFlags::NO_OVERWRITE,
+            )
+        }))
+        .unwrap_err();
+        assert!(err.is_key_exist());
+
+        // A panicking closure is resumed in the awaiting task, and the writer thread carries on.
+        let panicked = panic::catch_unwind(AssertUnwindSafe(|| {
+            block_on(db.write(|txn| -> Result<()> {
+                let table = txn.open_table(None)?;
+                txn.put(&table, b"partial", b"value", WriteFlags::empty())?;
+                panic!("boom")
+            }))
+        }));
+        assert!(panicked.is_err());
+
+        block_on(db.write(|txn| {
+            let table = txn.open_table(None)?;
+            txn.put(&table, b"last", b"value", WriteFlags::empty())
+        }))
+        .unwrap();
+        let (partial, last) = block_on(db.read(|txn| {
+            let table = txn.open_table(None).unwrap();
+            (
+                txn.contains_key(&table, b"partial").unwrap(),
+                txn.contains_key(&table, b"last").unwrap(),
+            )
+        }))
+        .unwrap();
+        assert!(!partial);
+        assert!(last);
+    }
+
+    #[test]
+    fn test_async_database_readers() {
+        let dir = tempdir().unwrap();
+        let db = AsyncDatabase::with_readers(Database::open(&dir).unwrap(), 2);
+
+        // Reads share a bounded pool of threads, however many are submitted at once.
+        let reads = (0..64)
+            .map(|_| {
+                db.read(|_| {
+                    thread::sleep(std::time::Duration::from_millis(1));
+                    let current = thread::current();
+                    (current.id(), current.name().map(str::to_owned))
+                })
+            })
+            .collect::<Vec<_>>();
+        let mut threads = std::collections::HashSet::new();
+        for read in reads {
+            let (id, name) = block_on(read).unwrap();
+            assert_eq!(name.as_deref(), Some("mdbx-reader"));
+            threads.insert(id);
+        }
+        assert!(threads.len() <= 2);
+    }
 }
@@ -0,0 +1,290 @@
+#![cfg(feature = "orm")]
+
+use libmdbx::{orm::TypedTable, *};
+use tempfile::tempdir;
+
+type Database = libmdbx::Database<NoWriteMap>;
+
+#[test]
+fn test_typed_table() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = TypedTable::<u64, String>::create(&txn, None, TableFlags::empty()).unwrap();
+    for i in 0..5u64 {
+        table
+            .put(&txn, i, format!("value{i}"), WriteFlags::empty())
+            .unwrap();
+    }
+    assert!(table.del(&txn, 4, None).unwrap());
+    assert!(!table.del(&txn, 4, None).unwrap());
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = TypedTable::<u64, String>::open(&txn, None).unwrap();
+    assert_eq!(table.get(&txn, 1).unwrap(), Some("value1".to_string()));
+    assert_eq!(table.get(&txn, 4).unwrap(), None);
+    assert!(table.contains_key(&txn, 1).unwrap());
+    assert!(!table.contains_key(&txn, 4).unwrap());
+    assert_eq!(
+        table
+            .iter(&txn)
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap(),
+        (0..4u64)
+            .map(|i| (i, format!("value{i}")))
+            .collect::<Vec<_>>()
+    );
+
+    // The untyped API remains available through the raw handle.
+    assert_eq!(
+        txn.get::<Vec<u8>>(table.raw(), &1u64.to_be_bytes())
+            .unwrap(),
+        Some(b"value1".to_vec())
+    );
+}
+
+#[test]
+fn test_typed_table_decode_error() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    txn.put(&table, b"short", 7u64.to_be_bytes(), WriteFlags::empty())
+        .unwrap();
+
+    let table = TypedTable::<u64, u64>::new(table);
+    assert!(matches!(
+        table.iter(&txn).unwrap().next(),
+        Some(Err(Error::DecodeError(_)))
+    ));
+
+    let table = TypedTable::<u64, u32>::new(txn.open_table(None).unwrap());
+    txn.put(
+        &table,
+        1u64.to_be_bytes(),
+        b"wrong length",
+        WriteFlags::empty(),
+    )
+    .unwrap();
+    assert!(matches!(table.get(&txn, 1), Err(Error::DecodeError(_))));
+}
+
+#[test]
+fn test_integer_codecs() {
+    use libmdbx::orm::{Decodable, Encodable, NativeU32, NativeU64};
+
+    assert_eq!(u16::decode(&0xabcdu16.encode()).unwrap(), 0xabcd);
+    assert_eq!(u32::decode(&7u32.encode()).unwrap(), 7);
+    assert_eq!(u64::decode(&u64::MAX.encode()).unwrap(), u64::MAX);
+    assert_eq!(u128::decode(&(1u128 << 100).encode()).unwrap(), 1 << 100);
+    for i in [i64::MIN, -1, 0, 1, i64::MAX] {
+        assert_eq!(i64::decode(&i.encode()).unwrap(), i);
+    }
+    assert_eq!(
+        NativeU32::decode(&NativeU32(7).encode()).unwrap(),
+        NativeU32(7)
+    );
+    assert_eq!(7u64.to_ne_bytes(), NativeU64(7).encode());
+
+    assert!(u16::decode(&[0; 3]).is_err());
+    assert!(u64::decode(&[0; 4]).is_err());
+    assert!(i64::decode(&[]).is_err());
+    assert!(NativeU64::decode(&[0; 9]).is_err());
+}
+
+#[test]
+fn test_integer_key_order() {
+    use libmdbx::orm::NativeU64;
+
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(3),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let unsigned = TypedTable::<u64, ()>::create(&txn, Some("u64"), TableFlags::empty()).unwrap();
+    let signed = TypedTable::<i64, ()>::create(&txn, Some("i64"), TableFlags::empty()).unwrap();
+    let native =
+        TypedTable::<NativeU64, ()>::create(&txn, Some("native"), TableFlags::INTEGER_KEY).unwrap();
+    for i in (1..1000u64).rev() {
+        unsigned.put(&txn, i, (), WriteFlags::empty()).unwrap();
+        signed
+            .put(&txn, i as i64 - 500, (), WriteFlags::empty())
+            .unwrap();
+        native
+            .put(&txn, NativeU64(i), (), WriteFlags::empty())
+            .unwrap();
+    }
+
+    fn keys<K>(iter: Result<impl Iterator<Item = Result<(K, ())>>>) -> Vec<K> {
+        iter.unwrap().map(|item| item.unwrap().0).collect()
+    }
+    assert_eq!(keys(unsigned.iter(&txn)), (1..1000).collect::<Vec<u64>>());
+    assert_eq!(keys(signed.iter(&txn)), (-499..500).collect::<Vec<i64>>());
+    assert_eq!(
+        keys(native.iter(&txn)),
+        (1..1000).map(NativeU64).collect::<Vec<_>>()
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_json() {
+    use libmdbx::orm::SerdeJson;
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    enum Shape {
+        Circle { radius: u32 },
+        Polygon(Vec<(i32, i32)>),
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Drawing {
+        name: String,
+        shapes: Vec<Option<Shape>>,
+    }
+
+    let drawing = Drawing {
+        name: "sketch \"1\"".to_string(),
+        shapes: vec![
+            Some(Shape::Circle { radius: 3 }),
+            None,
+            Some(Shape::Polygon(vec![(0, 0), (-1, 2)])),
+        ],
+    };
+
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table =
+        TypedTable::<u64, SerdeJson<Drawing>>::create(&txn, None, TableFlags::empty()).unwrap();
+    table
+        .put(&txn, 1, SerdeJson(drawing), WriteFlags::empty())
+        .unwrap();
+    let stored = txn
+        .get::<Vec<u8>>(table.raw(), &1u64.to_be_bytes())
+        .unwrap()
+        .unwrap();
+    txn.put(
+        table.raw(),
+        2u64.to_be_bytes(),
+        &stored[..stored.len() - 1],
+        WriteFlags::empty(),
+    )
+    .unwrap();
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = TypedTable::<u64, SerdeJson<Drawing>>::open(&txn, None).unwrap();
+    let SerdeJson(drawing) = table.get(&txn, 1).unwrap().unwrap();
+    assert_eq!(
+        drawing.shapes[2],
+        Some(Shape::Polygon(vec![(0, 0), (-1, 2)]))
+    );
+
+    match table.get(&txn, 2) {
+        Err(Error::DecodeError(e)) => assert!(e.to_string().starts_with("EOF while parsing")),
+        other => panic!("unexpected {other:?}"),
+    }
+}
+
+#[test]
+fn test_typed_cursor() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let names =
+        TypedTable::<u64, String>::create(&txn, Some("names"), TableFlags::empty()).unwrap();
+    let tags = TypedTable::<String, u32>::create(&txn, Some("tags"), TableFlags::DUP_SORT).unwrap();
+    {
+        let mut cursor = names.cursor(&txn).unwrap();
+        for (id, name) in [(3, "carol"), (1, "alice"), (2, "bob")] {
+            cursor
+                .put(id, name.to_string(), WriteFlags::empty())
+                .unwrap();
+        }
+        let mut cursor = tags.cursor(&txn).unwrap();
+        for (tag, id) in [("admin", 1), ("user", 3), ("user", 1), ("user", 2)] {
+            cursor
+                .put(tag.to_string(), id, WriteFlags::empty())
+                .unwrap();
+        }
+    }
+    txn.commit().unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let names = TypedTable::<u64, String>::open(&txn, Some("names")).unwrap();
+    let tags = TypedTable::<String, u32>::open(&txn, Some("tags")).unwrap();
+    {
+        let mut cursor = names.cursor(&txn).unwrap();
+        assert_eq!(cursor.first().unwrap(), Some((1, "alice".to_string())));
+        assert_eq!(cursor.next().unwrap(), Some((2, "bob".to_string())));
+        assert_eq!(cursor.last().unwrap(), Some((3, "carol".to_string())));
+        assert_eq!(cursor.seek_exact(4).unwrap(), None);
+        assert_eq!(cursor.seek(0).unwrap(), Some((1, "alice".to_string())));
+        cursor.del(WriteFlags::empty()).unwrap();
+        assert_eq!(
+            cursor
+                .iter_start()
+                .map(|item| item.unwrap().1)
+                .collect::<Vec<_>>(),
+            vec!["bob".to_string(), "carol".to_string()]
+        );
+
+        let mut cursor = tags.cursor(&txn).unwrap();
+        assert_eq!(
+            cursor
+                .iter_dup_of("user".to_string())
+                .collect::<Result<Vec<_>>>()
+                .unwrap(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(cursor.seek_dup("user".to_string(), 2).unwrap(), Some(2));
+        cursor.del(WriteFlags::empty()).unwrap();
+        assert_eq!(cursor.current().unwrap(), Some(("user".to_string(), 3)));
+        assert_eq!(
+            cursor.seek_exact("admin".to_string()).unwrap(),
+            Some(("admin".to_string(), 1))
+        );
+        assert_eq!(cursor.next_dup().unwrap(), None);
+        assert_eq!(cursor.next_nodup().unwrap(), Some(("user".to_string(), 1)));
+        assert_eq!(
+            cursor
+                .iter_from("b".to_string())
+                .collect::<Result<Vec<_>>>()
+                .unwrap(),
+            vec![("user".to_string(), 1), ("user".to_string(), 3)]
+        );
+    }
+    assert_eq!(names.get(&txn, 1).unwrap(), None);
+    assert_eq!(tags.get(&txn, "user".to_string()).unwrap(), Some(1));
+    assert_eq!(tags.get_both(&txn, "user".to_string(), 3).unwrap(), Some(3));
+    assert_eq!(tags.get_both(&txn, "user".to_string(), 2).unwrap(), None);
+    assert_eq!(
+        tags.get_both_range(&txn, "user".to_string(), 2).unwrap(),
+        Some(3)
+    );
+    assert_eq!(
+        tags.get_both_range(&txn, "guest".to_string(), 0).unwrap(),
+        None
+    );
+}
@@ -2,6 +2,8 @@ use libmdbx::*;
 use std::{
     borrow::Cow,
     io::Write,
+    ops::Bound,
+    str::Utf8Error,
     sync::{Arc, Barrier},
     thread::{self, JoinHandle},
 };
@@ -251,6 +253,166 @@ fn test_drop_table() {
     ));
 }
 
+#[test]
+fn test_drop_table_by_name() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    {
+        let txn = db.begin_rw_txn().unwrap();
+        let table = txn.create_table(Some("test"), TableFlags::empty()).unwrap();
+        txn.put(&table, b"key", b"val", WriteFlags::empty())
+            .unwrap();
+        txn.commit().unwrap();
+    }
+
+    {
+        let mut txn = db.begin_rw_txn().unwrap();
+        txn.prime_for_permaopen(txn.open_table(Some("test")).unwrap());
+        assert!(matches!(
+            unsafe { txn.drop_table_by_name("test") },
+            Err(Error::TableInUse)
+        ));
+    }
+
+    {
+        let mut txn = db.begin_rw_txn().unwrap();
+        unsafe { txn.drop_table_by_name("test") }.unwrap();
+        assert!(matches!(
+            txn.open_table(Some("test")).unwrap_err(),
+            Error::NotFound
+        ));
+        txn.commit().unwrap();
+    }
+
+    let txn = db.begin_rw_txn().unwrap();
+    assert!(matches!(
+        txn.open_table(Some("test")).unwrap_err(),
+        Error::NotFound
+    ));
+    let table = txn.create_table(Some("test"), TableFlags::empty()).unwrap();
+    assert_eq!(txn.get::<()>(&table, b"key").unwrap(), None);
+}
+
+#[test]
+fn test_owned_ro_txn() {
+    struct Snapshot {
+        txn: OwnedRoTransaction<NoWriteMap>,
+    }
+
+    impl Snapshot {
+        fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+            let table = self.txn.open_table(None).unwrap();
+            self.txn.get(&table, key).unwrap()
+        }
+    }
+
+    let dir = tempdir().unwrap();
+    let db = Arc::new(Database::open(&dir).unwrap());
+    let txn = db.begin_rw_txn().unwrap();
+    txn.put(
+        &txn.open_table(None).unwrap(),
+        b"key1",
+        b"val1",
+        WriteFlags::empty(),
+    )
+    .unwrap();
+    txn.commit().unwrap();
+
+    let snapshot = Snapshot {
+        txn: db.begin_ro_txn_owned().unwrap(),
+    };
+    let txn = db.begin_rw_txn().unwrap();
+    txn.put(
+        &txn.open_table(None).unwrap(),
+        b"key2",
+        b"val2",
+        WriteFlags::empty(),
+    )
+    .unwrap();
+    txn.commit().unwrap();
+    drop(db);
+
+    assert_eq!(snapshot.get(b"key1").as_deref(), Some(&b"val1"[..]));
+    assert_eq!(snapshot.get(b"key2"), None);
+    let table = snapshot.txn.open_table(None).unwrap();
+    let mut cursor = snapshot.txn.cursor(&table).unwrap();
+    assert_eq!(
+        cursor.first().unwrap(),
+        Some((b"key1".to_vec(), b"val1".to_vec()))
+    );
+    assert_eq!(Arc::strong_count(snapshot.txn.database()), 1);
+}
+
+#[test]
+fn test_send_ro_txn() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    txn.put(&table, b"key", b"val", WriteFlags::empty())
+        .unwrap();
+    txn.commit().unwrap();
+
+    // A read-only transaction started on one thread is used and dropped on another.
+    let txn = db.begin_ro_txn().unwrap();
+    thread::scope(|scope| {
+        scope
+            .spawn(move || {
+                let table = txn.open_table(None).unwrap();
+                let mut cursor = txn.cursor(&table).unwrap();
+                // So are its cursors.
+                thread::scope(|scope| {
+                    scope.spawn(move || {
+                        assert_eq!(cursor.first().unwrap(), Some((*b"key", *b"val")));
+                    });
+                });
+                assert_eq!(txn.get(&table, b"key").unwrap(), Some(*b"val"));
+                drop(txn);
+            })
+            .join()
+            .unwrap();
+    });
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    txn.put(&table, b"key", b"new", WriteFlags::empty())
+        .unwrap();
+    txn.commit().unwrap();
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    assert_eq!(txn.get(&table, b"key").unwrap(), Some(*b"new"));
+}
+
+#[test]
+fn test_send_owned_ro_txn() {
+    let dir = tempdir().unwrap();
+    let db = Arc::new(Database::open(&dir).unwrap());
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    txn.put(&table, b"key", b"val", WriteFlags::empty())
+        .unwrap();
+    txn.commit().unwrap();
+
+    // Unlike a borrowing transaction, it can be handed to a thread which outlives the caller.
+    let txn = db.begin_ro_txn_owned().unwrap();
+    drop(db);
+    let value = thread::spawn(move || {
+        let table = txn.open_table(None).unwrap();
+        txn.get::<Vec<u8>>(&table, b"key").unwrap()
+    })
+    .join()
+    .unwrap();
+    assert_eq!(value.as_deref(), Some(&b"val"[..]));
+}
+
 #[test]
 fn test_concurrent_readers_single_writer() {
     let dir = tempdir().unwrap();
@@ -440,3 +602,1889 @@ fn test_stat_dupsort() {
         assert_eq!(stat.entries(), 8);
     }
 }
+
+#[test]
+fn test_dupsort_depthmask() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let plain = txn
+        .create_table(Some("plain"), TableFlags::empty())
+        .unwrap();
+    let table = txn
+        .create_table(Some("dups"), TableFlags::DUP_SORT)
+        .unwrap();
+    assert_eq!(txn.dupsort_depthmask(&table).unwrap(), 0);
+    txn.put(&table, b"single", b"value", WriteFlags::empty())
+        .unwrap();
+    assert_eq!(txn.dupsort_depthmask(&table).unwrap(), 0b1);
+    for i in 0..3u32 {
+        txn.put(&table, b"few", i.to_be_bytes(), WriteFlags::empty())
+            .unwrap();
+    }
+    assert_eq!(txn.dupsort_depthmask(&table).unwrap(), 0b11);
+    // Enough duplicates to need branch pages in the nested tree.
+    for i in 0..10_000u32 {
+        txn.put(
+            &table,
+            b"hot",
+            [i.to_be_bytes(); 16].concat(),
+            WriteFlags::empty(),
+        )
+        .unwrap();
+    }
+    let mask = txn.dupsort_depthmask(&table).unwrap();
+    assert_eq!(mask & 0b11, 0b11);
+    assert!(mask >> 2 != 0, "{mask:#b}");
+    assert!(matches!(
+        txn.dupsort_depthmask(&plain),
+        Err(Error::Incompatible)
+    ));
+    let id = txn.id();
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(Some("dups")).unwrap();
+    assert_eq!(txn.dupsort_depthmask(&table).unwrap(), mask);
+    let stat = txn.table_stat(&table).unwrap();
+    assert_eq!(stat.entries(), 10_004);
+    assert_eq!(stat.mod_txnid(), id);
+    // The nested trees count towards the pages of the table.
+    assert!(stat.branch_pages() > 0);
+}
+
+#[test]
+fn test_fixed_value_table() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_fixed_value_table::<16>(Some("fixed"), TableFlags::empty())
+        .unwrap();
+    let dup_table = txn
+        .create_fixed_value_table::<16>(Some("fixed_dup"), TableFlags::DUP_SORT)
+        .unwrap();
+    assert_eq!(
+        txn.table_flags(&dup_table).unwrap(),
+        TableFlags::DUP_SORT | TableFlags::DUP_FIXED
+    );
+    for i in 0..3_u8 {
+        table.put(&txn, [i], &[i; 16], WriteFlags::empty()).unwrap();
+        dup_table
+            .put(&txn, b"key", &[i; 16], WriteFlags::empty())
+            .unwrap();
+    }
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_fixed_value_table::<16>(Some("fixed")).unwrap();
+    assert_eq!(table.get(&txn, &[1]).unwrap(), Some([1; 16]));
+    assert_eq!(table.get(&txn, &[3]).unwrap(), None);
+
+    let items = table
+        .iter(&txn)
+        .unwrap()
+        .map(|r| r.unwrap().1)
+        .collect::<Vec<_>>();
+    assert_eq!(items, vec![[0; 16], [1; 16], [2; 16]]);
+
+    let dup_table = txn.open_fixed_value_table::<16>(Some("fixed_dup")).unwrap();
+    let items = dup_table
+        .iter(&txn)
+        .unwrap()
+        .map(|r| r.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        items,
+        vec![
+            (Cow::Borrowed(&b"key"[..]), [0; 16]),
+            (Cow::Borrowed(&b"key"[..]), [1; 16]),
+            (Cow::Borrowed(&b"key"[..]), [2; 16]),
+        ]
+    );
+}
+
+#[test]
+fn test_fixed_value_table_wrong_size() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    txn.put(&table, b"key1", [0; 16], WriteFlags::empty())
+        .unwrap();
+    txn.put(&table, b"key2", b"short", WriteFlags::empty())
+        .unwrap();
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_fixed_value_table::<16>(None).unwrap();
+    assert_eq!(table.get(&txn, b"key1").unwrap(), Some([0; 16]));
+    assert!(matches!(
+        table.get(&txn, b"key2").unwrap_err(),
+        Error::WrongValueSize {
+            expected: 16,
+            actual: 5
+        }
+    ));
+
+    let mut iter = table.iter(&txn).unwrap();
+    assert!(iter.next().unwrap().is_ok());
+    assert!(matches!(
+        iter.next().unwrap().unwrap_err(),
+        Error::WrongValueSize {
+            expected: 16,
+            actual: 5
+        }
+    ));
+}
+
+#[test]
+fn test_max_key_value_size() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(1),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    let dup_table = txn.create_table(Some("dup"), TableFlags::DUP_SORT).unwrap();
+
+    // Records over the limit trip an assertion inside MDBX rather than returning
+    // `Error::BadValSize`, so only records exactly at the limit are exercised here.
+    let max_key = txn.table_max_key_size(&table).unwrap();
+    assert_eq!(max_key, db.max_key_size(TableFlags::empty()).unwrap());
+    txn.put(&table, vec![1; max_key], b"val", WriteFlags::empty())
+        .unwrap();
+
+    let max_value = txn.table_max_value_size(&dup_table).unwrap();
+    assert!(max_value < db.max_value_size(TableFlags::empty()).unwrap());
+    txn.put(&dup_table, b"key", vec![1; max_value], WriteFlags::empty())
+        .unwrap();
+
+    // DUP_SORT tables have lower limits for keys as well as values.
+    assert_eq!(max_value, db.max_value_size(TableFlags::DUP_SORT).unwrap());
+    let max_dup_key = db.max_key_size(TableFlags::DUP_SORT).unwrap();
+    assert!(max_dup_key < max_key);
+    assert_eq!(txn.table_max_key_size(&dup_table).unwrap(), max_dup_key);
+    for value in [b"val1", b"val2"] {
+        txn.put(&dup_table, vec![2; max_dup_key], value, WriteFlags::empty())
+            .unwrap();
+    }
+
+    let page_size = db.info().unwrap().page_size() as usize;
+    for flags in [TableFlags::empty(), TableFlags::DUP_SORT] {
+        assert_eq!(
+            limits::max_key_size(page_size, flags).unwrap(),
+            db.max_key_size(flags).unwrap()
+        );
+        assert_eq!(
+            limits::max_value_size(page_size, flags).unwrap(),
+            db.max_value_size(flags).unwrap()
+        );
+    }
+}
+
+#[test]
+fn test_commit_with_latency() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    for i in 0..20_000u32 {
+        txn.put(&table, i.to_be_bytes(), [0; 32], WriteFlags::empty())
+            .unwrap();
+    }
+    let start = std::time::Instant::now();
+    let (_, latency) = txn.commit_with_latency().unwrap();
+    let elapsed = start.elapsed();
+
+    let stages = latency.preparation()
+        + latency.gc_wallclock()
+        + latency.audit()
+        + latency.write()
+        + latency.sync()
+        + latency.ending();
+    assert!(stages > std::time::Duration::ZERO);
+    assert!(latency.whole() >= stages / 2);
+    assert!(latency.whole() <= elapsed);
+}
+
+#[test]
+fn test_txn_id_and_info() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let mut last_id = 0;
+    for i in 0..3u32 {
+        let txn = db.begin_rw_txn().unwrap();
+        assert!(txn.id() > last_id);
+        last_id = txn.id();
+        assert!(!txn.flags().contains(TransactionFlags::READ_ONLY));
+
+        let table = txn.open_table(None).unwrap();
+        txn.put(&table, i.to_be_bytes(), b"val", WriteFlags::empty())
+            .unwrap();
+        assert!(txn.flags().contains(TransactionFlags::DIRTY));
+        let info = txn.info(false).unwrap();
+        assert_eq!(info.id(), last_id);
+        assert!(info.space_dirty() > 0);
+        txn.commit().unwrap();
+    }
+
+    let ro_txn = db.begin_ro_txn().unwrap();
+    assert!(ro_txn.flags().contains(TransactionFlags::READ_ONLY));
+    assert_eq!(ro_txn.id(), last_id);
+    assert_eq!(ro_txn.info(true).unwrap().reader_lag(), 0);
+
+    for i in 0..2u32 {
+        let txn = db.begin_rw_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        txn.put(&table, i.to_be_bytes(), b"new", WriteFlags::empty())
+            .unwrap();
+        txn.commit().unwrap();
+        assert_eq!(ro_txn.info(true).unwrap().reader_lag(), u64::from(i) + 1);
+    }
+}
+
+#[test]
+fn test_ro_txn_lag() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let ro_txn = db.begin_ro_txn().unwrap();
+    assert_eq!(ro_txn.lag().unwrap(), 0);
+    assert!(!ro_txn.is_stale(0).unwrap());
+
+    for i in 0..5u32 {
+        let txn = db.begin_rw_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        txn.put(&table, i.to_be_bytes(), b"val", WriteFlags::empty())
+            .unwrap();
+        txn.commit().unwrap();
+    }
+
+    assert_eq!(ro_txn.lag().unwrap(), 5);
+    assert!(ro_txn.is_stale(4).unwrap());
+    assert!(!ro_txn.is_stale(5).unwrap());
+}
+
+#[test]
+fn test_append() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_table(Some("plain"), TableFlags::empty())
+        .unwrap();
+    let dup_table = txn.create_table(Some("dup"), TableFlags::DUP_SORT).unwrap();
+    for i in 0..100u32 {
+        txn.append(&table, i.to_be_bytes(), b"val").unwrap();
+        txn.append_dup(&dup_table, b"key", i.to_be_bytes()).unwrap();
+    }
+    assert!(matches!(
+        txn.append(&table, 50u32.to_be_bytes(), b"val"),
+        Err(Error::KeyMismatch)
+    ));
+    assert!(matches!(
+        txn.append_dup(&dup_table, b"key", 50u32.to_be_bytes()),
+        Err(Error::KeyMismatch)
+    ));
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(Some("plain")).unwrap();
+    let keys = txn
+        .cursor(&table)
+        .unwrap()
+        .into_iter_start::<[u8; 4], ()>()
+        .map(|r| u32::from_be_bytes(r.unwrap().0))
+        .collect::<Vec<_>>();
+    assert_eq!(keys, (0..100).collect::<Vec<_>>());
+
+    let dup_table = txn.open_table(Some("dup")).unwrap();
+    let values = txn
+        .cursor(&dup_table)
+        .unwrap()
+        .into_iter_dup_of::<(), [u8; 4]>(b"key")
+        .map(|r| u32::from_be_bytes(r.unwrap().1))
+        .collect::<Vec<_>>();
+    assert_eq!(values, (0..100).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_replace() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    assert_eq!(
+        txn.replace::<Vec<u8>>(&table, b"key1", b"val1", WriteFlags::empty())
+            .unwrap(),
+        None
+    );
+    assert_eq!(
+        txn.replace(&table, b"key1", b"val2", WriteFlags::empty())
+            .unwrap(),
+        Some(*b"val1")
+    );
+
+    // larger than the initial buffer, while the page is dirty
+    let large = vec![7; 1000];
+    txn.put(&table, b"key2", &large, WriteFlags::empty())
+        .unwrap();
+    assert_eq!(
+        txn.replace::<Vec<u8>>(&table, b"key2", b"small", WriteFlags::empty())
+            .unwrap(),
+        Some(large.clone())
+    );
+    txn.put(&table, b"key2", &large, WriteFlags::empty())
+        .unwrap();
+    txn.commit().unwrap();
+
+    // and while the page is clean
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    assert_eq!(
+        txn.replace::<Cow<[u8]>>(&table, b"key2", b"small", WriteFlags::empty())
+            .unwrap(),
+        Some(Cow::Owned(large))
+    );
+    assert_eq!(txn.get(&table, b"key2").unwrap(), Some(*b"small"));
+    assert_eq!(txn.get(&table, b"key1").unwrap(), Some(*b"val2"));
+}
+
+#[test]
+fn test_replace_dup() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.create_table(None, TableFlags::DUP_SORT).unwrap();
+    for v in [b"val1", b"val2", b"val3"] {
+        txn.put(&table, b"key", v, WriteFlags::empty()).unwrap();
+    }
+    txn.put(&table, b"single", b"val1", WriteFlags::empty())
+        .unwrap();
+
+    txn.replace_dup(&table, b"key", b"val2", b"val4").unwrap();
+    assert!(matches!(
+        txn.replace_dup(&table, b"key", b"val2", b"val5"),
+        Err(Error::NotFound)
+    ));
+    let values = txn
+        .cursor(&table)
+        .unwrap()
+        .iter_dup_of::<(), [u8; 4]>(b"key")
+        .map(|r| r.unwrap().1)
+        .collect::<Vec<_>>();
+    assert_eq!(values, vec![*b"val1", *b"val3", *b"val4"]);
+
+    assert!(matches!(
+        txn.replace::<()>(&table, b"key", b"val5", WriteFlags::CURRENT),
+        Err(Error::Multival)
+    ));
+    assert_eq!(
+        txn.replace(&table, b"single", b"val2", WriteFlags::CURRENT)
+            .unwrap(),
+        Some(*b"val1")
+    );
+    assert_eq!(txn.get(&table, b"single").unwrap(), Some(*b"val2"));
+}
+
+#[test]
+fn test_sequence() {
+    let dir = tempdir().unwrap();
+
+    {
+        let db = Database::open(&dir).unwrap();
+        let mut last = None;
+        for _ in 0..3 {
+            let txn = db.begin_rw_txn().unwrap();
+            let table = txn.open_table(None).unwrap();
+            let value = txn.sequence(&table, 5).unwrap();
+            assert!(last.is_none_or(|last| value > last));
+            assert_eq!(txn.current_sequence(&table).unwrap(), value + 5);
+            last = Some(value);
+            txn.commit().unwrap();
+        }
+        assert_eq!(last, Some(10));
+
+        let txn = db.begin_rw_txn().unwrap();
+        let table = txn.open_table(None).unwrap();
+        assert!(matches!(
+            txn.sequence(&table, u64::MAX),
+            Err(Error::SequenceOverflow)
+        ));
+        assert_eq!(txn.current_sequence(&table).unwrap(), 15);
+    }
+
+    let db = Database::open(&dir).unwrap();
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    assert_eq!(txn.current_sequence(&table).unwrap(), 15);
+}
+
+#[test]
+fn test_canary() {
+    let dir = tempdir().unwrap();
+
+    let id = {
+        let db = Database::open(&dir).unwrap();
+        let txn = db.begin_rw_txn().unwrap();
+        assert_eq!(txn.canary().unwrap(), Canary::default());
+        txn.put_canary(&Canary {
+            x: 1,
+            y: 2,
+            z: 3,
+            v: 42,
+        })
+        .unwrap();
+        let id = txn.id();
+        assert_eq!(
+            txn.canary().unwrap(),
+            Canary {
+                x: 1,
+                y: 2,
+                z: 3,
+                v: id,
+            }
+        );
+
+        // Readers keep the markers of their snapshot.
+        let reader = db.begin_ro_txn().unwrap();
+        txn.commit().unwrap();
+        assert_eq!(reader.canary().unwrap(), Canary::default());
+        id
+    };
+
+    let db = Database::open(&dir).unwrap();
+    let txn = db.begin_ro_txn().unwrap();
+    assert_eq!(
+        txn.canary().unwrap(),
+        Canary {
+            x: 1,
+            y: 2,
+            z: 3,
+            v: id,
+        }
+    );
+}
+
+#[test]
+fn test_del_range() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_table(Some("plain"), TableFlags::empty())
+        .unwrap();
+    let dup_table = txn.create_table(Some("dup"), TableFlags::DUP_SORT).unwrap();
+    for i in 0..10u8 {
+        txn.put(&table, [i], b"val", WriteFlags::empty()).unwrap();
+        for v in [b"val1", b"val2", b"val3"] {
+            txn.put(&dup_table, [i], v, WriteFlags::empty()).unwrap();
+        }
+    }
+
+    let keys = |table: &Table| {
+        txn.cursor(table)
+            .unwrap()
+            .iter_start::<[u8; 1], ()>()
+            .map(|r| r.unwrap().0[0])
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(txn.del_range(&table, [2u8]..[4u8]).unwrap(), 2);
+    assert_eq!(keys(&table), vec![0, 1, 4, 5, 6, 7, 8, 9]);
+    assert_eq!(txn.del_range(&table, [4u8]..=[5u8]).unwrap(), 2);
+    assert_eq!(keys(&table), vec![0, 1, 6, 7, 8, 9]);
+    assert_eq!(
+        txn.del_range::<[u8; 1]>(&table, (Bound::Excluded([6]), Bound::Unbounded))
+            .unwrap(),
+        3
+    );
+    assert_eq!(keys(&table), vec![0, 1, 6]);
+    assert_eq!(txn.del_range(&table, [2u8]..[6u8]).unwrap(), 0);
+    assert_eq!(txn.del_range(&table, [3u8]..[3u8]).unwrap(), 0);
+    assert_eq!(txn.del_range::<[u8; 1]>(&table, ..=[0]).unwrap(), 1);
+    assert_eq!(keys(&table), vec![1, 6]);
+    assert_eq!(txn.del_range::<[u8; 1]>(&table, ..).unwrap(), 2);
+    assert!(keys(&table).is_empty());
+
+    assert_eq!(txn.del_range(&dup_table, [3u8]..=[5u8]).unwrap(), 9);
+    assert_eq!(
+        keys(&dup_table),
+        vec![0, 0, 0, 1, 1, 1, 2, 2, 2, 6, 6, 6, 7, 7, 7, 8, 8, 8, 9, 9, 9]
+    );
+    assert_eq!(txn.del_range::<[u8; 1]>(&dup_table, [8]..).unwrap(), 6);
+    assert_eq!(
+        keys(&dup_table),
+        vec![0, 0, 0, 1, 1, 1, 2, 2, 2, 6, 6, 6, 7, 7, 7]
+    );
+}
+
+#[test]
+fn test_len() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_table(Some("plain"), TableFlags::empty())
+        .unwrap();
+    let dup_table = txn.create_table(Some("dup"), TableFlags::DUP_SORT).unwrap();
+
+    assert!(txn.is_empty(&table).unwrap());
+    assert_eq!(txn.len(&table).unwrap(), 0);
+    assert_eq!(txn.key_count(&table).unwrap(), 0);
+    assert!(txn.is_empty(&dup_table).unwrap());
+    assert_eq!(txn.key_count(&dup_table).unwrap(), 0);
+
+    for i in 0..5u8 {
+        txn.put(&table, [i], b"val", WriteFlags::empty()).unwrap();
+        for v in [b"val1", b"val2", b"val3"] {
+            txn.put(&dup_table, [i], v, WriteFlags::empty()).unwrap();
+        }
+    }
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(Some("plain")).unwrap();
+    let dup_table = txn.open_table(Some("dup")).unwrap();
+
+    assert!(!txn.is_empty(&table).unwrap());
+    assert_eq!(txn.len(&table).unwrap(), 5);
+    assert_eq!(txn.key_count(&table).unwrap(), 5);
+    assert!(!txn.is_empty(&dup_table).unwrap());
+    assert_eq!(txn.len(&dup_table).unwrap(), 15);
+    assert_eq!(txn.key_count(&dup_table).unwrap(), 5);
+}
+
+#[test]
+fn test_estimate_range() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    for i in 0..10_000u32 {
+        txn.put(
+            &table,
+            i.to_be_bytes(),
+            i.to_be_bytes(),
+            WriteFlags::empty(),
+        )
+        .unwrap();
+    }
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+
+    let all = txn.estimate_range(&table, None, None).unwrap();
+    assert!((5_000..=20_000).contains(&all), "{all}");
+
+    let half = txn
+        .estimate_range(
+            &table,
+            Some((&2_500u32.to_be_bytes(), None)),
+            Some((&7_500u32.to_be_bytes(), None)),
+        )
+        .unwrap();
+    assert!((2_500..=10_000).contains(&half), "{half}");
+
+    let backwards = txn
+        .estimate_range(
+            &table,
+            Some((&7_500u32.to_be_bytes(), None)),
+            Some((&2_500u32.to_be_bytes(), None)),
+        )
+        .unwrap();
+    assert!(backwards < 0, "{backwards}");
+
+    let mut start = txn.cursor(&table).unwrap();
+    start.first::<(), ()>().unwrap();
+    let mut end = txn.cursor(&table).unwrap();
+    end.set_range::<(), ()>(&5_000u32.to_be_bytes()).unwrap();
+    let distance = start.estimate_distance(&end).unwrap();
+    assert!((2_500..=10_000).contains(&distance), "{distance}");
+
+    let step = start.estimate_set_range(&5_000u32.to_be_bytes()).unwrap();
+    assert!((2_500..=10_000).contains(&step), "{step}");
+    assert_eq!(
+        start.get_current::<[u8; 4], ()>().unwrap().unwrap().0,
+        0u32.to_be_bytes()
+    );
+}
+
+#[test]
+fn test_contains_key() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    txn.put(&table, b"key1", b"val1", WriteFlags::empty())
+        .unwrap();
+    assert!(txn.contains_key(&table, b"key1").unwrap());
+    assert!(!txn.contains_key(&table, b"key2").unwrap());
+    assert_eq!(txn.get::<()>(&table, b"key2").unwrap(), None);
+
+    // Genuine errors are not mistaken for a missing key: once a write fails, the transaction
+    // is unusable and lookups report it.
+    let value = [0u8; 1024];
+    let err = (0u32..)
+        .find_map(|i| {
+            txn.put(&table, i.to_be_bytes(), value, WriteFlags::empty())
+                .err()
+        })
+        .unwrap();
+    assert!(matches!(err, Error::MapFull), "{err:?}");
+    assert!(matches!(
+        txn.contains_key(&table, b"key1"),
+        Err(Error::BadTxn)
+    ));
+    assert!(matches!(txn.get::<()>(&table, b"key2"), Err(Error::BadTxn)));
+}
+
+#[test]
+fn test_first_last() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_table(Some("plain"), TableFlags::empty())
+        .unwrap();
+    let dup_table = txn.create_table(Some("dup"), TableFlags::DUP_SORT).unwrap();
+
+    assert_eq!(txn.first::<(), ()>(&table).unwrap(), None);
+    assert_eq!(txn.last::<(), ()>(&table).unwrap(), None);
+    assert_eq!(txn.first_dup::<()>(&dup_table, b"key").unwrap(), None);
+    assert_eq!(txn.last_dup::<()>(&dup_table, b"key").unwrap(), None);
+
+    txn.put(&table, b"key2", b"val2", WriteFlags::empty())
+        .unwrap();
+    assert_eq!(txn.first(&table).unwrap(), Some((*b"key2", *b"val2")));
+    assert_eq!(txn.last(&table).unwrap(), Some((*b"key2", *b"val2")));
+
+    txn.put(&table, b"key1", b"val1", WriteFlags::empty())
+        .unwrap();
+    txn.put(&table, b"key3", b"val3", WriteFlags::empty())
+        .unwrap();
+    assert_eq!(txn.first(&table).unwrap(), Some((*b"key1", *b"val1")));
+    assert_eq!(txn.last(&table).unwrap(), Some((*b"key3", *b"val3")));
+
+    for (key, val) in [
+        (b"key1", b"val2"),
+        (b"key1", b"val1"),
+        (b"key2", b"val3"),
+        (b"key2", b"val1"),
+        (b"key2", b"val2"),
+    ] {
+        txn.put(&dup_table, key, val, WriteFlags::empty()).unwrap();
+    }
+    assert_eq!(txn.first(&dup_table).unwrap(), Some((*b"key1", *b"val1")));
+    assert_eq!(txn.last(&dup_table).unwrap(), Some((*b"key2", *b"val3")));
+    assert_eq!(txn.first_dup(&dup_table, b"key1").unwrap(), Some(*b"val1"));
+    assert_eq!(txn.last_dup(&dup_table, b"key1").unwrap(), Some(*b"val2"));
+    assert_eq!(txn.first_dup(&dup_table, b"key2").unwrap(), Some(*b"val1"));
+    assert_eq!(txn.last_dup(&dup_table, b"key2").unwrap(), Some(*b"val3"));
+    assert_eq!(txn.last_dup::<()>(&dup_table, b"key3").unwrap(), None);
+}
+
+#[test]
+fn test_dup_count() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_table(Some("plain"), TableFlags::empty())
+        .unwrap();
+    let dup_table = txn.create_table(Some("dup"), TableFlags::DUP_SORT).unwrap();
+
+    txn.put(&table, b"key1", b"val1", WriteFlags::empty())
+        .unwrap();
+    assert_eq!(txn.dup_count(&table, b"key1").unwrap(), 1);
+    assert_eq!(txn.dup_count(&table, b"key2").unwrap(), 0);
+
+    for i in 0..100u32 {
+        txn.put(&dup_table, b"key1", i.to_be_bytes(), WriteFlags::empty())
+            .unwrap();
+    }
+    txn.put(&dup_table, b"key2", b"val", WriteFlags::empty())
+        .unwrap();
+    assert_eq!(txn.dup_count(&dup_table, b"key1").unwrap(), 100);
+    assert_eq!(txn.dup_count(&dup_table, b"key2").unwrap(), 1);
+    assert_eq!(txn.dup_count(&dup_table, b"key3").unwrap(), 0);
+}
+
+#[test]
+fn test_custom_comparators() {
+    struct Reversed;
+
+    impl Comparator for Reversed {
+        fn compare(a: &[u8], b: &[u8]) -> std::cmp::Ordering {
+            b.cmp(a)
+        }
+    }
+
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(1),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_table_with_comparators(
+            Some("reversed"),
+            TableFlags::DUP_SORT,
+            Some(Reversed::as_raw()),
+            Some(Reversed::as_raw()),
+        )
+        .unwrap();
+    for key in [b"key1", b"key3", b"key2"] {
+        for val in [b"val1", b"val2"] {
+            txn.put(&table, key, val, WriteFlags::empty()).unwrap();
+        }
+    }
+
+    let mut cursor = txn.cursor(&table).unwrap();
+    assert_eq!(
+        cursor
+            .iter_start::<[u8; 4], [u8; 4]>()
+            .collect::<Result<Vec<_>>>()
+            .unwrap(),
+        vec![
+            (*b"key3", *b"val2"),
+            (*b"key3", *b"val1"),
+            (*b"key2", *b"val2"),
+            (*b"key2", *b"val1"),
+            (*b"key1", *b"val2"),
+            (*b"key1", *b"val1"),
+        ]
+    );
+    assert_eq!(
+        cursor.set_range(b"key25").unwrap(),
+        Some((*b"key2", *b"val2"))
+    );
+    assert_eq!(cursor.set_range::<(), ()>(b"key0").unwrap(), None);
+    drop(cursor);
+    txn.commit().unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    txn.create_table_with_comparators(
+        Some("reversed"),
+        TableFlags::DUP_SORT,
+        Some(Reversed::as_raw()),
+        Some(Reversed::as_raw()),
+    )
+    .unwrap();
+    assert!(matches!(
+        txn.create_table_with_comparators(
+            Some("reversed"),
+            TableFlags::DUP_SORT,
+            Some(Reversed::as_raw()),
+            None
+        ),
+        Err(Error::Incompatible)
+    ));
+}
+
+#[test]
+fn test_integer_table() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let keys = [300u64, 2, u64::MAX, 256, 1, 70_000];
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_integer_table(Some("int"), TableFlags::empty())
+        .unwrap();
+    for key in keys {
+        table
+            .put(&txn, key, key.to_string(), WriteFlags::empty())
+            .unwrap();
+    }
+    assert!(table.del(&txn, 2, None).unwrap());
+    txn.create_table(Some("plain"), TableFlags::empty())
+        .unwrap();
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    assert!(matches!(
+        txn.open_integer_table(Some("plain")),
+        Err(Error::Incompatible)
+    ));
+
+    let table = txn.open_integer_table(Some("int")).unwrap();
+    assert_eq!(
+        table.get::<Vec<u8>, _, _>(&txn, 256).unwrap(),
+        Some(b"256".to_vec())
+    );
+    assert_eq!(table.get::<Vec<u8>, _, _>(&txn, 2).unwrap(), None);
+    assert_eq!(
+        table
+            .iter::<Vec<u8>, _, _>(&txn)
+            .unwrap()
+            .collect::<Result<Vec<_>>>()
+            .unwrap(),
+        [1u64, 256, 300, 70_000, u64::MAX]
+            .map(|key| (key, key.to_string().into_bytes()))
+            .to_vec()
+    );
+}
+
+#[test]
+fn test_retain_drain() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_table(Some("plain"), TableFlags::empty())
+        .unwrap();
+    let dup_table = txn.create_table(Some("dup"), TableFlags::DUP_SORT).unwrap();
+    for i in 0..1000u32 {
+        txn.put(
+            &table,
+            i.to_be_bytes(),
+            i.to_le_bytes(),
+            WriteFlags::empty(),
+        )
+        .unwrap();
+    }
+    for i in 0..10u8 {
+        for v in 0..10u8 {
+            txn.put(&dup_table, [i], [v], WriteFlags::empty()).unwrap();
+        }
+    }
+
+    let deleted = txn
+        .retain(&table, |key, value| {
+            assert_eq!(
+                u32::from_be_bytes(key.try_into().unwrap()).to_le_bytes(),
+                value
+            );
+            key[3] % 2 == 0
+        })
+        .unwrap();
+    assert_eq!(deleted, 500);
+    assert_eq!(
+        txn.cursor(&table)
+            .unwrap()
+            .iter_start::<[u8; 4], ()>()
+            .map(|r| u32::from_be_bytes(r.unwrap().0))
+            .collect::<Vec<_>>(),
+        (0..1000).step_by(2).collect::<Vec<_>>()
+    );
+
+    assert_eq!(
+        txn.retain(&dup_table, |_, value| value[0] % 2 == 0)
+            .unwrap(),
+        50
+    );
+    assert_eq!(txn.len(&dup_table).unwrap(), 50);
+    assert_eq!(txn.key_count(&dup_table).unwrap(), 10);
+    assert!(txn
+        .cursor(&dup_table)
+        .unwrap()
+        .iter_start::<[u8; 1], [u8; 1]>()
+        .all(|r| r.unwrap().1[0] % 2 == 0));
+
+    let drained = txn
+        .drain(&dup_table, [3u8]..=[4u8], |_, value| value[0] >= 6)
+        .unwrap();
+    assert_eq!(
+        drained,
+        vec![
+            (vec![3], vec![6]),
+            (vec![3], vec![8]),
+            (vec![4], vec![6]),
+            (vec![4], vec![8]),
+        ]
+    );
+    assert_eq!(txn.dup_count(&dup_table, &[2]).unwrap(), 5);
+    assert_eq!(txn.dup_count(&dup_table, &[3]).unwrap(), 3);
+    assert_eq!(txn.dup_count(&dup_table, &[4]).unwrap(), 3);
+    assert_eq!(txn.dup_count(&dup_table, &[5]).unwrap(), 5);
+
+    assert_eq!(
+        txn.drain::<[u8; 4]>(&table, .., |_, _| true).unwrap().len(),
+        500
+    );
+    assert!(txn.is_empty(&table).unwrap());
+}
+
+#[test]
+fn test_utf8_codecs() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    txn.put(&table, b"valid", "héllo", WriteFlags::empty())
+        .unwrap();
+    txn.put(&table, b"invalid", b"ab\xffcd", WriteFlags::empty())
+        .unwrap();
+
+    fn check<K: TransactionKind>(txn: &Transaction<K, NoWriteMap>, borrowed: bool) {
+        let table = txn.open_table(None).unwrap();
+
+        assert_eq!(
+            txn.get::<String>(&table, b"valid").unwrap().unwrap(),
+            "héllo"
+        );
+        let strict = txn.get::<Utf8Strict>(&table, b"valid").unwrap().unwrap();
+        assert_eq!(*strict, "héllo");
+        assert_eq!(matches!(strict.0, Cow::Borrowed(_)), borrowed);
+        let lossy = txn.get::<Utf8Lossy>(&table, b"valid").unwrap().unwrap();
+        assert_eq!(*lossy, "héllo");
+        assert_eq!(matches!(lossy.0, Cow::Borrowed(_)), borrowed);
+
+        for err in [
+            txn.get::<String>(&table, b"invalid").unwrap_err(),
+            txn.get::<Utf8Strict>(&table, b"invalid").unwrap_err(),
+        ] {
+            match err {
+                Error::DecodeError(e) => {
+                    assert_eq!(e.downcast_ref::<Utf8Error>().unwrap().valid_up_to(), 2)
+                }
+                other => panic!("unexpected {other:?}"),
+            }
+        }
+        let lossy = txn.get::<Utf8Lossy>(&table, b"invalid").unwrap().unwrap();
+        assert_eq!(*lossy, "ab\u{FFFD}cd");
+    }
+
+    check(&txn, false);
+    txn.commit().unwrap();
+    check(&db.begin_ro_txn().unwrap(), true);
+}
+
+#[test]
+fn test_get_owned_borrowed() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            mode: Mode::ReadWrite(ReadWriteOptions {
+                max_size: Some(64 << 20),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let value = (0..16 << 20).map(|i| i as u8).collect::<Vec<_>>();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    txn.put(&table, b"big", &value, WriteFlags::empty())
+        .unwrap();
+    txn.put(&table, b"empty", b"", WriteFlags::empty()).unwrap();
+    // Values written in this transaction live in dirty pages, which are not borrowable.
+    assert!(matches!(
+        txn.get::<&[u8]>(&table, b"big"),
+        Err(Error::CannotBorrow)
+    ));
+    assert!(matches!(
+        txn.get::<Cow<[u8]>>(&table, b"big").unwrap().unwrap(),
+        Cow::Owned(_)
+    ));
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    let borrowed = txn.get::<&[u8]>(&table, b"big").unwrap().unwrap();
+    let owned = txn.get::<Vec<u8>>(&table, b"big").unwrap().unwrap();
+    assert_eq!(borrowed, value);
+    assert_eq!(owned, value);
+    // Borrowing points into the memory map every time, while owning copies.
+    assert_eq!(
+        borrowed.as_ptr(),
+        txn.get::<&[u8]>(&table, b"big").unwrap().unwrap().as_ptr()
+    );
+    assert_ne!(borrowed.as_ptr(), owned.as_ptr());
+    assert_eq!(txn.get::<&[u8]>(&table, b"empty").unwrap(), Some(&[][..]));
+    drop(txn);
+
+    // The owned copy outlives the transaction.
+    assert_eq!(owned.len(), 16 << 20);
+
+    // Values untouched by a read-write transaction can be borrowed there as well.
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    assert_eq!(
+        txn.get::<&[u8]>(&table, b"big").unwrap().unwrap().len(),
+        16 << 20
+    );
+}
+
+#[test]
+fn test_error_details() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(1),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let err = txn.open_table(Some("missing")).unwrap_err();
+    assert!(err.is_not_found());
+    assert_eq!(err.code(), -30798);
+    assert_eq!(
+        err.to_string(),
+        "MDBX_NOTFOUND: No matching key/data pair found"
+    );
+
+    let table = txn.open_table(None).unwrap();
+    txn.put(&table, b"key", b"val", WriteFlags::empty())
+        .unwrap();
+    let err = txn
+        .put(&table, b"key", b"val", WriteFlags::NO_OVERWRITE)
+        .unwrap_err();
+    assert!(err.is_key_exist());
+    assert_eq!(err.code(), -30799);
+    assert_eq!(
+        err.to_string(),
+        "MDBX_KEYEXIST: Key/data pair already exists"
+    );
+    assert_eq!(
+        std::io::Error::from(err).kind(),
+        std::io::ErrorKind::AlreadyExists
+    );
+
+    let value = [0u8; 1024];
+    let err = (0u32..)
+        .find_map(|i| {
+            txn.put(&table, i.to_be_bytes(), value, WriteFlags::empty())
+                .err()
+        })
+        .unwrap();
+    assert!(err.is_map_full());
+    assert!(!err.is_busy());
+    assert_eq!(err.code(), -30792);
+    assert_eq!(
+        err.to_string(),
+        "MDBX_MAP_FULL: Environment mapsize limit reached"
+    );
+}
+
+#[test]
+fn test_dump_load() {
+    type Items = Vec<(Vec<u8>, Vec<u8>)>;
+
+    fn items(db: &Database, name: &str) -> (TableFlags, Items) {
+        let txn = db.begin_ro_txn().unwrap();
+        let table = txn.open_table(Some(name)).unwrap();
+        let items = txn
+            .cursor(&table)
+            .unwrap()
+            .iter_start()
+            .collect::<Result<Vec<_>>>()
+            .unwrap();
+        (txn.table_flags(&table).unwrap(), items)
+    }
+
+    let options = DatabaseOptions {
+        max_tables: Some(4),
+        ..Default::default()
+    };
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(&dir, options.clone()).unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_table(Some("dups"), TableFlags::DUP_SORT)
+        .unwrap();
+    for (key, value) in [
+        (&b"key1"[..], &b"val1"[..]),
+        (b"key1", b"val2"),
+        (b"key1", b"with space\\backslash"),
+        (b"\x00\xff\n", b"\x01\x02\r\n"),
+        (b"key2", b""),
+    ] {
+        txn.put(&table, key, value, WriteFlags::empty()).unwrap();
+    }
+    txn.commit().unwrap();
+    let expected = items(&db, "dups");
+    assert_eq!(expected.1.len(), 5);
+
+    for format in [DumpFormat::Text, DumpFormat::Binary] {
+        let mut dump = Vec::new();
+        let txn = db.begin_ro_txn().unwrap();
+        let table = txn.open_table(Some("dups")).unwrap();
+        assert_eq!(txn.dump_to(&table, &mut dump, format, None).unwrap(), 5);
+        drop(txn);
+
+        let other_dir = tempdir().unwrap();
+        let other = Database::open_with_options(&other_dir, options.clone()).unwrap();
+        let txn = other.begin_rw_txn().unwrap();
+        assert_eq!(
+            txn.load_from(Some("loaded"), &dump[..], format, None)
+                .unwrap(),
+            5
+        );
+        txn.commit().unwrap();
+        assert_eq!(items(&other, "loaded"), expected);
+    }
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(Some("dups")).unwrap();
+    let mut dump = Vec::new();
+    txn.dump_to(&table, &mut dump, DumpFormat::Text, None)
+        .unwrap();
+    drop(txn);
+    let dump = String::from_utf8(dump).unwrap();
+    assert!(dump.starts_with("VERSION=3\nformat=print\ntype=btree\nduplicates=1\nHEADER=END\n"));
+    assert!(dump.contains("\n \\00\\ff\\0a\n \\01\\02\\0d\\0a\n"));
+    assert!(dump.contains("\n with space\\\\backslash\n"));
+    assert!(dump.ends_with("\n key2\n \nDATA=END\n"));
+
+    // Malformed input reports where parsing failed.
+    let txn = db.begin_rw_txn().unwrap();
+    for (input, format, message) in [
+        (
+            &b"VERSION=3\nHEADER=END\n key\n \\zz\nDATA=END\n"[..],
+            DumpFormat::Text,
+            "malformed dump at line 4: invalid escape sequence",
+        ),
+        (
+            b"VERSION=3\nHEADER=END\n key\n",
+            DumpFormat::Text,
+            "malformed dump at line 4: key without a value",
+        ),
+        (
+            b"VERSION=2\n",
+            DumpFormat::Text,
+            "malformed dump at line 1: unsupported VERSION 2",
+        ),
+        (
+            b"MDBXDUMP\0\0\0\0\x03\0\0\0\0\0\0\0abc\x05\0\0\0\0\0\0\0ab",
+            DumpFormat::Binary,
+            "malformed dump at offset 31: expected 5 bytes of data",
+        ),
+        (
+            b"LMDBDUMP\0\0\0\0",
+            DumpFormat::Binary,
+            "malformed dump at offset 0: missing MDBXDUMP header",
+        ),
+    ] {
+        let err = txn.load_from(Some("bad"), input, format, None).unwrap_err();
+        assert!(matches!(err, Error::DecodeError(_)));
+        assert_eq!(err.to_string(), message);
+    }
+}
+
+#[test]
+fn test_table_handle_cache() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(4),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    // Handles of tables created by aborted transactions are not cached.
+    let txn = db.begin_rw_txn().unwrap();
+    txn.create_table(Some("table"), TableFlags::empty())
+        .unwrap();
+    txn.open_table(Some("table")).unwrap();
+    drop(txn);
+    assert_eq!(db.cached_tables(), 0);
+    let txn = db.begin_ro_txn().unwrap();
+    assert!(matches!(
+        txn.open_table(Some("table")),
+        Err(Error::NotFound)
+    ));
+    drop(txn);
+
+    let txn = db.begin_rw_txn().unwrap();
+    let dbi = txn
+        .create_table(Some("table"), TableFlags::empty())
+        .unwrap()
+        .dbi();
+    assert_eq!(db.cached_tables(), 0);
+    txn.commit().unwrap();
+    assert_eq!(db.cached_tables(), 1);
+
+    let txn = db.begin_ro_txn().unwrap();
+    assert_eq!(txn.open_table(Some("table")).unwrap().dbi(), dbi);
+    drop(txn);
+
+    // Neither are those created by nested transactions.
+    let mut txn = db.begin_rw_txn().unwrap();
+    let nested = txn.begin_nested_txn().unwrap();
+    nested
+        .create_table(Some("nested"), TableFlags::empty())
+        .unwrap();
+    nested.commit().unwrap();
+    drop(txn);
+    assert_eq!(db.cached_tables(), 1);
+    let txn = db.begin_ro_txn().unwrap();
+    assert!(matches!(
+        txn.open_table(Some("nested")),
+        Err(Error::NotFound)
+    ));
+    drop(txn);
+
+    // Dropped tables are removed from the cache.
+    let mut txn = db.begin_rw_txn().unwrap();
+    unsafe { txn.drop_table_by_name("table") }.unwrap();
+    assert_eq!(db.cached_tables(), 0);
+    txn.commit().unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    assert!(matches!(
+        txn.open_table(Some("table")),
+        Err(Error::NotFound)
+    ));
+    let table = txn
+        .create_table(Some("table"), TableFlags::DUP_SORT)
+        .unwrap();
+    txn.put(&table, b"key", b"value1", WriteFlags::empty())
+        .unwrap();
+    txn.put(&table, b"key", b"value2", WriteFlags::empty())
+        .unwrap();
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(Some("table")).unwrap();
+    assert_eq!(txn.table_flags(&table).unwrap(), TableFlags::DUP_SORT);
+    assert_eq!(txn.len(&table).unwrap(), 2);
+    assert_eq!(db.cached_tables(), 1);
+}
+
+#[test]
+fn test_table_handle_cache_older_snapshot() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(1),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let old = db.begin_ro_txn().unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_table(Some("table"), TableFlags::empty())
+        .unwrap();
+    txn.put(&table, b"key", b"val", WriteFlags::empty())
+        .unwrap();
+    txn.commit().unwrap();
+    assert_eq!(db.cached_tables(), 1);
+
+    // The cached handle does not make the table visible to a snapshot predating it.
+    assert!(matches!(
+        old.open_table(Some("table")),
+        Err(Error::NotFound)
+    ));
+    drop(old);
+    assert_eq!(db.cached_tables(), 1);
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(Some("table")).unwrap();
+    assert_eq!(txn.get(&table, b"key").unwrap(), Some(*b"val"));
+}
+
+#[test]
+fn test_bulk_load() {
+    type Items = Vec<(Vec<u8>, Vec<u8>)>;
+
+    fn items(db: &Database, name: &str) -> Items {
+        let txn = db.begin_ro_txn().unwrap();
+        let table = txn.open_table(Some(name)).unwrap();
+        let mut cursor = txn.cursor(&table).unwrap();
+        cursor.iter_start().collect::<Result<Items>>().unwrap()
+    }
+
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(5),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    txn.create_table(Some("plain"), TableFlags::empty())
+        .unwrap();
+    txn.create_table(Some("dups"), TableFlags::DUP_SORT)
+        .unwrap();
+    txn.create_table(Some("fixed"), TableFlags::DUP_SORT | TableFlags::DUP_FIXED)
+        .unwrap();
+    txn.create_table(Some("chunks"), TableFlags::empty())
+        .unwrap();
+    txn.commit().unwrap();
+
+    let plain: Items = (0..1000u32)
+        .map(|i| (i.to_be_bytes().to_vec(), format!("value{i}").into_bytes()))
+        .collect();
+    assert_eq!(
+        db.bulk_load(Some("plain"), plain.clone(), Some(64), None)
+            .unwrap(),
+        1000
+    );
+    assert_eq!(items(&db, "plain"), plain);
+
+    // Keys repeat for duplicates, with fixed-size values stored in runs of differing lengths.
+    let dups: Items = (0..100u32)
+        .flat_map(|i| (0..i % 7).map(move |j| (i.to_be_bytes().to_vec(), vec![j as u8; 4])))
+        .collect();
+    for name in ["dups", "fixed"] {
+        assert_eq!(
+            db.bulk_load(Some(name), dups.clone(), None, None).unwrap(),
+            dups.len() as u64
+        );
+        assert_eq!(items(&db, name), dups);
+    }
+
+    // Out of order input reports the offending item.
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.create_table(
+        Some("unsorted"),
+        TableFlags::DUP_SORT | TableFlags::DUP_FIXED,
+    );
+    let table = table.unwrap();
+    let input = [(b"a", b"1"), (b"b", b"1"), (b"b", b"2"), (b"b", b"2")];
+    assert!(matches!(
+        txn.bulk_load(&table, input, None),
+        Err(Error::Unsorted { index: 3 })
+    ));
+    // The items before it were stored.
+    assert_eq!(
+        txn.cursor(&table).unwrap().iter_start::<(), ()>().count(),
+        3
+    );
+    assert!(matches!(
+        txn.bulk_load(&table, [(b"c", b"1"), (b"a", b"2")], None),
+        Err(Error::Unsorted { index: 1 })
+    ));
+    // Input continues after the data already in the table.
+    assert!(matches!(
+        txn.bulk_load(&table, [(b"b", b"1")], None),
+        Err(Error::Unsorted { index: 0 })
+    ));
+    let plain = txn.open_table(Some("plain")).unwrap();
+    assert!(matches!(
+        txn.bulk_load(&plain, [(999u32.to_be_bytes(), b"value")], None),
+        Err(Error::Unsorted { index: 0 })
+    ));
+    assert_eq!(
+        txn.bulk_load(&plain, [(1000u32.to_be_bytes(), b"value")], None)
+            .unwrap(),
+        1
+    );
+    drop(txn);
+
+    // Committed chunks are kept when a later one is out of order.
+    let err = db
+        .bulk_load(
+            Some("chunks"),
+            (0..10u8).map(|i| ([i.min(5)], [i])),
+            Some(4),
+            None,
+        )
+        .unwrap_err();
+    assert!(matches!(err, Error::Unsorted { index: 6 }));
+    assert_eq!(items(&db, "chunks").len(), 4);
+}
+
+#[test]
+fn test_cancel_load_and_dump() {
+    /// Cancels the token on the `after`-th read or write, i.e. once a buffer is full.
+    struct Tripwire<T> {
+        inner: T,
+        token: CancellationToken,
+        after: usize,
+    }
+
+    impl<T> Tripwire<T> {
+        fn tick(&mut self) {
+            self.after = self.after.saturating_sub(1);
+            if self.after == 0 {
+                self.token.cancel();
+            }
+        }
+    }
+
+    impl<T: std::io::Read> std::io::Read for Tripwire<T> {
+        fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.tick();
+            self.inner.read(buf)
+        }
+    }
+
+    impl<T: Write> Write for Tripwire<T> {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.tick();
+            self.inner.write(buf)
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            self.inner.flush()
+        }
+    }
+
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(3),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let items = (0..10_000u32).map(|i| (i.to_be_bytes(), i.to_le_bytes()));
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn
+        .create_table(Some("items"), TableFlags::empty())
+        .unwrap();
+    txn.create_table(Some("resumed"), TableFlags::empty())
+        .unwrap();
+
+    // Bulk loads stop before the next item, keeping those stored so far.
+    let token = CancellationToken::new();
+    let cancelling = items.clone().inspect(|(key, _)| {
+        if key == &100u32.to_be_bytes() {
+            token.cancel();
+        }
+    });
+    let err = txn.bulk_load(&table, cancelling, Some(&token)).unwrap_err();
+    assert!(matches!(err, Error::Cancelled { progress: 101 }));
+    assert_eq!(txn.table_stat(&table).unwrap().entries(), 101);
+    txn.commit().unwrap();
+
+    // The database commits the items stored, so that the load can resume after them.
+    let token = CancellationToken::new();
+    let cancelling = items.clone().inspect(|(key, _)| {
+        if key == &2500u32.to_be_bytes() {
+            token.cancel();
+        }
+    });
+    let err = db
+        .bulk_load(Some("resumed"), cancelling, Some(1000), Some(&token))
+        .unwrap_err();
+    let Error::Cancelled { progress } = err else {
+        panic!("{err:?}");
+    };
+    assert_eq!(progress, 2501);
+    db.bulk_load(Some("resumed"), items.skip(2501), None, None)
+        .unwrap();
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(Some("resumed")).unwrap();
+    assert_eq!(txn.table_stat(&table).unwrap().entries(), 10_000);
+
+    // Dumps flush the items written before the cancellation, which then load as a whole.
+    for format in [DumpFormat::Text, DumpFormat::Binary] {
+        let token = CancellationToken::new();
+        let mut dump = Tripwire {
+            inner: Vec::new(),
+            token: token.clone(),
+            after: 3,
+        };
+        let err = txn
+            .dump_to(&table, &mut dump, format, Some(&token))
+            .unwrap_err();
+        let Error::Cancelled { progress } = err else {
+            panic!("{err:?}");
+        };
+        assert!(0 < progress && progress < 10_000, "{progress}");
+        if format == DumpFormat::Text {
+            dump.inner.extend_from_slice(b"DATA=END\n");
+        }
+
+        let other_dir = tempdir().unwrap();
+        let other = Database::open(&other_dir).unwrap();
+        let other_txn = other.begin_rw_txn().unwrap();
+        assert_eq!(
+            other_txn
+                .load_from(None, &dump.inner[..], format, None)
+                .unwrap(),
+            progress
+        );
+
+        // Loads read in chunks and stop before the next item after the second one.
+        let token = CancellationToken::new();
+        let input = Tripwire {
+            inner: &dump.inner[..],
+            token: token.clone(),
+            after: 2,
+        };
+        let err = other_txn
+            .load_from(None, input, format, Some(&token))
+            .unwrap_err();
+        assert!(
+            matches!(err, Error::Cancelled { progress: loaded } if 0 < loaded && loaded < progress),
+            "{err:?}"
+        );
+    }
+}
+
+#[test]
+fn test_with_txn() {
+    #[derive(Debug)]
+    enum AppError {
+        Db(Error),
+        Invalid,
+    }
+
+    impl From<Error> for AppError {
+        fn from(e: Error) -> Self {
+            AppError::Db(e)
+        }
+    }
+
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+    let get = |key: &[u8]| {
+        db.with_ro_txn(|txn| {
+            let table = txn.open_table(None)?;
+            txn.get::<Vec<u8>>(&table, key)
+        })
+        .unwrap()
+    };
+
+    // Committed when the closure succeeds.
+    let value = db
+        .with_rw_txn(|txn| {
+            let table = txn.open_table(None)?;
+            txn.put(&table, b"key", b"val", WriteFlags::empty())?;
+            Ok::<_, AppError>(42)
+        })
+        .unwrap();
+    assert_eq!(value, 42);
+    assert_eq!(get(b"key"), Some(b"val".to_vec()));
+
+    // Aborted when it fails.
+    let err = db
+        .with_rw_txn(|txn| {
+            let table = txn.open_table(None)?;
+            txn.put(&table, b"err", b"val", WriteFlags::empty())?;
+            Err::<(), _>(AppError::Invalid)
+        })
+        .unwrap_err();
+    assert!(matches!(err, AppError::Invalid));
+    assert_eq!(get(b"err"), None);
+
+    // Or panics.
+    let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        db.with_rw_txn(|txn| {
+            let table = txn.open_table(None)?;
+            txn.put(&table, b"panic", b"val", WriteFlags::empty())?;
+            panic!("failed");
+            #[allow(unreachable_code)]
+            Ok::<_, Error>(())
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(get(b"panic"), None);
+
+    // Nesting write transactions fails instead of deadlocking.
+    let err = db
+        .with_rw_txn(|_| db.with_rw_txn(|_| Ok::<_, AppError>(())))
+        .unwrap_err();
+    assert!(matches!(err, AppError::Db(Error::TxnOverlapping)));
+    db.with_rw_txn(|txn| {
+        let table = txn.open_table(None)?;
+        txn.del(&table, b"key", None)?;
+        Ok::<_, Error>(())
+    })
+    .unwrap();
+    assert_eq!(get(b"key"), None);
+}
+
+#[test]
+fn test_get_both() {
+    let dir = tempdir().unwrap();
+    let db = Database::open(&dir).unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.create_table(None, TableFlags::DUP_SORT).unwrap();
+    for (key, value) in [(b"key1", b"val1"), (b"key1", b"val3"), (b"key2", b"val2")] {
+        txn.put(&table, key, value, WriteFlags::empty()).unwrap();
+    }
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    // An exact duplicate.
+    assert_eq!(
+        txn.get_both(&table, b"key1", b"val3").unwrap(),
+        Some(*b"val3")
+    );
+    assert_eq!(
+        txn.get_both_range(&table, b"key1", b"val1").unwrap(),
+        Some(*b"val1")
+    );
+    // A range lookup landing on a later duplicate.
+    assert_eq!(
+        txn.get_both_range(&table, b"key1", b"val2").unwrap(),
+        Some(*b"val3")
+    );
+    // A missing duplicate of an existing key.
+    assert_eq!(txn.get_both::<()>(&table, b"key1", b"val2").unwrap(), None);
+    assert_eq!(
+        txn.get_both_range::<()>(&table, b"key1", b"val4").unwrap(),
+        None
+    );
+    // A missing key.
+    assert_eq!(txn.get_both::<()>(&table, b"key0", b"val1").unwrap(), None);
+    assert_eq!(
+        txn.get_both_range::<()>(&table, b"key3", b"").unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_put_no_overwrite() {
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(2),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+
+    assert_eq!(
+        txn.put_no_overwrite::<()>(&table, b"key", b"val1").unwrap(),
+        None
+    );
+    assert_eq!(
+        txn.put_no_overwrite(&table, b"key", b"val2").unwrap(),
+        Some(*b"val1")
+    );
+    assert_eq!(txn.get(&table, b"key").unwrap(), Some(*b"val1"));
+
+    let mut cursor = txn.cursor(&table).unwrap();
+    assert_eq!(
+        cursor.put_no_overwrite::<()>(b"key2", b"val1").unwrap(),
+        None
+    );
+    assert_eq!(
+        cursor
+            .put_no_overwrite::<Vec<u8>>(b"key2", b"val2")
+            .unwrap(),
+        Some(b"val1".to_vec())
+    );
+    assert_eq!(cursor.get_current().unwrap(), Some((*b"key2", *b"val1")));
+    drop(cursor);
+
+    // Duplicates are not added to existing keys, which report their first duplicate.
+    for flags in [
+        TableFlags::DUP_SORT,
+        TableFlags::DUP_SORT | TableFlags::DUP_FIXED,
+    ] {
+        let table = txn.create_table(Some("dups"), flags).unwrap();
+        txn.put(&table, b"key", b"val2", WriteFlags::empty())
+            .unwrap();
+        txn.put(&table, b"key", b"val3", WriteFlags::empty())
+            .unwrap();
+        assert_eq!(
+            txn.put_no_overwrite(&table, b"key", b"val1").unwrap(),
+            Some(*b"val2")
+        );
+        assert_eq!(txn.get_both::<()>(&table, b"key", b"val1").unwrap(), None);
+        // Unlike NO_DUP_DATA, which only refuses an existing pair.
+        assert!(matches!(
+            txn.put(&table, b"key", b"val3", WriteFlags::NO_DUP_DATA),
+            Err(Error::KeyExist)
+        ));
+        txn.put(&table, b"key", b"val1", WriteFlags::NO_DUP_DATA)
+            .unwrap();
+        unsafe { txn.drop_table(table).unwrap() };
+    }
+}
+
+#[test]
+fn test_walk_pages() {
+    use std::panic;
+
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            max_tables: Some(1),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    for i in 0..1000u32 {
+        txn.put(&table, i.to_be_bytes(), [0; 64], WriteFlags::empty())
+            .unwrap();
+    }
+    let page_size = db.stat().unwrap().page_size() as usize;
+    txn.put(
+        &table,
+        b"large",
+        vec![1; 2 * page_size],
+        WriteFlags::empty(),
+    )
+    .unwrap();
+    let dups = txn
+        .create_table(Some("dups"), TableFlags::DUP_SORT)
+        .unwrap();
+    for i in 0..3u32 {
+        txn.put(&dups, b"key", i.to_be_bytes(), WriteFlags::empty())
+            .unwrap();
+    }
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let mut pages = Vec::new();
+    txn.walk_pages(|page| {
+        assert!(page.error.is_none(), "{page:?}");
+        assert!((0.0..=1.0).contains(&page.fill()), "{page:?}");
+        pages.push((
+            page.pgno,
+            page.count,
+            page.owner.clone().into_owned(),
+            page.kind,
+            page.entries,
+        ));
+    })
+    .unwrap();
+    let info = txn.info(false).unwrap();
+    assert_eq!(pages[0], (0, 3, PageOwner::Meta, PageKind::Meta, 3));
+
+    // Every page up to the last used one is either visited once or free.
+    let used = pages.iter().map(|page| u64::from(page.1)).sum::<u64>();
+    assert_eq!(
+        used + db.freelist().unwrap() as u64,
+        info.space_used() / page_size as u64
+    );
+
+    let main = pages
+        .iter()
+        .filter(|page| page.2 == PageOwner::Main)
+        .collect::<Vec<_>>();
+    assert!(main.iter().any(|page| page.3 == PageKind::Branch));
+    assert_eq!(
+        main.iter()
+            .filter(|page| page.3 == PageKind::Leaf)
+            .map(|page| page.4)
+            .sum::<usize>(),
+        1002
+    );
+    assert!(main
+        .iter()
+        .any(|page| page.3 == PageKind::Large && page.1 == 3));
+
+    // The few duplicates are stored within the leaf page of their key, and reported first.
+    let named = pages
+        .iter()
+        .filter(|page| page.2 == PageOwner::Table("dups".into()))
+        .map(|page| (page.1, page.3, page.4))
+        .collect::<Vec<_>>();
+    assert_eq!(named, [(0, PageKind::SubLeaf, 3), (1, PageKind::Leaf, 1)]);
+
+    // A panicking visitor stops the walk, and the panic is passed on.
+    let mut visited = 0;
+    let result = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        txn.walk_pages(|_| {
+            visited += 1;
+            panic!("stop");
+        })
+    }));
+    assert!(result.is_err());
+    assert_eq!(visited, 1);
+    txn.walk_pages(|_| ()).unwrap();
+}
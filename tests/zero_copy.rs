@@ -0,0 +1,96 @@
+use libmdbx::*;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    borrow::Cow,
+    cell::Cell,
+};
+use tempfile::tempdir;
+
+type Database = libmdbx::Database<NoWriteMap>;
+
+/// Counts the heap allocations made by each thread.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_scan_without_allocations() {
+    const COUNT: u32 = 1_000_000;
+
+    let dir = tempdir().unwrap();
+    let db = Database::open_with_options(
+        &dir,
+        DatabaseOptions {
+            mode: Mode::ReadWrite(ReadWriteOptions {
+                max_size: Some(256 << 20),
+                ..Default::default()
+            }),
+            ..Default::default()
+        },
+    )
+    .unwrap();
+
+    let txn = db.begin_rw_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    for i in 0..COUNT {
+        txn.put(
+            &table,
+            i.to_be_bytes(),
+            u64::from(i).to_le_bytes(),
+            WriteFlags::APPEND,
+        )
+        .unwrap();
+    }
+    txn.commit().unwrap();
+
+    let txn = db.begin_ro_txn().unwrap();
+    let table = txn.open_table(None).unwrap();
+    let mut cursor = txn.cursor(&table).unwrap();
+
+    let before = allocations();
+    let mut scanned = 0;
+    for item in cursor.iter_start::<Cow<[u8]>, Cow<[u8]>>() {
+        let (key, value) = item.unwrap();
+        assert!(
+            matches!((key, value), (Cow::Borrowed(_), Cow::Borrowed(value)) if value.len() == 8)
+        );
+        scanned += 1;
+    }
+    let value = txn
+        .get::<Cow<[u8]>>(&table, &7u32.to_be_bytes())
+        .unwrap()
+        .unwrap();
+    assert!(matches!(value, Cow::Borrowed(_)));
+    assert_eq!(allocations(), before);
+    assert_eq!(scanned, COUNT);
+
+    // Owned values are copied out.
+    let before = allocations();
+    txn.get::<Vec<u8>>(&table, &7u32.to_be_bytes()).unwrap();
+    assert_eq!(allocations(), before + 1);
+}